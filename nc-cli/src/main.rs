@@ -1,7 +1,7 @@
 mod pretty_print;
 
-use sec_data_parser::parse_submission;
-use std::fs::read_dir;
+use sec_data_parser::{parse_submission_from_bytes, FeedArchive, ParseOptions};
+use std::fs::{read, read_dir};
 
 use crate::pretty_print::PrettyPrint;
 use clap::{AppSettings, Clap};
@@ -18,6 +18,7 @@ struct Opts {
 enum SubCommand {
     Describe(DescribeCommand),
     Check(CheckCommand),
+    CheckFeed(CheckFeedCommand),
 }
 
 #[derive(Clap)]
@@ -28,6 +29,17 @@ struct DescribeCommand {
 #[derive(Clap)]
 struct CheckCommand {
     dir: PathBuf,
+    /// Keep unknown tags, printing them as warnings, instead of failing.
+    #[clap(long)]
+    lenient: bool,
+    /// Also check each submission's header against its documents.
+    #[clap(long)]
+    validate: bool,
+}
+
+#[derive(Clap)]
+struct CheckFeedCommand {
+    file: PathBuf,
 }
 
 fn main() {
@@ -35,16 +47,49 @@ fn main() {
 
     match opts.subcmd {
         SubCommand::Describe(DescribeCommand { file }) => {
-            let submission = parse_submission(&file).unwrap();
-
-            submission.pretty_print();
+            let source = read(&file).unwrap();
+            match parse_submission_from_bytes(&source) {
+                Ok(submission) => submission.pretty_print(),
+                Err(error) => eprint!("{}", error.render(&source)),
+            }
         }
-        SubCommand::Check(CheckCommand { dir }) => {
+        SubCommand::Check(CheckCommand {
+            dir,
+            lenient,
+            validate,
+        }) => {
+            let options = ParseOptions {
+                lenient,
+                ..Default::default()
+            };
             for file in read_dir(dir).unwrap() {
                 let path = file.unwrap().path();
                 println!("{:?}", &path);
 
-                parse_submission(&path).unwrap();
+                match options.report_path(&path) {
+                    Ok(report) => {
+                        for warning in report.warnings {
+                            println!("  warning: {}", warning);
+                        }
+                        if validate {
+                            for finding in report.submission.validate() {
+                                println!("  finding: {}", finding);
+                            }
+                        }
+                    }
+                    Err(error) => println!("  {}", error),
+                }
+            }
+        }
+        SubCommand::CheckFeed(CheckFeedCommand { file }) => {
+            let mut archive = FeedArchive::open(&file).unwrap();
+
+            for (name, result) in archive.submissions().unwrap() {
+                println!("{}", name);
+
+                if let Err(error) = result {
+                    println!("  {}", error);
+                }
             }
         }
     }
@@ -1,5 +1,5 @@
 use colored::Colorize;
-use sec_data_parser::{Company, CompanyData, Document, Submission, TypedData};
+use sec_data_parser::{Body, Company, CompanyData, Document, Submission, TypedData};
 
 pub trait PrettyPrint {
     fn pretty_print_with_indent(&self, indent: u32);
@@ -24,7 +24,7 @@ impl PrettyPrint for (&str, &str) {
 impl PrettyPrint for CompanyData {
     fn pretty_print_with_indent(&self, indent: u32) {
         PrettyPrint::pretty_print_with_indent(&("Name", self.conformed_name.as_str()), indent + 1);
-        PrettyPrint::pretty_print_with_indent(&("CIK", self.cik.as_str()), indent + 1);
+        PrettyPrint::pretty_print_with_indent(&("CIK", self.cik.to_string().as_str()), indent + 1);
     }
 }
 
@@ -50,6 +50,21 @@ impl PrettyPrint for TypedData {
     }
 }
 
+impl PrettyPrint for Body {
+    fn pretty_print_with_indent(&self, indent: u32) {
+        match self {
+            Body::Loaded(typed_data) => typed_data.pretty_print_with_indent(indent),
+            Body::Lazy(range) => PrettyPrint::pretty_print_with_indent(
+                &(
+                    "Data",
+                    format!("{} bytes at offset {}", range.length, range.offset).as_str(),
+                ),
+                indent,
+            ),
+        }
+    }
+}
+
 impl PrettyPrint for Document {
     fn pretty_print_with_indent(&self, indent: u32) {
         PrettyPrint::pretty_print_with_indent(&("Type", self.doc_type.as_str()), indent + 1);
@@ -0,0 +1,315 @@
+use crate::location::Location;
+use crate::schema::Address;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// The state of an address, from EDGAR's two-character codes. US states and territories have
+/// their own variants; the other codes in the `Location` table, for Canadian provinces and other
+/// countries such as `A6` for Ontario or `X0` for the United Kingdom, are kept as `Foreign`.
+/// `location` gives the name and ISO codes for either kind.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
+pub enum State {
+    Alabama,
+    Alaska,
+    Arizona,
+    Arkansas,
+    California,
+    Colorado,
+    Connecticut,
+    Delaware,
+    DistrictOfColumbia,
+    Florida,
+    Georgia,
+    Hawaii,
+    Idaho,
+    Illinois,
+    Indiana,
+    Iowa,
+    Kansas,
+    Kentucky,
+    Louisiana,
+    Maine,
+    Maryland,
+    Massachusetts,
+    Michigan,
+    Minnesota,
+    Mississippi,
+    Missouri,
+    Montana,
+    Nebraska,
+    Nevada,
+    NewHampshire,
+    NewJersey,
+    NewMexico,
+    NewYork,
+    NorthCarolina,
+    NorthDakota,
+    Ohio,
+    Oklahoma,
+    Oregon,
+    Pennsylvania,
+    RhodeIsland,
+    SouthCarolina,
+    SouthDakota,
+    Tennessee,
+    Texas,
+    Utah,
+    Vermont,
+    Virginia,
+    Washington,
+    WestVirginia,
+    Wisconsin,
+    Wyoming,
+    AmericanSamoa,
+    Guam,
+    NorthernMarianaIslands,
+    PuertoRico,
+    VirginIslands,
+    UnitedStatesMinorOutlyingIslands,
+    /// EDGAR's code for the United States as a whole, used where no state applies.
+    UnitedStates,
+    Foreign(String),
+}
+
+impl State {
+    pub fn parse(st: &str) -> Option<State> {
+        Some(match st {
+            "AL" => State::Alabama,
+            "AK" => State::Alaska,
+            "AZ" => State::Arizona,
+            "AR" => State::Arkansas,
+            "CA" => State::California,
+            "CO" => State::Colorado,
+            "CT" => State::Connecticut,
+            "DE" => State::Delaware,
+            "DC" => State::DistrictOfColumbia,
+            "FL" => State::Florida,
+            "GA" => State::Georgia,
+            "HI" => State::Hawaii,
+            "ID" => State::Idaho,
+            "IL" => State::Illinois,
+            "IN" => State::Indiana,
+            "IA" => State::Iowa,
+            "KS" => State::Kansas,
+            "KY" => State::Kentucky,
+            "LA" => State::Louisiana,
+            "ME" => State::Maine,
+            "MD" => State::Maryland,
+            "MA" => State::Massachusetts,
+            "MI" => State::Michigan,
+            "MN" => State::Minnesota,
+            "MS" => State::Mississippi,
+            "MO" => State::Missouri,
+            "MT" => State::Montana,
+            "NE" => State::Nebraska,
+            "NV" => State::Nevada,
+            "NH" => State::NewHampshire,
+            "NJ" => State::NewJersey,
+            "NM" => State::NewMexico,
+            "NY" => State::NewYork,
+            "NC" => State::NorthCarolina,
+            "ND" => State::NorthDakota,
+            "OH" => State::Ohio,
+            "OK" => State::Oklahoma,
+            "OR" => State::Oregon,
+            "PA" => State::Pennsylvania,
+            "RI" => State::RhodeIsland,
+            "SC" => State::SouthCarolina,
+            "SD" => State::SouthDakota,
+            "TN" => State::Tennessee,
+            "TX" => State::Texas,
+            "UT" => State::Utah,
+            "VT" => State::Vermont,
+            "VA" => State::Virginia,
+            "WA" => State::Washington,
+            "WV" => State::WestVirginia,
+            "WI" => State::Wisconsin,
+            "WY" => State::Wyoming,
+            "B5" => State::AmericanSamoa,
+            "GU" => State::Guam,
+            "1V" => State::NorthernMarianaIslands,
+            "PR" => State::PuertoRico,
+            "VI" => State::VirginIslands,
+            "2J" => State::UnitedStatesMinorOutlyingIslands,
+            "X1" => State::UnitedStates,
+            _ => match Location::from_code(st) {
+                Some(location) if !location.is_us() => State::Foreign(st.to_string()),
+                _ => return None,
+            },
+        })
+    }
+
+    pub fn code(&self) -> &str {
+        match self {
+            State::Alabama => "AL",
+            State::Alaska => "AK",
+            State::Arizona => "AZ",
+            State::Arkansas => "AR",
+            State::California => "CA",
+            State::Colorado => "CO",
+            State::Connecticut => "CT",
+            State::Delaware => "DE",
+            State::DistrictOfColumbia => "DC",
+            State::Florida => "FL",
+            State::Georgia => "GA",
+            State::Hawaii => "HI",
+            State::Idaho => "ID",
+            State::Illinois => "IL",
+            State::Indiana => "IN",
+            State::Iowa => "IA",
+            State::Kansas => "KS",
+            State::Kentucky => "KY",
+            State::Louisiana => "LA",
+            State::Maine => "ME",
+            State::Maryland => "MD",
+            State::Massachusetts => "MA",
+            State::Michigan => "MI",
+            State::Minnesota => "MN",
+            State::Mississippi => "MS",
+            State::Missouri => "MO",
+            State::Montana => "MT",
+            State::Nebraska => "NE",
+            State::Nevada => "NV",
+            State::NewHampshire => "NH",
+            State::NewJersey => "NJ",
+            State::NewMexico => "NM",
+            State::NewYork => "NY",
+            State::NorthCarolina => "NC",
+            State::NorthDakota => "ND",
+            State::Ohio => "OH",
+            State::Oklahoma => "OK",
+            State::Oregon => "OR",
+            State::Pennsylvania => "PA",
+            State::RhodeIsland => "RI",
+            State::SouthCarolina => "SC",
+            State::SouthDakota => "SD",
+            State::Tennessee => "TN",
+            State::Texas => "TX",
+            State::Utah => "UT",
+            State::Vermont => "VT",
+            State::Virginia => "VA",
+            State::Washington => "WA",
+            State::WestVirginia => "WV",
+            State::Wisconsin => "WI",
+            State::Wyoming => "WY",
+            State::AmericanSamoa => "B5",
+            State::Guam => "GU",
+            State::NorthernMarianaIslands => "1V",
+            State::PuertoRico => "PR",
+            State::VirginIslands => "VI",
+            State::UnitedStatesMinorOutlyingIslands => "2J",
+            State::UnitedStates => "X1",
+            State::Foreign(code) => code,
+        }
+    }
+
+    /// Whether the `Location` for the code is outside the United States and its territories.
+    pub fn is_foreign(&self) -> bool {
+        self.location().is_some_and(|location| !location.is_us())
+    }
+}
+
+impl Display for State {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.code())
+    }
+}
+
+/// A US ZIP code, with the ZIP+4 extension when one was given.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
+pub struct Zip {
+    pub base: String,
+    pub plus_four: Option<String>,
+}
+
+impl Zip {
+    /// Accepts `12345`, `12345-6789` and `123456789`.
+    pub fn parse(st: &str) -> Option<Zip> {
+        let digits =
+            |st: &str, len: usize| st.len() == len && st.bytes().all(|b| b.is_ascii_digit());
+        let (base, plus_four) = match st.split_once('-') {
+            Some((base, plus_four)) => (base, Some(plus_four)),
+            None if st.len() == 9 => (&st[..5], Some(&st[5..])),
+            None => (st, None),
+        };
+        if !digits(base, 5) || !plus_four.is_none_or(|p| digits(p, 4)) {
+            return None;
+        }
+
+        Some(Zip {
+            base: base.to_string(),
+            plus_four: plus_four.map(|p| p.to_string()),
+        })
+    }
+}
+
+impl Display for Zip {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.base)?;
+        if let Some(plus_four) = &self.plus_four {
+            write!(f, "-{}", plus_four)?;
+        }
+        Ok(())
+    }
+}
+
+// Normalizes a phone number to `+<country code><number>`. Numbers without a country code are
+// taken to be North American unless `foreign` is set, in which case they cannot be normalized.
+// Anything after an extension marker is dropped.
+fn normalize_phone(phone: &str, foreign: bool) -> Option<String> {
+    let upper = phone.to_ascii_uppercase();
+    let number = match upper.find(|c: char| c.is_ascii_alphabetic()) {
+        Some(idx) => &upper[..idx],
+        None => &upper,
+    };
+    let digits: String = number.chars().filter(|c| c.is_ascii_digit()).collect();
+
+    let international = if number.trim_start().starts_with('+') {
+        Some(digits.as_str())
+    } else {
+        digits.strip_prefix("011")
+    };
+    match international {
+        Some(digits) if (8..=15).contains(&digits.len()) => Some(format!("+{}", digits)),
+        Some(_) => None,
+        None if foreign => None,
+        None if digits.len() == 10 => Some(format!("+1{}", digits)),
+        None if digits.len() == 11 && digits.starts_with('1') => Some(format!("+{}", digits)),
+        None => None,
+    }
+}
+
+/// The parts of an `Address` which have a standard form, normalized where possible. Each field is
+/// `None` when the raw value was missing or not understood; the raw values stay on the `Address`.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct NormalizedAddress {
+    pub state: Option<State>,
+    pub zip: Option<Zip>,
+    /// In `+<country code><number>` form.
+    pub phone: Option<String>,
+    /// Whether the address is outside the United States and its territories.
+    pub foreign: bool,
+}
+
+impl Address {
+    pub fn normalized(&self) -> NormalizedAddress {
+        let state = self.state.as_deref().and_then(|st| State::parse(st.trim()));
+        let foreign = state.as_ref().is_some_and(State::is_foreign);
+        let zip = if foreign {
+            None
+        } else {
+            self.zip.as_deref().and_then(|zip| Zip::parse(zip.trim()))
+        };
+        let phone = self
+            .phone
+            .as_deref()
+            .and_then(|phone| normalize_phone(phone, foreign));
+
+        NormalizedAddress {
+            state,
+            zip,
+            phone,
+            foreign,
+        }
+    }
+}
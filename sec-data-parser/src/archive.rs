@@ -0,0 +1,69 @@
+use crate::error::Result;
+use crate::schema::Submission;
+use crate::ParseOptions;
+use flate2::read::GzDecoder;
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+use tar::{Archive, Entries, EntryType};
+
+// Reads a day of the Public Dissemination Service feed, which is delivered as a gzipped tar of
+// `.nc` files, without unpacking it to disk.
+pub struct FeedArchive<R: Read> {
+    archive: Archive<GzDecoder<R>>,
+    options: ParseOptions,
+}
+
+impl FeedArchive<File> {
+    pub fn open(path: &Path) -> Result<FeedArchive<File>> {
+        Ok(FeedArchive::new(File::open(path)?))
+    }
+}
+
+impl<R: Read> FeedArchive<R> {
+    pub fn new(reader: R) -> FeedArchive<R> {
+        FeedArchive::with_options(reader, ParseOptions::default())
+    }
+
+    pub fn with_options(reader: R, options: ParseOptions) -> FeedArchive<R> {
+        FeedArchive {
+            archive: Archive::new(GzDecoder::new(reader)),
+            options,
+        }
+    }
+
+    pub fn submissions(&mut self) -> Result<FeedSubmissions<'_, R>> {
+        Ok(FeedSubmissions {
+            entries: self.archive.entries()?,
+            options: &self.options,
+        })
+    }
+}
+
+// Yields each entry's name along with the result of parsing it. A filing that fails to parse does
+// not affect the entries after it, but a corrupt archive ends iteration after reporting the
+// error with an empty entry name.
+pub struct FeedSubmissions<'a, R: 'a + Read> {
+    entries: Entries<'a, GzDecoder<R>>,
+    options: &'a ParseOptions,
+}
+
+impl<'a, R: Read> Iterator for FeedSubmissions<'a, R> {
+    type Item = (String, Result<Submission>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let entry = match self.entries.next()? {
+                Ok(entry) => entry,
+                Err(error) => return Some((String::new(), Err(error.into()))),
+            };
+
+            if entry.header().entry_type() != EntryType::Regular {
+                continue;
+            }
+
+            let name = String::from_utf8_lossy(&entry.path_bytes()).into_owned();
+            return Some((name, self.options.parse_reader(entry)));
+        }
+    }
+}
@@ -0,0 +1,395 @@
+//! Schedules 13D and 13G, filed by anyone who acquires more than 5% of a class of an issuer's
+//! securities. Each reporting person has a cover page stating how much of the class they own.
+//! Since December 2024 the schedules are XML documents; before that the cover pages are only
+//! found in the text of the primary document.
+
+use crate::error::Result;
+use crate::form_type::FormFamily;
+use crate::schema::Submission;
+use crate::types::Cik;
+use crate::xml::{self, parse_number};
+use roxmltree::Node;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct CoverPages {
+    /// The CUSIP of the class of securities the schedule is about.
+    pub cusip: Option<String>,
+    pub reporting_persons: Vec<CoverPage>,
+}
+
+/// The cover page of one reporting person. Share amounts are numbers of shares.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct CoverPage {
+    pub name: Option<String>,
+    /// Only given in XML schedules.
+    pub cik: Option<Cik>,
+    pub citizenship: Option<String>,
+    pub sole_voting_power: Option<f64>,
+    pub shared_voting_power: Option<f64>,
+    pub sole_dispositive_power: Option<f64>,
+    pub shared_dispositive_power: Option<f64>,
+    pub aggregate_amount_owned: Option<f64>,
+    pub percent_of_class: Option<f64>,
+    /// For example `IN` for an individual or `IA` for an investment adviser.
+    pub type_of_reporting_person: Option<String>,
+}
+
+// Elements are found by name anywhere below the reporting person, since the 13D and 13G schemas
+// nest them differently.
+fn descendant<'a, 'input>(node: Node<'a, 'input>, names: &[&str]) -> Option<Node<'a, 'input>> {
+    node.descendants().find(|n| {
+        n.is_element()
+            && names
+                .iter()
+                .any(|name| n.tag_name().name().eq_ignore_ascii_case(name))
+    })
+}
+
+fn descendant_string(node: Node<'_, '_>, names: &[&str]) -> Option<String> {
+    descendant(node, names)
+        .and_then(xml::text)
+        .map(|st| st.to_string())
+}
+
+fn descendant_number(node: Node<'_, '_>, names: &'static [&'static str]) -> Result<Option<f64>> {
+    descendant(node, names)
+        .and_then(xml::text)
+        .map(|st| parse_number(st).ok_or_else(|| xml::bad_value(names[0], st)))
+        .transpose()
+}
+
+/// Parses the XML of a Schedule 13D or 13G, without the `<XML>` wrapper.
+pub fn parse_xml(st: &str) -> Result<CoverPages> {
+    let document = xml::parse(st)?;
+    let root = xml::root(&document, "edgarSubmission")?;
+
+    let mut reporting_persons = Vec::new();
+    for person in root.descendants().filter(|n| {
+        n.is_element()
+            && matches!(
+                n.tag_name().name(),
+                "reportingPersonInfo" | "coverPageHeaderReportingPersonDetails"
+            )
+    }) {
+        reporting_persons.push(CoverPage {
+            name: descendant_string(person, &["reportingPersonName"]),
+            cik: descendant(person, &["reportingPersonCIK"])
+                .and_then(xml::text)
+                .map(|st| Cik::parse(st).ok_or_else(|| xml::bad_value("reportingPersonCIK", st)))
+                .transpose()?,
+            citizenship: descendant_string(person, &["citizenshipOrOrganization"]),
+            sole_voting_power: descendant_number(person, &["soleVotingPower"])?,
+            shared_voting_power: descendant_number(person, &["sharedVotingPower"])?,
+            sole_dispositive_power: descendant_number(person, &["soleDispositivePower"])?,
+            shared_dispositive_power: descendant_number(person, &["sharedDispositivePower"])?,
+            aggregate_amount_owned: descendant_number(
+                person,
+                &[
+                    "aggregateAmountOwned",
+                    "reportingPersonBeneficiallyOwnedAggregateNumberOfShares",
+                ],
+            )?,
+            percent_of_class: descendant_number(person, &["percentOfClass", "classPercent"])?,
+            type_of_reporting_person: descendant_string(person, &["typeOfReportingPerson"]),
+        });
+    }
+
+    Ok(CoverPages {
+        cusip: descendant_string(root, &["issuerCusip", "issuerCusipNumber"]),
+        reporting_persons,
+    })
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Row {
+    Name,
+    Citizenship,
+    SoleVotingPower,
+    SharedVotingPower,
+    SoleDispositivePower,
+    SharedDispositivePower,
+    AggregateAmountOwned,
+    PercentOfClass,
+    TypeOfReportingPerson,
+    // Rows which are not extracted, but which end the row before them.
+    Other,
+}
+
+// The captions of the rows of a cover page, in upper case. The rows are numbered differently on
+// the two schedules, so they are found by caption.
+const CAPTIONS: &[(&str, Row)] = &[
+    ("NAMES OF REPORTING PERSON", Row::Name),
+    ("NAME OF REPORTING PERSON", Row::Name),
+    ("CHECK THE APPROPRIATE BOX", Row::Other),
+    ("SEC USE ONLY", Row::Other),
+    ("SOURCE OF FUNDS", Row::Other),
+    ("DISCLOSURE OF LEGAL PROCEEDINGS", Row::Other),
+    ("CITIZENSHIP OR PLACE OF ORGANIZATION", Row::Citizenship),
+    ("SOLE VOTING POWER", Row::SoleVotingPower),
+    ("SHARED VOTING POWER", Row::SharedVotingPower),
+    ("SOLE DISPOSITIVE POWER", Row::SoleDispositivePower),
+    ("SHARED DISPOSITIVE POWER", Row::SharedDispositivePower),
+    (
+        "AGGREGATE AMOUNT BENEFICIALLY OWNED",
+        Row::AggregateAmountOwned,
+    ),
+    ("IF THE AGGREGATE AMOUNT", Row::Other),
+    ("PERCENT OF CLASS REPRESENTED", Row::PercentOfClass),
+    ("TYPE OF REPORTING PERSON", Row::TypeOfReportingPerson),
+];
+
+/// Extracts the cover pages from the text of a schedule, which may be HTML. Each page starts
+/// at a "names of reporting persons" row, and the value of each row is read from the text
+/// between its caption and the next one. Rows which cannot be read are left as `None`.
+pub fn parse_text(st: &str) -> CoverPages {
+    let text = normalize(&strip_html(st));
+    // Captions may be broken across lines; replacing newlines keeps the offsets the same.
+    let upper = text.to_ascii_uppercase().replace('\n', " ");
+
+    let mut captions: Vec<(usize, usize, Row)> = Vec::new();
+    for (caption, row) in CAPTIONS {
+        for (start, _) in upper.match_indices(caption) {
+            // Finish the caption's last word, as in `PERSONS` or `PERSON(S)`.
+            let rest = &upper[start + caption.len()..];
+            let tail = rest
+                .find(|c: char| !(c.is_ascii_alphabetic() || c == '(' || c == ')' || c == ':'))
+                .unwrap_or(rest.len());
+            captions.push((start, start + caption.len() + tail, *row));
+        }
+    }
+    captions.sort_by_key(|&(start, _, _)| start);
+
+    let mut pages: Vec<CoverPage> = Vec::new();
+    for (i, &(_, end, row)) in captions.iter().enumerate() {
+        let next = captions
+            .get(i + 1)
+            .map_or(text.len(), |&(start, _, _)| start);
+        let value = &text[end..next.max(end)];
+
+        if row == Row::Name {
+            pages.push(CoverPage {
+                name: first_line(value),
+                ..CoverPage::default()
+            });
+            continue;
+        }
+        if pages.is_empty() {
+            pages.push(CoverPage::default());
+        }
+        let page = pages.last_mut().unwrap();
+        let (slot, value) = match row {
+            Row::Citizenship => {
+                page.citizenship = page.citizenship.take().or_else(|| first_line(value));
+                continue;
+            }
+            Row::TypeOfReportingPerson => {
+                page.type_of_reporting_person = page
+                    .type_of_reporting_person
+                    .take()
+                    .or_else(|| first_line(value));
+                continue;
+            }
+            Row::SoleVotingPower => (&mut page.sole_voting_power, value),
+            Row::SharedVotingPower => (&mut page.shared_voting_power, value),
+            Row::SoleDispositivePower => (&mut page.sole_dispositive_power, value),
+            Row::SharedDispositivePower => (&mut page.shared_dispositive_power, value),
+            Row::AggregateAmountOwned => (&mut page.aggregate_amount_owned, value),
+            Row::PercentOfClass => (&mut page.percent_of_class, value),
+            Row::Name | Row::Other => continue,
+        };
+        if slot.is_none() {
+            *slot = first_number(value);
+        }
+    }
+
+    CoverPages {
+        cusip: cusip(&text),
+        reporting_persons: pages,
+    }
+}
+
+// Row numbers, such as `7.` or `(7)`, which precede captions.
+fn is_row_number(token: &str) -> bool {
+    let digits = token.trim_start_matches('(').trim_end_matches(['.', ')']);
+    !digits.is_empty()
+        && digits.len() <= 2
+        && digits.bytes().all(|b| b.is_ascii_digit())
+        && digits.len() < token.len()
+}
+
+fn first_number(value: &str) -> Option<f64> {
+    let tokens: Vec<&str> = value.split_whitespace().collect();
+    for (i, token) in tokens.iter().enumerate() {
+        // Skip references such as `ROW (9)` or `Item 4`, footnote markers and row numbers.
+        let previous = i
+            .checked_sub(1)
+            .map(|i| tokens[i].to_ascii_uppercase())
+            .unwrap_or_default();
+        if previous == "ROW" || previous == "ROWS" || previous == "ITEM" {
+            continue;
+        }
+        if token.starts_with('(') || is_row_number(token) {
+            continue;
+        }
+        if *token == "-0-" {
+            return Some(0.0);
+        }
+
+        let number = token.split('(').next().unwrap_or_default();
+        let number = number.trim_matches(|c| c == '$' || c == '%' || c == '*' || c == ':');
+        if !number.bytes().any(|b| b.is_ascii_digit()) {
+            continue;
+        }
+        let parsed = parse_number(number)?;
+        // A small bare number at the end is the next row's number, with this row left empty.
+        if i == tokens.len() - 1 && number.len() <= 2 && (1.0..=14.0).contains(&parsed) {
+            return None;
+        }
+        return Some(parsed);
+    }
+    None
+}
+
+fn first_line(value: &str) -> Option<String> {
+    value
+        .lines()
+        .map(|line| {
+            let upper = line.to_ascii_uppercase();
+            let line = match upper.find("(SEE INSTRUCTIONS)") {
+                Some(start) => format!(
+                    "{}{}",
+                    &line[..start],
+                    &line[start + "(SEE INSTRUCTIONS)".len()..]
+                ),
+                None => line.to_string(),
+            };
+            line.trim_matches(|c: char| c.is_whitespace() || c == ':' || c == '*')
+                .to_string()
+        })
+        .find(|line| {
+            let upper = line.to_ascii_uppercase();
+            !line.is_empty()
+                && !is_row_number(line)
+                && !upper.contains("IDENTIFICATION NO")
+                && !upper.contains("ENTITIES ONLY")
+        })
+}
+
+fn cusip(text: &str) -> Option<String> {
+    let upper = text.to_ascii_uppercase();
+    let start = upper.find("CUSIP")?;
+    upper[start..]
+        .split_whitespace()
+        .skip(1)
+        .map(|token| token.trim_matches(|c: char| !c.is_ascii_alphanumeric()))
+        .find(|token| token.len() >= 6 && token.bytes().any(|b| b.is_ascii_digit()))
+        .map(|token| token.to_string())
+}
+
+// Removes tags, putting block-level elements on lines of their own, and decodes the common
+// character references.
+fn strip_html(st: &str) -> String {
+    let mut out = String::with_capacity(st.len());
+    let mut rest = st;
+    while let Some(start) = rest.find('<') {
+        out.push_str(&decode_entities(&rest[..start]));
+        rest = &rest[start..];
+        let is_tag = rest[1..]
+            .chars()
+            .next()
+            .is_some_and(|c| c.is_ascii_alphabetic() || c == '/' || c == '!');
+        match rest.find('>').filter(|_| is_tag) {
+            Some(end) => {
+                let name = rest[1..end]
+                    .trim_start_matches('/')
+                    .split(|c: char| c.is_whitespace() || c == '/')
+                    .next()
+                    .unwrap_or_default()
+                    .to_ascii_lowercase();
+                let block = matches!(
+                    name.as_str(),
+                    "p" | "br" | "div" | "tr" | "table" | "li" | "h1" | "h2" | "h3" | "h4"
+                );
+                out.push(if block { '\n' } else { ' ' });
+                rest = &rest[end + 1..];
+            }
+            None => {
+                out.push('<');
+                rest = &rest[1..];
+            }
+        }
+    }
+    out.push_str(&decode_entities(rest));
+    out
+}
+
+fn decode_entities(st: &str) -> String {
+    if !st.contains('&') {
+        return st.to_string();
+    }
+    let mut out = String::with_capacity(st.len());
+    let mut rest = st;
+    while let Some(start) = rest.find('&') {
+        out.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let decoded = rest.find(';').filter(|&end| end <= 10).and_then(|end| {
+            let name = &rest[1..end];
+            let c = match name {
+                "nbsp" => ' ',
+                "amp" => '&',
+                "lt" => '<',
+                "gt" => '>',
+                "quot" => '"',
+                "apos" => '\'',
+                _ => match name.strip_prefix("#x").or_else(|| name.strip_prefix("#X")) {
+                    Some(hex) => char::from_u32(u32::from_str_radix(hex, 16).ok()?)?,
+                    None => char::from_u32(name.strip_prefix('#')?.parse().ok()?)?,
+                },
+            };
+            Some((c, end))
+        });
+        match decoded {
+            Some((c, end)) => {
+                out.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+// Collapses the whitespace within each line and drops blank lines.
+fn normalize(st: &str) -> String {
+    st.lines()
+        .map(|line| line.split_whitespace().collect::<Vec<_>>().join(" "))
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+impl Submission {
+    /// The cover pages of a Schedule 13D or 13G, from its XML if it has one and otherwise from
+    /// the text of its primary document. Returns `None` for other forms and when bodies were not
+    /// loaded.
+    pub fn cover_pages(&self) -> Option<Result<CoverPages>> {
+        if self.form().family() != Some(FormFamily::BeneficialOwnership) {
+            return None;
+        }
+        let mut schedules = self
+            .documents
+            .iter()
+            .filter(|document| document.form().family() == Some(FormFamily::BeneficialOwnership));
+        if let Some(xml) = schedules.clone().find_map(|document| document.xml()) {
+            return Some(parse_xml(xml));
+        }
+        schedules
+            .find_map(|document| document.plain_text())
+            .map(|text| Ok(parse_text(text)))
+    }
+}
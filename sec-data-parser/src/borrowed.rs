@@ -0,0 +1,127 @@
+use crate::context::{declared_document_count, Context};
+use crate::document_tree::{parse_doc, DocumentTree};
+use crate::error::{ErrorKind, ParseError, Result};
+use crate::schema::Submission;
+use crate::span::Span;
+use crate::tag::{ContainerTag, ValueTag};
+use crate::tokens::SliceTokenizer;
+use crate::types::parse_date;
+use crate::warning::ParseReport;
+use crate::ParseOptions;
+use chrono::NaiveDate;
+
+// A submission parsed without copying its values out of the source. Values are only decoded into
+// owned strings if they are not valid UTF-8.
+pub struct SubmissionRef<'a> {
+    parts: Vec<DocumentTree<'a>>,
+    span: Span,
+    options: ParseOptions,
+}
+
+#[derive(Clone)]
+pub struct ContainerRef<'t, 'a> {
+    tag: ContainerTag,
+    parts: &'t [DocumentTree<'a>],
+}
+
+impl<'t, 'a> ContainerRef<'t, 'a> {
+    pub fn tag(&self) -> &ContainerTag {
+        &self.tag
+    }
+
+    pub fn values(&self, tag: ValueTag) -> impl Iterator<Item = &'t str> {
+        self.parts.iter().filter_map(move |part| match part {
+            DocumentTree::ValueNode(t, value, _) if *t == tag => Some(value.as_ref()),
+            _ => None,
+        })
+    }
+
+    pub fn value(&self, tag: ValueTag) -> Option<&'t str> {
+        self.values(tag).next()
+    }
+
+    pub fn containers(&self, tag: ContainerTag) -> impl Iterator<Item = ContainerRef<'t, 'a>> {
+        self.parts.iter().filter_map(move |part| match part {
+            DocumentTree::ContainerNode(t, parts, _) if *t == tag => Some(ContainerRef {
+                tag: tag.clone(),
+                parts,
+            }),
+            _ => None,
+        })
+    }
+
+    pub fn container(&self, tag: ContainerTag) -> Option<ContainerRef<'t, 'a>> {
+        self.containers(tag).next()
+    }
+}
+
+impl<'a> SubmissionRef<'a> {
+    pub fn parse(source: &'a [u8], options: &ParseOptions) -> Result<SubmissionRef<'a>> {
+        let mut tokens = SliceTokenizer::new(source, options.bodies, options.lenient);
+
+        match parse_doc(&mut tokens, options.decoding)? {
+            DocumentTree::ContainerNode(ContainerTag::Submission, parts, span) => {
+                Ok(SubmissionRef {
+                    parts,
+                    span,
+                    options: options.clone(),
+                })
+            }
+            tree => {
+                let error = ParseError::new(ErrorKind::MissingContainer(ContainerTag::Submission));
+                Err(match tree.span() {
+                    Some(span) => error.at(span),
+                    None => error,
+                })
+            }
+        }
+    }
+
+    pub fn root(&self) -> ContainerRef<'_, 'a> {
+        ContainerRef {
+            tag: ContainerTag::Submission,
+            parts: &self.parts,
+        }
+    }
+
+    pub fn accession_number(&self) -> Option<&str> {
+        self.root().value(ValueTag::AccessionNumber)
+    }
+
+    pub fn filing_type(&self) -> Option<&str> {
+        self.root().value(ValueTag::Type)
+    }
+
+    pub fn filing_date(&self) -> Option<NaiveDate> {
+        self.root().value(ValueTag::FilingDate).and_then(parse_date)
+    }
+
+    pub fn filers(&self) -> impl Iterator<Item = ContainerRef<'_, 'a>> {
+        self.root().containers(ContainerTag::Filer)
+    }
+
+    pub fn to_owned(&self) -> Result<Submission> {
+        Ok(self.to_report()?.submission)
+    }
+
+    pub fn to_report(&self) -> Result<ParseReport> {
+        let mut ctx = Context::new(self.accession_number(), &self.options);
+        let submission = ctx.nested(
+            &ContainerTag::Submission,
+            &self.parts,
+            self.span,
+            |parts, ctx| {
+                let submission = Submission::from_parts(parts, ctx)?;
+                ctx.check_document_count(
+                    declared_document_count(parts),
+                    submission.documents.len(),
+                );
+                Ok(submission)
+            },
+        )?;
+        Ok(ParseReport {
+            submission,
+            warnings: ctx.take_warnings(),
+        })
+    }
+}
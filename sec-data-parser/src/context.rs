@@ -0,0 +1,221 @@
+use crate::document_tree::DocumentTree;
+use crate::error::{ErrorKind, ParseError, Result};
+use crate::schema::ExtraContainer;
+use crate::span::Span;
+use crate::tag::{ContainerTag, ValueTag};
+use crate::warning::{Warning, WarningKind};
+use crate::ParseOptions;
+
+// The `<PUBLIC-DOCUMENT-COUNT>` among a submission's parts, and where it was.
+pub fn declared_document_count(parts: &[DocumentTree<'_>]) -> Option<(usize, Span)> {
+    parts.iter().find_map(|part| match part {
+        DocumentTree::ValueNode(ValueTag::PublicDocumentCount, value, span) => {
+            Some((value.parse().ok()?, *span))
+        }
+        _ => None,
+    })
+}
+
+// State threaded through the `from_parts` constructors so that errors raised deep in the tree
+// can report which submission and which container they came from.
+pub struct Context {
+    accession_number: Option<String>,
+    path: Vec<(ContainerTag, Span)>,
+    warnings: Vec<Warning>,
+    pub options: ParseOptions,
+}
+
+impl Context {
+    pub fn new(accession_number: Option<&str>, options: &ParseOptions) -> Context {
+        Context {
+            accession_number: accession_number.map(|st| st.to_string()),
+            path: Vec::new(),
+            warnings: Vec::new(),
+            options: options.clone(),
+        }
+    }
+
+    pub fn enter(&mut self, tag: ContainerTag, span: Span) {
+        self.path.push((tag, span));
+    }
+
+    pub fn nested<T>(
+        &mut self,
+        tag: &ContainerTag,
+        parts: &[DocumentTree<'_>],
+        span: Span,
+        parse: fn(&[DocumentTree<'_>], &mut Context) -> Result<T>,
+    ) -> Result<T> {
+        self.enter(tag.clone(), span);
+        let result = parse(parts, self);
+        self.path.pop();
+        result
+    }
+
+    pub fn error(&self, kind: ErrorKind, span: Option<Span>) -> ParseError {
+        ParseError {
+            kind,
+            accession_number: self.accession_number.clone(),
+            path: self.path.iter().map(|(tag, _)| tag.clone()).collect(),
+            span: span.or_else(|| self.path.last().map(|(_, span)| *span)),
+        }
+    }
+
+    pub fn warn(&mut self, kind: WarningKind, span: Option<Span>) {
+        self.warnings.push(Warning {
+            kind,
+            path: self.path.iter().map(|(tag, _)| tag.clone()).collect(),
+            span: span.or_else(|| self.path.last().map(|(_, span)| *span)),
+        });
+    }
+
+    pub fn take_warnings(&mut self) -> Vec<Warning> {
+        std::mem::take(&mut self.warnings)
+    }
+
+    // Replaces the warnings, for callers which take them to filter out some.
+    pub fn restore_warnings(&mut self, warnings: Vec<Warning>) {
+        self.warnings = warnings;
+    }
+
+    // Called once every document of the submission has been read, with the result of
+    // `declared_document_count`.
+    pub fn check_document_count(&mut self, declared: Option<(usize, Span)>, found: usize) {
+        if let Some((declared, span)) = declared {
+            if declared != found {
+                self.warn(
+                    WarningKind::DocumentCountMismatch { declared, found },
+                    Some(span),
+                );
+            }
+        }
+    }
+
+    // Called for any part the caller has no field for. When parsing leniently, unknown and
+    // misplaced tags are kept in the caller's `extra` fields with a warning instead of failing.
+    pub fn keep_unknown(
+        &mut self,
+        part: &DocumentTree<'_>,
+        extra: &mut Vec<(String, String)>,
+        extra_containers: &mut Vec<ExtraContainer>,
+    ) -> Result<()> {
+        if !self.options.lenient {
+            return Err(self.unexpected(part));
+        }
+
+        match part {
+            DocumentTree::ValueNode(tag, value, span) => {
+                let kind = match tag {
+                    ValueTag::Unknown(name) => WarningKind::UnknownValue(name.clone()),
+                    _ => WarningKind::UnexpectedValue(tag.clone()),
+                };
+                self.warn(kind, Some(*span));
+                extra.push((tag.name().to_string(), value.to_string()));
+            }
+            DocumentTree::ContainerNode(tag, parts, span) => {
+                let kind = match tag {
+                    ContainerTag::Unknown(name) => WarningKind::UnknownContainer(name.clone()),
+                    _ => WarningKind::UnexpectedContainer(tag.clone()),
+                };
+                self.warn(kind, Some(*span));
+                extra_containers.push(ExtraContainer::from_tree(tag, parts));
+            }
+            _ => return Err(self.unexpected(part)),
+        }
+        Ok(())
+    }
+
+    pub fn unexpected(&self, part: &DocumentTree<'_>) -> ParseError {
+        let kind = match part {
+            DocumentTree::ContainerNode(tag, _, _) => ErrorKind::UnexpectedContainer(tag.clone()),
+            DocumentTree::ValueNode(tag, _, _) => ErrorKind::UnexpectedValue(tag.clone()),
+            DocumentTree::TextNode(_, _)
+            | DocumentTree::TextRangeNode(_, _)
+            | DocumentTree::Empty => ErrorKind::UnexpectedText,
+        };
+        self.error(kind, part.span())
+    }
+
+    pub fn bad_value(&self, part: &DocumentTree<'_>) -> ParseError {
+        match part {
+            DocumentTree::ValueNode(tag, value, span) => self.error(
+                ErrorKind::BadValue(tag.clone(), value.as_ref().into()),
+                Some(*span),
+            ),
+            _ => self.unexpected(part),
+        }
+    }
+
+    // Parses an identifier such as a CIK. When parsing leniently, one which cannot be read is
+    // kept in `extra` with a warning and left unset, rather than losing the whole submission.
+    // Identifiers a struct cannot be built without are parsed with `bad_value` instead.
+    pub fn identifier<T>(
+        &mut self,
+        part: &DocumentTree<'_>,
+        parse: fn(&str) -> Option<T>,
+        extra: &mut Vec<(String, String)>,
+    ) -> Result<Option<T>> {
+        match part {
+            DocumentTree::ValueNode(tag, value, span) => match parse(value) {
+                Some(identifier) => Ok(Some(identifier)),
+                None if self.options.lenient => {
+                    self.warn(
+                        WarningKind::BadValue(tag.clone(), value.to_string()),
+                        Some(*span),
+                    );
+                    extra.push((tag.name().to_string(), value.to_string()));
+                    Ok(None)
+                }
+                None => Err(self.bad_value(part)),
+            },
+            _ => Err(self.unexpected(part)),
+        }
+    }
+
+    // Parses an identifier which may only be given once into `slot`. A repeat is a duplicate
+    // even when the first value could not be read and was kept in `extra` instead.
+    pub fn unique_identifier<T>(
+        &mut self,
+        slot: &mut Option<T>,
+        part: &DocumentTree<'_>,
+        parse: fn(&str) -> Option<T>,
+        extra: &mut Vec<(String, String)>,
+    ) -> Result<()> {
+        let seen = match part {
+            DocumentTree::ValueNode(tag, _, _) => extra.iter().any(|(name, _)| name == tag.name()),
+            _ => false,
+        };
+        if seen {
+            return Err(self.duplicate(part));
+        }
+        self.ensure_unset(slot, part)?;
+        *slot = self.identifier(part, parse, extra)?;
+        Ok(())
+    }
+
+    pub fn ensure_unset<T>(&self, slot: &Option<T>, part: &DocumentTree<'_>) -> Result<()> {
+        match slot {
+            None => Ok(()),
+            Some(_) => Err(self.duplicate(part)),
+        }
+    }
+
+    fn duplicate(&self, part: &DocumentTree<'_>) -> ParseError {
+        let kind = match part {
+            DocumentTree::ContainerNode(tag, _, _) => ErrorKind::DuplicateContainer(tag.clone()),
+            DocumentTree::ValueNode(tag, _, _) => ErrorKind::DuplicateValue(tag.clone()),
+            DocumentTree::TextNode(_, _)
+            | DocumentTree::TextRangeNode(_, _)
+            | DocumentTree::Empty => ErrorKind::UnexpectedText,
+        };
+        self.error(kind, part.span())
+    }
+
+    pub fn required_value<T>(&self, slot: Option<T>, tag: ValueTag) -> Result<T> {
+        slot.ok_or_else(|| self.error(ErrorKind::MissingValue(tag), None))
+    }
+
+    pub fn required_container<T>(&self, slot: Option<T>, tag: ContainerTag) -> Result<T> {
+        slot.ok_or_else(|| self.error(ErrorKind::MissingContainer(tag), None))
+    }
+}
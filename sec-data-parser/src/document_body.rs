@@ -1,6 +1,85 @@
+use crate::encoding::{decode, encode, DecodingPolicy, TextEncoding};
+use crate::error::{ErrorKind, ParseError, Result};
+use crate::schema::Document;
+use crate::uuencode;
 use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
 use std::fmt::{Display, Formatter};
-use uuencode::uudecode;
+use std::io::{Read, Seek, SeekFrom};
+
+/// How much of each document's `<TEXT>` to keep. Skipping bodies is much faster when only the
+/// header is needed.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum BodyMode {
+    /// Do not record bodies at all.
+    Skip,
+    /// Record only where each body is in the source.
+    Lazy,
+    /// Decode every body.
+    #[default]
+    Full,
+}
+
+/// The location of a document's `<TEXT>` content within the source, excluding the tags themselves.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct BodyRange {
+    pub offset: usize,
+    pub length: usize,
+}
+
+impl BodyRange {
+    pub fn slice<'a>(&self, source: &'a [u8]) -> Result<&'a [u8]> {
+        source
+            .get(self.offset..self.offset + self.length)
+            .ok_or_else(|| malformed("body range is outside the source"))
+    }
+
+    pub fn read<R: Read + Seek>(&self, reader: &mut R) -> Result<Vec<u8>> {
+        reader.seek(SeekFrom::Start(self.offset as u64))?;
+        let mut bytes = vec![0; self.length];
+        reader.read_exact(&mut bytes)?;
+        Ok(bytes)
+    }
+}
+
+/// A document's body, either decoded up front or left in the source to be decoded on demand.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum Body {
+    Loaded(TypedData),
+    Lazy(BodyRange),
+}
+
+impl Body {
+    /// Decodes a lazy body from the source it was parsed from, such as a memory-mapped file.
+    pub fn load_from_slice(
+        &self,
+        source: &[u8],
+        decoding: DecodingPolicy,
+    ) -> Result<Cow<'_, TypedData>> {
+        match self {
+            Body::Loaded(typed_data) => Ok(Cow::Borrowed(typed_data)),
+            Body::Lazy(range) => {
+                let (typed_data, _) = TypedData::from_bytes(range.slice(source)?, decoding)?;
+                Ok(Cow::Owned(typed_data))
+            }
+        }
+    }
+
+    /// Decodes a lazy body by seeking to it in the source it was parsed from.
+    pub fn load_from_reader<R: Read + Seek>(
+        &self,
+        reader: &mut R,
+        decoding: DecodingPolicy,
+    ) -> Result<Cow<'_, TypedData>> {
+        match self {
+            Body::Loaded(typed_data) => Ok(Cow::Borrowed(typed_data)),
+            Body::Lazy(range) => {
+                let (typed_data, _) = TypedData::from_bytes(&range.read(reader)?, decoding)?;
+                Ok(Cow::Owned(typed_data))
+            }
+        }
+    }
+}
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub enum DataType {
@@ -10,6 +89,17 @@ pub enum DataType {
     Xbrl,
 }
 
+impl DataType {
+    fn wrapper_tag(&self) -> Option<&'static str> {
+        match self {
+            DataType::Plaintext => None,
+            DataType::Xml => Some("XML"),
+            DataType::Pdf => Some("PDF"),
+            DataType::Xbrl => Some("XBRL"),
+        }
+    }
+}
+
 impl Display for DataType {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -25,6 +115,7 @@ impl Display for DataType {
 pub enum DocumentBody {
     BinaryData(String, Vec<u8>),
     Text(String),
+    RawText(Vec<u8>),
 }
 
 impl Display for DocumentBody {
@@ -34,17 +125,24 @@ impl Display for DocumentBody {
                 write!(f, "Binary file {} with {} bytes.", filename, data.len())
             }
             DocumentBody::Text(data) => write!(f, "Text data with {} bytes", data.len()),
+            DocumentBody::RawText(data) => write!(f, "Raw text data with {} bytes", data.len()),
         }
     }
 }
 
+fn malformed(reason: &str) -> ParseError {
+    ParseError::new(ErrorKind::MalformedBody(reason.to_string()))
+}
+
 impl DocumentBody {
-    pub fn from_string(st: &str) -> DocumentBody {
-        if st.starts_with("begin 644") {
-            let (data, filename) = uudecode(st).unwrap();
-            DocumentBody::BinaryData(filename, data)
+    pub fn from_string(st: &str) -> Result<DocumentBody> {
+        // Wrapped bodies such as `<PDF>` put the uuencoded data on the line after the tag.
+        let trimmed = st.trim();
+        if trimmed.starts_with("begin 644") {
+            let (filename, data) = uuencode::decode(trimmed)?;
+            Ok(DocumentBody::BinaryData(filename, data))
         } else {
-            DocumentBody::Text(st.to_string())
+            Ok(DocumentBody::Text(st.to_string()))
         }
     }
 }
@@ -56,35 +154,112 @@ pub struct TypedData {
 }
 
 impl TypedData {
-    pub fn from_string(st: &str) -> TypedData {
+    pub fn from_bytes(bytes: &[u8], decoding: DecodingPolicy) -> Result<(TypedData, TextEncoding)> {
+        if decoding == DecodingPolicy::PreserveRaw && std::str::from_utf8(bytes).is_err() {
+            let typed_data = TypedData {
+                data_type: DataType::Plaintext,
+                body: DocumentBody::RawText(bytes.trim_ascii().to_vec()),
+            };
+            return Ok((typed_data, TextEncoding::Raw));
+        }
+
+        let (st, encoding) = decode(bytes, decoding)?;
+        Ok((TypedData::from_string(&st)?, encoding))
+    }
+
+    pub fn from_string(st: &str) -> Result<TypedData> {
         let st = st.trim();
-        if let Some(st) = st.strip_prefix("<XML>") {
+        Ok(if let Some(st) = st.strip_prefix("<XML>") {
             TypedData {
                 data_type: DataType::Xml,
-                body: DocumentBody::from_string(st.strip_suffix("</XML>").unwrap()),
+                body: DocumentBody::from_string(
+                    st.strip_suffix("</XML>")
+                        .ok_or_else(|| malformed("<XML> is not closed"))?,
+                )?,
             }
         } else if let Some(st) = st.strip_prefix("<PDF>") {
             TypedData {
                 data_type: DataType::Pdf,
-                body: DocumentBody::from_string(st.strip_suffix("</PDF>").unwrap()),
+                body: DocumentBody::from_string(
+                    st.strip_suffix("</PDF>")
+                        .ok_or_else(|| malformed("<PDF> is not closed"))?,
+                )?,
             }
         } else if let Some(st) = st.strip_prefix("<XBRL>") {
             TypedData {
-                data_type: DataType::Pdf,
-                body: DocumentBody::from_string(st.strip_suffix("</XBRL>").unwrap()),
+                data_type: DataType::Xbrl,
+                body: DocumentBody::from_string(
+                    st.strip_suffix("</XBRL>")
+                        .ok_or_else(|| malformed("<XBRL> is not closed"))?,
+                )?,
             }
         } else {
             TypedData {
                 data_type: DataType::Plaintext,
-                body: DocumentBody::from_string(st),
+                body: DocumentBody::from_string(st)?,
             }
+        })
+    }
+
+    /// Renders the content of a `<TEXT>` block, re-encoding text in the encoding it was read with.
+    pub fn to_text_block(&self, encoding: Option<TextEncoding>) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        if let Some(tag) = self.data_type.wrapper_tag() {
+            bytes.extend_from_slice(format!("<{}>", tag).as_bytes());
+        }
+        match &self.body {
+            DocumentBody::BinaryData(filename, data) => {
+                bytes.push(b'\n');
+                bytes.extend_from_slice(uuencode::encode(filename, data).as_bytes());
+                bytes.push(b'\n');
+            }
+            DocumentBody::Text(st) => bytes.extend_from_slice(&encode(st, encoding)),
+            DocumentBody::RawText(raw) => bytes.extend_from_slice(raw),
+        }
+        if let Some(tag) = self.data_type.wrapper_tag() {
+            bytes.extend_from_slice(format!("</{}>", tag).as_bytes());
+        }
+        bytes
+    }
+
+    /// The content of an `<XML>` body, for parsing with one of the crate's XML document types.
+    pub fn xml(&self) -> Option<&str> {
+        match (&self.data_type, &self.body) {
+            (DataType::Xml, DocumentBody::Text(st)) => Some(st),
+            _ => None,
+        }
+    }
+
+    pub fn plain_text(&self) -> Option<&str> {
+        match (&self.data_type, &self.body) {
+            (DataType::Plaintext, DocumentBody::Text(st)) => Some(st),
+            _ => None,
         }
     }
 
     pub fn to_bytes(&self) -> &[u8] {
         match &self.body {
-            DocumentBody::BinaryData(_, b) => &b,
+            DocumentBody::BinaryData(_, b) => b,
             DocumentBody::Text(s) => s.as_bytes(),
+            DocumentBody::RawText(b) => b,
+        }
+    }
+}
+
+impl Document {
+    /// The content of the document's `<XML>` body, if it has one and it was loaded.
+    pub fn xml(&self) -> Option<&str> {
+        match &self.body {
+            Some(Body::Loaded(typed_data)) => typed_data.xml(),
+            _ => None,
+        }
+    }
+
+    /// The document's body if it is plain text and was loaded.
+    pub fn plain_text(&self) -> Option<&str> {
+        match &self.body {
+            Some(Body::Loaded(typed_data)) => typed_data.plain_text(),
+            _ => None,
         }
     }
 }
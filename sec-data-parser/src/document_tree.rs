@@ -1,53 +1,143 @@
-use std::collections::VecDeque;
-
+use crate::document_body::BodyRange;
 use crate::document_tree::DocumentTree::ContainerNode;
+use crate::encoding::{decode_cow, DecodingPolicy};
 use crate::error;
-use crate::error::ParseError;
+use crate::error::{ErrorKind, ParseError};
+use crate::span::Span;
 use crate::tag::{ContainerTag, ValueTag};
 use crate::tokens::Token;
+use std::borrow::Cow;
 
+// The trailing `Span` on each node is the position of its opening tag in the source.
 #[derive(Debug)]
-pub enum DocumentTree {
-    ContainerNode(ContainerTag, Vec<DocumentTree>),
-    ValueNode(ValueTag, String),
-    TextNode(String),
+pub enum DocumentTree<'a> {
+    ContainerNode(ContainerTag, Vec<DocumentTree<'a>>, Span),
+    ValueNode(ValueTag, Cow<'a, str>, Span),
+    TextNode(Cow<'a, [u8]>, Span),
+    TextRangeNode(BodyRange, Span),
     Empty,
 }
 
-pub fn parse_doc(tokens: &mut VecDeque<Token>) -> error::Result<DocumentTree> {
-    Ok(if let Some(token) = tokens.pop_front() {
-        match token {
-            Token::ContainerTagOpen(tag) => {
-                let mut parts = Vec::new();
-
-                while let Some(next_token) = tokens.front() {
-                    if next_token == &Token::ContainerTagClose(tag) {
-                        tokens.pop_front();
-
-                        return Ok(ContainerNode(tag, parts));
-                    } else if let Token::ContainerTagClose(c) = next_token {
-                        panic!("Expected {:?}, got {:?}", c, tag);
-                        //return Ok(ContainerNode(tag, parts));
-                    } else {
-                        parts.push(parse_doc(tokens)?);
-                    }
-                }
+impl<'a> DocumentTree<'a> {
+    // Nodes built from a `Submission` rather than parsed from a source have a default span.
+    pub fn value(tag: ValueTag, value: impl Into<Cow<'a, str>>) -> DocumentTree<'a> {
+        DocumentTree::ValueNode(tag, value.into(), Span::default())
+    }
 
-                return Err(ParseError::UnexpectedEndOfInput(tag));
-            }
-            Token::ContainerTagClose(tag) => return Err(ParseError::UnexpectedCloseTag(tag)),
-            Token::ValueTag(tag) => {
-                let mut value = "".to_string();
-                while let Some(Token::RawText(c)) = tokens.front() {
-                    value.push_str(c);
-                    tokens.pop_front();
+    pub fn optional(tag: ValueTag, value: &'a Option<String>) -> Option<DocumentTree<'a>> {
+        value.as_ref().map(|value| DocumentTree::value(tag, value))
+    }
+
+    // Flags such as `<PAPER>` are written as empty values, and omitted when unset.
+    pub fn flag(tag: ValueTag, set: bool) -> Option<DocumentTree<'a>> {
+        if set {
+            Some(DocumentTree::value(tag, ""))
+        } else {
+            None
+        }
+    }
+
+    pub fn container(tag: ContainerTag, parts: Vec<DocumentTree<'a>>) -> DocumentTree<'a> {
+        ContainerNode(tag, parts, Span::default())
+    }
+
+    pub fn span(&self) -> Option<Span> {
+        match self {
+            DocumentTree::ContainerNode(_, _, span) => Some(*span),
+            DocumentTree::ValueNode(_, _, span) => Some(*span),
+            DocumentTree::TextNode(_, span) => Some(*span),
+            DocumentTree::TextRangeNode(_, span) => Some(*span),
+            DocumentTree::Empty => None,
+        }
+    }
+}
+
+// An unknown opening tag is read as an empty value, because it cannot be told apart from one until
+// its closing tag is found. When that happens, everything after the opening tag is moved inside a
+// new container. Returns false if there is no matching opening tag.
+pub fn close_unknown(parts: &mut Vec<DocumentTree<'_>>, name: &str) -> bool {
+    let start = parts.iter().rposition(|part| match part {
+        DocumentTree::ValueNode(ValueTag::Unknown(n), value, _) => n == name && value.is_empty(),
+        _ => false,
+    });
+
+    match start {
+        Some(start) => {
+            let children = parts.split_off(start + 1);
+            let span = parts.pop().and_then(|part| part.span()).unwrap_or_default();
+            parts.push(ContainerNode(
+                ContainerTag::Unknown(name.to_string()),
+                children,
+                span,
+            ));
+            true
+        }
+        None => false,
+    }
+}
+
+pub fn parse_doc<'a>(
+    tokens: &mut impl Iterator<Item = error::Result<(Token<'a>, Span)>>,
+    decoding: DecodingPolicy,
+) -> error::Result<DocumentTree<'a>> {
+    match tokens.next().transpose()? {
+        Some((token, span)) => parse_node(token, span, tokens, decoding),
+        None => Ok(DocumentTree::Empty),
+    }
+}
+
+pub fn parse_node<'a>(
+    token: Token<'a>,
+    span: Span,
+    tokens: &mut impl Iterator<Item = error::Result<(Token<'a>, Span)>>,
+    decoding: DecodingPolicy,
+) -> error::Result<DocumentTree<'a>> {
+    Ok(match token {
+        Token::ContainerTagOpen(tag) => {
+            let mut parts = Vec::new();
+
+            loop {
+                match tokens
+                    .next()
+                    .transpose()
+                    .map_err(|e| e.within(tag.clone()))?
+                {
+                    Some((Token::ContainerTagClose(c), _)) if c == tag => {
+                        return Ok(ContainerNode(tag, parts, span));
+                    }
+                    Some((Token::ContainerTagClose(ContainerTag::Unknown(name)), _))
+                        if close_unknown(&mut parts, &name) => {}
+                    Some((Token::ContainerTagClose(c), next_span)) => {
+                        return Err(ParseError::new(ErrorKind::MismatchedCloseTag {
+                            expected: tag.clone(),
+                            found: c,
+                        })
+                        .at(next_span)
+                        .within(tag));
+                    }
+                    Some((next_token, next_span)) => parts.push(
+                        parse_node(next_token, next_span, tokens, decoding)
+                            .map_err(|e| e.within(tag.clone()))?,
+                    ),
+                    None => {
+                        return Err(
+                            ParseError::new(ErrorKind::UnexpectedEndOfInput(tag.clone()))
+                                .at(span)
+                                .within(tag),
+                        )
+                    }
                 }
-                DocumentTree::ValueNode(tag, value)
             }
-            Token::TextBlock(text) => DocumentTree::TextNode(text),
-            _ => panic!("Unexpected: {:?}", &token),
         }
-    } else {
-        DocumentTree::Empty
+        Token::ContainerTagClose(tag) => {
+            return Err(ParseError::new(ErrorKind::UnexpectedCloseTag(tag)).at(span))
+        }
+        Token::ValueTag(tag, value) => {
+            let (value, _) = decode_cow(value, decoding).map_err(|e| e.at(span))?;
+            DocumentTree::ValueNode(tag, value, span)
+        }
+        Token::TextBlock(text) => DocumentTree::TextNode(text, span),
+        Token::TextRange(range) => DocumentTree::TextRangeNode(range, span),
+        Token::RawText(_) => return Err(ParseError::new(ErrorKind::UnexpectedText).at(span)),
     })
 }
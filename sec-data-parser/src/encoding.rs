@@ -0,0 +1,71 @@
+use crate::error::{ErrorKind, ParseError, Result};
+use encoding_rs::WINDOWS_1252;
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+
+/// How to turn the bytes of a filing into text. Older filings frequently contain Latin-1 or
+/// Windows-1252 characters, which are not valid UTF-8.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum DecodingPolicy {
+    /// Fail on anything that is not valid UTF-8.
+    Strict,
+    /// Replace invalid UTF-8 sequences with U+FFFD.
+    Lossy,
+    /// Decode as Windows-1252 (a superset of Latin-1) when the text is not valid UTF-8.
+    #[default]
+    Windows1252Fallback,
+    /// Keep document bodies which are not valid UTF-8 as raw bytes. Header values fall back to
+    /// Windows-1252.
+    PreserveRaw,
+}
+
+/// The encoding that was actually used to decode a piece of text.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub enum TextEncoding {
+    Utf8,
+    Utf8Lossy,
+    Windows1252,
+    Raw,
+}
+
+pub fn decode(bytes: &[u8], policy: DecodingPolicy) -> Result<(Cow<'_, str>, TextEncoding)> {
+    match std::str::from_utf8(bytes) {
+        Ok(st) => Ok((Cow::Borrowed(st), TextEncoding::Utf8)),
+        Err(_) => match policy {
+            DecodingPolicy::Strict => Err(ParseError::new(ErrorKind::InvalidUtf8)),
+            DecodingPolicy::Lossy => Ok((String::from_utf8_lossy(bytes), TextEncoding::Utf8Lossy)),
+            DecodingPolicy::Windows1252Fallback | DecodingPolicy::PreserveRaw => {
+                let (st, _) = WINDOWS_1252.decode_without_bom_handling(bytes);
+                Ok((st, TextEncoding::Windows1252))
+            }
+        },
+    }
+}
+
+// Like `decode`, but reuses the allocation of owned bytes rather than copying them.
+pub fn decode_cow(
+    bytes: Cow<'_, [u8]>,
+    policy: DecodingPolicy,
+) -> Result<(Cow<'_, str>, TextEncoding)> {
+    match bytes {
+        Cow::Borrowed(bytes) => decode(bytes, policy),
+        Cow::Owned(bytes) => match String::from_utf8(bytes) {
+            Ok(st) => Ok((Cow::Owned(st), TextEncoding::Utf8)),
+            Err(e) => {
+                let (st, encoding) = decode(e.as_bytes(), policy)?;
+                Ok((Cow::Owned(st.into_owned()), encoding))
+            }
+        },
+    }
+}
+
+// The inverse of `decode`, for writing text back out in the encoding it was read with.
+pub fn encode(st: &str, encoding: Option<TextEncoding>) -> Cow<'_, [u8]> {
+    match encoding {
+        Some(TextEncoding::Windows1252) => {
+            let (bytes, _, _) = WINDOWS_1252.encode(st);
+            bytes
+        }
+        _ => Cow::Borrowed(st.as_bytes()),
+    }
+}
@@ -1,21 +1,164 @@
-use crate::tag::ContainerTag;
+use crate::span::{snippet, Span};
+use crate::tag::{ContainerTag, ValueTag};
 use std::error::Error;
-use std::fmt::{Debug, Display, Formatter};
+use std::fmt::{Display, Formatter};
 
 pub type Result<T> = std::result::Result<T, ParseError>;
 
 #[derive(Debug)]
-pub enum ParseError {
+pub enum ErrorKind {
+    Io(std::io::Error),
+    InvalidUtf8,
     InvalidValueTag(String),
     InvalidContainerTag(String),
+    UnterminatedTag,
+    UnterminatedTextBlock,
     UnexpectedEndOfInput(ContainerTag),
     UnexpectedCloseTag(ContainerTag),
+    MismatchedCloseTag {
+        expected: ContainerTag,
+        found: ContainerTag,
+    },
+    DuplicateValue(ValueTag),
+    DuplicateContainer(ContainerTag),
+    MissingValue(ValueTag),
+    MissingContainer(ContainerTag),
+    UnexpectedValue(ValueTag),
+    UnexpectedContainer(ContainerTag),
+    UnexpectedText,
+    BadValue(ValueTag, Box<str>),
+    MalformedBody(String),
+    MalformedXml(String),
+}
+
+impl Display for ErrorKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ErrorKind::Io(error) => write!(f, "I/O error: {}", error),
+            ErrorKind::InvalidUtf8 => write!(f, "submission is not valid UTF-8"),
+            ErrorKind::InvalidValueTag(tag) => write!(f, "invalid value tag <{}>", tag),
+            ErrorKind::InvalidContainerTag(tag) => write!(f, "invalid container tag <{}>", tag),
+            ErrorKind::UnterminatedTag => write!(f, "tag is missing its closing '>'"),
+            ErrorKind::UnterminatedTextBlock => write!(f, "<TEXT> block is missing </TEXT>"),
+            ErrorKind::UnexpectedEndOfInput(tag) => {
+                write!(f, "input ended before {} was closed", tag)
+            }
+            ErrorKind::UnexpectedCloseTag(tag) => write!(f, "unexpected </{}>", tag),
+            ErrorKind::MismatchedCloseTag { expected, found } => {
+                write!(f, "expected </{}>, found </{}>", expected, found)
+            }
+            ErrorKind::DuplicateValue(tag) => write!(f, "duplicate <{}>", tag),
+            ErrorKind::DuplicateContainer(tag) => write!(f, "duplicate <{}>", tag),
+            ErrorKind::MissingValue(tag) => write!(f, "missing required <{}>", tag),
+            ErrorKind::MissingContainer(tag) => {
+                write!(f, "missing required <{}>", tag)
+            }
+            ErrorKind::UnexpectedValue(tag) => write!(f, "unexpected <{}>", tag),
+            ErrorKind::UnexpectedContainer(tag) => write!(f, "unexpected <{}>", tag),
+            ErrorKind::UnexpectedText => write!(f, "unexpected text"),
+            ErrorKind::BadValue(tag, value) => write!(f, "bad value {:?} for <{}>", value, tag),
+            ErrorKind::MalformedBody(reason) => write!(f, "malformed document body: {}", reason),
+            ErrorKind::MalformedXml(reason) => write!(f, "malformed XML document: {}", reason),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct ParseError {
+    pub kind: ErrorKind,
+    pub accession_number: Option<String>,
+    pub path: Vec<ContainerTag>,
+    pub span: Option<Span>,
+}
+
+impl ParseError {
+    pub fn new(kind: ErrorKind) -> ParseError {
+        ParseError {
+            kind,
+            accession_number: None,
+            path: Vec::new(),
+            span: None,
+        }
+    }
+
+    pub fn at(mut self, span: Span) -> ParseError {
+        self.span.get_or_insert(span);
+        self
+    }
+
+    pub fn within(mut self, tag: ContainerTag) -> ParseError {
+        self.path.insert(0, tag);
+        self
+    }
+
+    pub fn with_accession_number(mut self, accession_number: Option<&str>) -> ParseError {
+        if self.accession_number.is_none() {
+            self.accession_number = accession_number.map(|st| st.to_string());
+        }
+        self
+    }
+
+    /// Describes the error along with the offending line of `source`, which must be the input
+    /// the error came from.
+    pub fn render(&self, source: &[u8]) -> String {
+        let mut out = format!("error: {}\n", self.kind);
+        let gutter = match self.span {
+            Some(span) => {
+                out.push_str(&snippet(source, span));
+                " ".repeat(span.line.to_string().len())
+            }
+            None => String::new(),
+        };
+        if !self.path.is_empty() {
+            out.push_str(&format!("{} = in {}\n", gutter, path_string(&self.path)));
+        }
+        if let Some(accession_number) = &self.accession_number {
+            out.push_str(&format!(
+                "{} = accession number {}\n",
+                gutter, accession_number
+            ));
+        }
+        out
+    }
+}
+
+// Formats a container stack as `SUBMISSION > FILER > COMPANY-DATA`.
+pub fn path_string(path: &[ContainerTag]) -> String {
+    let names: Vec<&str> = path.iter().map(|tag| tag.name()).collect();
+    names.join(" > ")
 }
 
 impl Display for ParseError {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        Debug::fmt(self, f)
+        write!(f, "{}", self.kind)?;
+
+        if !self.path.is_empty() {
+            write!(f, " in {}", path_string(&self.path))?;
+        }
+
+        if let Some(span) = self.span {
+            write!(f, " at {}", span)?;
+        }
+
+        if let Some(accession_number) = &self.accession_number {
+            write!(f, " (accession number {})", accession_number)?;
+        }
+
+        Ok(())
     }
 }
 
-impl Error for ParseError {}
+impl From<std::io::Error> for ParseError {
+    fn from(error: std::io::Error) -> Self {
+        ParseError::new(ErrorKind::Io(error))
+    }
+}
+
+impl Error for ParseError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match &self.kind {
+            ErrorKind::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
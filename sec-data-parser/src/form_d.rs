@@ -0,0 +1,387 @@
+//! Form D, the notice of an offering made without registration under an exemption such as
+//! Regulation D. Since 2009 the notice is an XML document in the submission's primary document.
+
+use crate::error::Result;
+use crate::form_type::Form;
+use crate::schema::{Company, Submission};
+use crate::types::Cik;
+use crate::xml::{
+    self, child, child_string, child_text, child_value, children, optional_value, parse_bool,
+    parse_date, parse_integer, required_text, required_value,
+};
+use chrono::NaiveDate;
+use roxmltree::Node;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FormD {
+    pub schema_version: Option<String>,
+    /// `D`, or `D/A` for an amendment.
+    pub submission_type: String,
+    pub primary_issuer: Issuer,
+    /// Issuers other than the primary issuer which join in the offering.
+    pub other_issuers: Vec<Issuer>,
+    pub related_persons: Vec<RelatedPerson>,
+    pub offering: Offering,
+    pub signatures: Vec<Signature>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Issuer {
+    pub cik: Cik,
+    pub name: String,
+    pub address: Option<PostalAddress>,
+    pub phone: Option<String>,
+    pub jurisdiction_of_incorporation: Option<String>,
+    /// For example `Corporation` or `Limited Partnership`.
+    pub entity_type: Option<String>,
+    pub year_of_incorporation: Option<u16>,
+    /// Whether the issuer was incorporated within the five years before the first sale.
+    pub within_five_years: Option<bool>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PostalAddress {
+    pub street1: Option<String>,
+    pub street2: Option<String>,
+    pub city: Option<String>,
+    /// An EDGAR location code, as in the header's addresses.
+    pub state_or_country: Option<String>,
+    pub state_or_country_description: Option<String>,
+    pub zip_code: Option<String>,
+}
+
+/// An executive officer, director or promoter of the issuer.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RelatedPerson {
+    pub first_name: Option<String>,
+    pub middle_name: Option<String>,
+    pub last_name: Option<String>,
+    pub address: Option<PostalAddress>,
+    pub relationships: Vec<String>,
+    pub relationship_clarification: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Offering {
+    /// For example `Pooled Investment Fund` or `Biotechnology`.
+    pub industry_group: Option<String>,
+    pub investment_fund_type: Option<String>,
+    /// The issuer's revenue range, or for funds its net asset value range.
+    pub issuer_size: Option<String>,
+    /// The rules and exclusions relied on, as written in the notice: `06b` for rule 506(b),
+    /// `06c` for 506(c), `04` for 504, `3C.1` for section 3(c)(1) of the Investment Company Act,
+    /// and so on.
+    pub federal_exemptions: Vec<String>,
+    pub is_amendment: Option<bool>,
+    /// `None` if the first sale is yet to occur.
+    pub date_of_first_sale: Option<NaiveDate>,
+    pub more_than_one_year: Option<bool>,
+    /// The kinds of securities offered, such as `Equity` or `Debt`.
+    pub types_of_securities: Vec<String>,
+    pub business_combination: Option<bool>,
+    pub minimum_investment: Option<u64>,
+    pub sales_compensation: Vec<SalesCompensation>,
+    pub total_offering_amount: Option<OfferingAmount>,
+    pub total_amount_sold: Option<u64>,
+    pub total_remaining: Option<OfferingAmount>,
+    pub has_non_accredited_investors: Option<bool>,
+    pub total_number_already_invested: Option<u64>,
+    pub sales_commissions: Option<u64>,
+    pub finders_fees: Option<u64>,
+    pub gross_proceeds_used: Option<u64>,
+}
+
+/// An amount in dollars, which for ongoing offerings such as funds may be left open.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OfferingAmount {
+    Dollars(u64),
+    Indefinite,
+}
+
+impl OfferingAmount {
+    pub fn parse(st: &str) -> Option<OfferingAmount> {
+        if st.eq_ignore_ascii_case("Indefinite") {
+            return Some(OfferingAmount::Indefinite);
+        }
+        parse_integer(st).map(OfferingAmount::Dollars)
+    }
+}
+
+/// A person paid a commission for sales in the offering, usually a broker-dealer.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SalesCompensation {
+    pub name: Option<String>,
+    pub crd_number: Option<String>,
+    pub associated_broker_dealer: Option<String>,
+    pub associated_broker_dealer_crd_number: Option<String>,
+    pub address: Option<PostalAddress>,
+    /// EDGAR location codes of the states solicited.
+    pub states_of_solicitation: Vec<String>,
+    pub foreign_solicitation: Option<bool>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Signature {
+    pub issuer_name: Option<String>,
+    pub signature_name: Option<String>,
+    pub name_of_signer: Option<String>,
+    pub title: Option<String>,
+    pub date: Option<NaiveDate>,
+}
+
+fn parse_year(st: &str) -> Option<u16> {
+    st.parse().ok()
+}
+
+// Amounts such as `<salesCommissions>` are wrapped in `<dollarAmount>`.
+fn dollar_amount(node: Option<Node<'_, '_>>, name: &'static str) -> Result<Option<u64>> {
+    optional_value(
+        node.and_then(|node| child(node, name)),
+        "dollarAmount",
+        parse_integer,
+    )
+}
+
+fn texts(node: Option<Node<'_, '_>>, name: &'static str) -> Vec<String> {
+    match node {
+        Some(node) => children(node, name)
+            .filter_map(xml::text)
+            .map(|st| st.to_string())
+            .collect(),
+        None => Vec::new(),
+    }
+}
+
+impl PostalAddress {
+    fn from_node(node: Option<Node<'_, '_>>) -> Option<PostalAddress> {
+        let node = node?;
+        Some(PostalAddress {
+            street1: child_string(node, "street1"),
+            street2: child_string(node, "street2"),
+            city: child_string(node, "city"),
+            state_or_country: child_string(node, "stateOrCountry"),
+            state_or_country_description: child_string(node, "stateOrCountryDescription"),
+            zip_code: child_string(node, "zipCode"),
+        })
+    }
+}
+
+impl Issuer {
+    fn from_node(node: Node<'_, '_>) -> Result<Issuer> {
+        let (year_of_incorporation, within_five_years) = match child(node, "yearOfInc") {
+            Some(year) => (
+                child_value(year, "value", parse_year)?,
+                match child_value(year, "withinFiveYears", parse_bool)? {
+                    Some(within) => Some(within),
+                    None => child_value(year, "overFiveYears", parse_bool)?.map(|over| !over),
+                },
+            ),
+            None => (None, None),
+        };
+
+        Ok(Issuer {
+            cik: required_value(node, "cik", Cik::parse)?,
+            name: required_text(node, "entityName")?.to_string(),
+            address: PostalAddress::from_node(child(node, "issuerAddress")),
+            phone: child_string(node, "issuerPhoneNumber"),
+            jurisdiction_of_incorporation: child_string(node, "jurisdictionOfInc"),
+            entity_type: child_string(node, "entityType"),
+            year_of_incorporation,
+            within_five_years,
+        })
+    }
+
+    /// The filer in the submission's header for this issuer.
+    pub fn company<'a>(&self, submission: &'a Submission) -> Option<&'a Company> {
+        submission
+            .filers
+            .iter()
+            .find(|company| company.cik() == Some(self.cik))
+    }
+}
+
+impl RelatedPerson {
+    fn from_node(node: Node<'_, '_>) -> RelatedPerson {
+        let name = child(node, "relatedPersonName");
+        RelatedPerson {
+            first_name: name.and_then(|name| child_string(name, "firstName")),
+            middle_name: name.and_then(|name| child_string(name, "middleName")),
+            last_name: name.and_then(|name| child_string(name, "lastName")),
+            address: PostalAddress::from_node(child(node, "relatedPersonAddress")),
+            relationships: texts(child(node, "relatedPersonRelationshipList"), "relationship"),
+            relationship_clarification: child_string(node, "relationshipClarification"),
+        }
+    }
+}
+
+impl SalesCompensation {
+    fn from_node(node: Node<'_, '_>) -> Result<SalesCompensation> {
+        Ok(SalesCompensation {
+            name: child_string(node, "recipientName"),
+            crd_number: child_string(node, "recipientCRDNumber"),
+            associated_broker_dealer: child_string(node, "associatedBDName"),
+            associated_broker_dealer_crd_number: child_string(node, "associatedBDCRDNumber"),
+            address: PostalAddress::from_node(child(node, "recipientAddress")),
+            states_of_solicitation: texts(child(node, "statesOfSolicitationList"), "state"),
+            foreign_solicitation: child_value(node, "foreignSolicitation", parse_bool)?,
+        })
+    }
+}
+
+impl Offering {
+    fn from_node(node: Node<'_, '_>) -> Result<Offering> {
+        let industry = child(node, "industryGroup");
+        let fund = industry.and_then(|industry| child(industry, "investmentFundInfo"));
+        let size = child(node, "issuerSize");
+        let filing = child(node, "typeOfFiling");
+        let amounts = child(node, "offeringSalesAmounts");
+        let investors = child(node, "investors");
+        let fees = child(node, "salesCommissionsFindersFees");
+        let proceeds = child(node, "useOfProceeds");
+
+        let mut types_of_securities = Vec::new();
+        if let Some(types) = child(node, "typesOfSecuritiesOffered") {
+            for flag in types.children().filter(|n| n.is_element()) {
+                let name = flag.tag_name().name();
+                if xml::text(flag).and_then(parse_bool) == Some(true) {
+                    let name = name.strip_prefix("is").unwrap_or(name);
+                    types_of_securities.push(name.strip_suffix("Type").unwrap_or(name).to_string());
+                }
+            }
+        }
+
+        let mut sales_compensation = Vec::new();
+        if let Some(list) = child(node, "salesCompensationList") {
+            for recipient in children(list, "recipient") {
+                sales_compensation.push(SalesCompensation::from_node(recipient)?);
+            }
+        }
+
+        Ok(Offering {
+            industry_group: industry.and_then(|node| child_string(node, "industryGroupType")),
+            investment_fund_type: fund.and_then(|node| child_string(node, "investmentFundType")),
+            issuer_size: size.and_then(|node| {
+                child_string(node, "revenueRange")
+                    .or_else(|| child_string(node, "aggregateNetAssetValueRange"))
+            }),
+            federal_exemptions: texts(child(node, "federalExemptionsExclusions"), "item"),
+            is_amendment: optional_value(
+                filing.and_then(|node| child(node, "newOrAmendment")),
+                "isAmendment",
+                parse_bool,
+            )?,
+            date_of_first_sale: optional_value(
+                filing.and_then(|node| child(node, "dateOfFirstSale")),
+                "value",
+                parse_date,
+            )?,
+            more_than_one_year: optional_value(
+                child(node, "durationOfOffering"),
+                "moreThanOneYear",
+                parse_bool,
+            )?,
+            types_of_securities,
+            business_combination: optional_value(
+                child(node, "businessCombinationTransaction"),
+                "isBusinessCombinationTransaction",
+                parse_bool,
+            )?,
+            minimum_investment: child_value(node, "minimumInvestmentAccepted", parse_integer)?,
+            sales_compensation,
+            total_offering_amount: optional_value(
+                amounts,
+                "totalOfferingAmount",
+                OfferingAmount::parse,
+            )?,
+            total_amount_sold: optional_value(amounts, "totalAmountSold", parse_integer)?,
+            total_remaining: optional_value(amounts, "totalRemaining", OfferingAmount::parse)?,
+            has_non_accredited_investors: optional_value(
+                investors,
+                "hasNonAccreditedInvestors",
+                parse_bool,
+            )?,
+            total_number_already_invested: optional_value(
+                investors,
+                "totalNumberAlreadyInvested",
+                parse_integer,
+            )?,
+            sales_commissions: dollar_amount(fees, "salesCommissions")?,
+            finders_fees: dollar_amount(fees, "findersFees")?,
+            gross_proceeds_used: dollar_amount(proceeds, "grossProceedsUsed")?,
+        })
+    }
+}
+
+impl FormD {
+    /// Parses the XML of a Form D, without the `<XML>` wrapper.
+    pub fn parse(st: &str) -> Result<FormD> {
+        let document = xml::parse(st)?;
+        let root = xml::root(&document, "edgarSubmission")?;
+
+        let primary_issuer = child(root, "primaryIssuer")
+            .ok_or_else(|| xml::malformed("missing <primaryIssuer>".into()))?;
+        let offering = child(root, "offeringData")
+            .ok_or_else(|| xml::malformed("missing <offeringData>".into()))?;
+
+        let mut other_issuers = Vec::new();
+        if let Some(list) = child(root, "issuerList") {
+            for issuer in children(list, "issuer") {
+                other_issuers.push(Issuer::from_node(issuer)?);
+            }
+        }
+
+        let related_persons = match child(root, "relatedPersonsList") {
+            Some(list) => children(list, "relatedPersonInfo")
+                .map(RelatedPerson::from_node)
+                .collect(),
+            None => Vec::new(),
+        };
+
+        let mut signatures = Vec::new();
+        if let Some(block) = child(offering, "signatureBlock") {
+            for signature in children(block, "signature") {
+                signatures.push(Signature {
+                    issuer_name: child_string(signature, "issuerName"),
+                    signature_name: child_string(signature, "signatureName"),
+                    name_of_signer: child_string(signature, "nameOfSigner"),
+                    title: child_string(signature, "signatureTitle"),
+                    date: child_value(signature, "signatureDate", parse_date)?,
+                });
+            }
+        }
+
+        Ok(FormD {
+            schema_version: child_string(root, "schemaVersion"),
+            submission_type: child_text(root, "submissionType")
+                .unwrap_or("D")
+                .to_string(),
+            primary_issuer: Issuer::from_node(primary_issuer)?,
+            other_issuers,
+            related_persons,
+            offering: Offering::from_node(offering)?,
+            signatures,
+        })
+    }
+
+    /// The primary issuer followed by any other issuers.
+    pub fn issuers(&self) -> impl Iterator<Item = &Issuer> {
+        std::iter::once(&self.primary_issuer).chain(&self.other_issuers)
+    }
+}
+
+impl Submission {
+    /// Parses the notice of a Form D. Returns `None` for other forms, for notices filed before
+    /// the XML format, and when bodies were not loaded.
+    pub fn form_d(&self) -> Option<Result<FormD>> {
+        if self.form().form != Form::FormD {
+            return None;
+        }
+        let xml = self
+            .documents
+            .iter()
+            .filter(|document| document.form().form == Form::FormD)
+            .find_map(|document| document.xml())?;
+        Some(FormD::parse(xml))
+    }
+}
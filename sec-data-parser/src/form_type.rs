@@ -0,0 +1,336 @@
+use crate::schema::{Document, FilingValues, Submission};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// An EDGAR form, without any `/A` amendment marker. Forms this crate does not list are kept as
+/// `Other`.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
+pub enum Form {
+    TenK,
+    TenK405,
+    TenKsb,
+    TenKt,
+    ElevenK,
+    TwentyF,
+    FortyF,
+    TenQ,
+    TenQsb,
+    EightK,
+    SixK,
+    S1,
+    S3,
+    S4,
+    S8,
+    S11,
+    F1,
+    F3,
+    F4,
+    TenTwelveB,
+    TenTwelveG,
+    /// A post-effective amendment to a fund's registration statement.
+    PostEffective485B,
+    Form497,
+    Def14A,
+    Defa14A,
+    Pre14A,
+    Form3,
+    Form4,
+    Form5,
+    Sc13D,
+    Sc13G,
+    /// Schedules 13D and 13G as named since they moved to XML in December 2024.
+    Schedule13D,
+    Schedule13G,
+    ThirteenFHr,
+    ThirteenFNt,
+    FormD,
+    Form144,
+    NportP,
+    NCsr,
+    NCsrs,
+    NQ,
+    NCen,
+    Corresp,
+    Upload,
+    /// A prospectus filed under one of the paragraphs of rule 424(b), e.g. `424B3`.
+    Prospectus424B(u8),
+    Other(String),
+}
+
+impl Form {
+    pub fn parse(st: &str) -> Form {
+        match st {
+            "10-K" => Form::TenK,
+            "10-K405" => Form::TenK405,
+            "10-KSB" => Form::TenKsb,
+            "10-KT" => Form::TenKt,
+            "11-K" => Form::ElevenK,
+            "20-F" => Form::TwentyF,
+            "40-F" => Form::FortyF,
+            "10-Q" => Form::TenQ,
+            "10-QSB" => Form::TenQsb,
+            "8-K" => Form::EightK,
+            "6-K" => Form::SixK,
+            "S-1" => Form::S1,
+            "S-3" => Form::S3,
+            "S-4" => Form::S4,
+            "S-8" => Form::S8,
+            "S-11" => Form::S11,
+            "F-1" => Form::F1,
+            "F-3" => Form::F3,
+            "F-4" => Form::F4,
+            "10-12B" => Form::TenTwelveB,
+            "10-12G" => Form::TenTwelveG,
+            "485BPOS" => Form::PostEffective485B,
+            "497" => Form::Form497,
+            "DEF 14A" => Form::Def14A,
+            "DEFA14A" => Form::Defa14A,
+            "PRE 14A" => Form::Pre14A,
+            "3" => Form::Form3,
+            "4" => Form::Form4,
+            "5" => Form::Form5,
+            "SC 13D" => Form::Sc13D,
+            "SC 13G" => Form::Sc13G,
+            "SCHEDULE 13D" => Form::Schedule13D,
+            "SCHEDULE 13G" => Form::Schedule13G,
+            "13F-HR" => Form::ThirteenFHr,
+            "13F-NT" => Form::ThirteenFNt,
+            "D" => Form::FormD,
+            "144" => Form::Form144,
+            "NPORT-P" => Form::NportP,
+            "N-CSR" => Form::NCsr,
+            "N-CSRS" => Form::NCsrs,
+            "N-Q" => Form::NQ,
+            "N-CEN" => Form::NCen,
+            "CORRESP" => Form::Corresp,
+            "UPLOAD" => Form::Upload,
+            _ => match st
+                .strip_prefix("424B")
+                .filter(|n| n.len() == 1)
+                .and_then(|n| n.parse().ok())
+            {
+                Some(paragraph) => Form::Prospectus424B(paragraph),
+                None => Form::Other(st.to_string()),
+            },
+        }
+    }
+
+    pub fn family(&self) -> Option<FormFamily> {
+        Some(match self {
+            Form::TenK
+            | Form::TenK405
+            | Form::TenKsb
+            | Form::TenKt
+            | Form::ElevenK
+            | Form::TwentyF
+            | Form::FortyF => FormFamily::AnnualReport,
+            Form::TenQ | Form::TenQsb => FormFamily::QuarterlyReport,
+            Form::EightK | Form::SixK => FormFamily::CurrentReport,
+            Form::S1
+            | Form::S3
+            | Form::S4
+            | Form::S8
+            | Form::S11
+            | Form::F1
+            | Form::F3
+            | Form::F4
+            | Form::TenTwelveB
+            | Form::TenTwelveG => FormFamily::Registration,
+            Form::PostEffective485B | Form::Form497 | Form::Prospectus424B(_) => {
+                FormFamily::Prospectus
+            }
+            Form::Def14A | Form::Defa14A | Form::Pre14A => FormFamily::Proxy,
+            Form::Form3 | Form::Form4 | Form::Form5 => FormFamily::InsiderOwnership,
+            Form::Sc13D | Form::Sc13G | Form::Schedule13D | Form::Schedule13G => {
+                FormFamily::BeneficialOwnership
+            }
+            Form::ThirteenFHr | Form::ThirteenFNt => FormFamily::InstitutionalHoldings,
+            Form::FormD | Form::Form144 => FormFamily::ExemptOffering,
+            Form::NportP | Form::NCsr | Form::NCsrs | Form::NQ | Form::NCen => {
+                FormFamily::FundReport
+            }
+            Form::Corresp | Form::Upload => FormFamily::Correspondence,
+            Form::Other(_) => return None,
+        })
+    }
+}
+
+impl Display for Form {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Form::TenK => "10-K",
+            Form::TenK405 => "10-K405",
+            Form::TenKsb => "10-KSB",
+            Form::TenKt => "10-KT",
+            Form::ElevenK => "11-K",
+            Form::TwentyF => "20-F",
+            Form::FortyF => "40-F",
+            Form::TenQ => "10-Q",
+            Form::TenQsb => "10-QSB",
+            Form::EightK => "8-K",
+            Form::SixK => "6-K",
+            Form::S1 => "S-1",
+            Form::S3 => "S-3",
+            Form::S4 => "S-4",
+            Form::S8 => "S-8",
+            Form::S11 => "S-11",
+            Form::F1 => "F-1",
+            Form::F3 => "F-3",
+            Form::F4 => "F-4",
+            Form::TenTwelveB => "10-12B",
+            Form::TenTwelveG => "10-12G",
+            Form::PostEffective485B => "485BPOS",
+            Form::Form497 => "497",
+            Form::Def14A => "DEF 14A",
+            Form::Defa14A => "DEFA14A",
+            Form::Pre14A => "PRE 14A",
+            Form::Form3 => "3",
+            Form::Form4 => "4",
+            Form::Form5 => "5",
+            Form::Sc13D => "SC 13D",
+            Form::Sc13G => "SC 13G",
+            Form::Schedule13D => "SCHEDULE 13D",
+            Form::Schedule13G => "SCHEDULE 13G",
+            Form::ThirteenFHr => "13F-HR",
+            Form::ThirteenFNt => "13F-NT",
+            Form::FormD => "D",
+            Form::Form144 => "144",
+            Form::NportP => "NPORT-P",
+            Form::NCsr => "N-CSR",
+            Form::NCsrs => "N-CSRS",
+            Form::NQ => "N-Q",
+            Form::NCen => "N-CEN",
+            Form::Corresp => "CORRESP",
+            Form::Upload => "UPLOAD",
+            Form::Prospectus424B(paragraph) => return write!(f, "424B{}", paragraph),
+            Form::Other(name) => name,
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Broad groups of forms, for filtering submissions without listing every variant.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Serialize, Deserialize)]
+pub enum FormFamily {
+    AnnualReport,
+    QuarterlyReport,
+    CurrentReport,
+    Registration,
+    Prospectus,
+    Proxy,
+    /// Forms 3, 4 and 5, filed by insiders.
+    InsiderOwnership,
+    /// Schedules 13D and 13G, filed by holders of more than 5% of a class.
+    BeneficialOwnership,
+    /// Form 13F, filed by institutional investment managers.
+    InstitutionalHoldings,
+    ExemptOffering,
+    FundReport,
+    Correspondence,
+}
+
+/// A form type as written in a submission, such as `10-K/A`: the form and whether it amends an
+/// earlier filing. Displays as it was parsed.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
+pub struct FormType {
+    pub form: Form,
+    pub amendment: bool,
+}
+
+impl FormType {
+    pub fn parse(st: &str) -> FormType {
+        match st.strip_suffix("/A") {
+            Some(form) => FormType {
+                form: Form::parse(form),
+                amendment: true,
+            },
+            None => FormType {
+                form: Form::parse(st),
+                amendment: false,
+            },
+        }
+    }
+
+    pub fn family(&self) -> Option<FormFamily> {
+        self.form.family()
+    }
+}
+
+impl Display for FormType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.form)?;
+        if self.amendment {
+            write!(f, "/A")?;
+        }
+        Ok(())
+    }
+}
+
+/// The type of an exhibit document, `EX-<number>` optionally followed by `.<suffix>`, as in
+/// `EX-99.1` or `EX-101.INS`.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
+pub struct ExhibitType {
+    pub number: u32,
+    pub suffix: Option<String>,
+}
+
+impl ExhibitType {
+    pub fn parse(st: &str) -> Option<ExhibitType> {
+        let rest = st.strip_prefix("EX-")?;
+        let digits = rest
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len());
+        let number = rest[..digits].parse().ok()?;
+        let suffix = match &rest[digits..] {
+            "" => None,
+            suffix => Some(
+                suffix
+                    .strip_prefix('.')
+                    .filter(|s| !s.is_empty())?
+                    .to_string(),
+            ),
+        };
+
+        Some(ExhibitType { number, suffix })
+    }
+
+    /// The suffix as a number, for the common case of exhibits like `EX-10.3`.
+    pub fn sub_number(&self) -> Option<u32> {
+        self.suffix.as_ref()?.parse().ok()
+    }
+}
+
+impl Display for ExhibitType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "EX-{}", self.number)?;
+        if let Some(suffix) = &self.suffix {
+            write!(f, ".{}", suffix)?;
+        }
+        Ok(())
+    }
+}
+
+impl Submission {
+    pub fn form(&self) -> FormType {
+        FormType::parse(&self.filing_type)
+    }
+}
+
+impl FilingValues {
+    pub fn form(&self) -> FormType {
+        FormType::parse(&self.form_type)
+    }
+}
+
+impl Document {
+    /// The document type read as a form, which for the primary document is usually the
+    /// submission's form.
+    pub fn form(&self) -> FormType {
+        FormType::parse(&self.doc_type)
+    }
+
+    /// The document type read as an exhibit, or `None` if it is not one.
+    pub fn exhibit(&self) -> Option<ExhibitType> {
+        ExhibitType::parse(&self.doc_type)
+    }
+}
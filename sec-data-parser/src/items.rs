@@ -0,0 +1,267 @@
+use crate::schema::Submission;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// An item reported by a Form 8-K, as listed in the submission's `<ITEMS>`. The numbering in use
+/// since August 2004 is `<section>.<item>`, e.g. `2.02`; earlier filings number items 1 to 12,
+/// which are the `Legacy` variants. Anything else is kept as `Other`.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
+pub enum Item8K {
+    EntryIntoMaterialDefinitiveAgreement,
+    TerminationOfMaterialDefinitiveAgreement,
+    BankruptcyOrReceivership,
+    MineSafety,
+    MaterialCybersecurityIncident,
+    CompletionOfAcquisitionOrDisposition,
+    ResultsOfOperations,
+    CreationOfDirectFinancialObligation,
+    TriggeringEvents,
+    ExitOrDisposalCosts,
+    MaterialImpairments,
+    DelistingNotice,
+    UnregisteredSalesOfEquity,
+    MaterialModificationOfRights,
+    ChangeInAccountant,
+    NonRelianceOnFinancialStatements,
+    ChangeInControl,
+    DepartureOfDirectorsOrOfficers,
+    AmendmentsToArticlesOrBylaws,
+    TradingSuspensionUnderBenefitPlans,
+    CodeOfEthicsAmendment,
+    ShellCompanyStatusChange,
+    ShareholderVote,
+    ShareholderDirectorNominations,
+    AbsInformationalMaterial,
+    ChangeOfServicerOrTrustee,
+    ChangeInCreditEnhancement,
+    FailureToMakeDistribution,
+    SecuritiesActUpdatingDisclosure,
+    StaticPool,
+    RegulationFdDisclosure,
+    OtherEvents,
+    FinancialStatementsAndExhibits,
+    LegacyChangeInControl,
+    LegacyAcquisitionOrDisposition,
+    LegacyBankruptcyOrReceivership,
+    LegacyChangeInAccountant,
+    LegacyOtherEvents,
+    LegacyDirectorResignation,
+    LegacyFinancialStatementsAndExhibits,
+    LegacyChangeInFiscalYear,
+    /// Item 9 was "Sales of Equity Securities Pursuant to Regulation S" until 2000.
+    LegacyRegulationFdDisclosure,
+    LegacyCodeOfEthicsAmendment,
+    LegacyTradingSuspensionUnderBenefitPlans,
+    LegacyResultsOfOperations,
+    Other(String),
+}
+
+impl Item8K {
+    pub fn parse(st: &str) -> Item8K {
+        match st {
+            "1.01" => Item8K::EntryIntoMaterialDefinitiveAgreement,
+            "1.02" => Item8K::TerminationOfMaterialDefinitiveAgreement,
+            "1.03" => Item8K::BankruptcyOrReceivership,
+            "1.04" => Item8K::MineSafety,
+            "1.05" => Item8K::MaterialCybersecurityIncident,
+            "2.01" => Item8K::CompletionOfAcquisitionOrDisposition,
+            "2.02" => Item8K::ResultsOfOperations,
+            "2.03" => Item8K::CreationOfDirectFinancialObligation,
+            "2.04" => Item8K::TriggeringEvents,
+            "2.05" => Item8K::ExitOrDisposalCosts,
+            "2.06" => Item8K::MaterialImpairments,
+            "3.01" => Item8K::DelistingNotice,
+            "3.02" => Item8K::UnregisteredSalesOfEquity,
+            "3.03" => Item8K::MaterialModificationOfRights,
+            "4.01" => Item8K::ChangeInAccountant,
+            "4.02" => Item8K::NonRelianceOnFinancialStatements,
+            "5.01" => Item8K::ChangeInControl,
+            "5.02" => Item8K::DepartureOfDirectorsOrOfficers,
+            "5.03" => Item8K::AmendmentsToArticlesOrBylaws,
+            "5.04" => Item8K::TradingSuspensionUnderBenefitPlans,
+            "5.05" => Item8K::CodeOfEthicsAmendment,
+            "5.06" => Item8K::ShellCompanyStatusChange,
+            "5.07" => Item8K::ShareholderVote,
+            "5.08" => Item8K::ShareholderDirectorNominations,
+            "6.01" => Item8K::AbsInformationalMaterial,
+            "6.02" => Item8K::ChangeOfServicerOrTrustee,
+            "6.03" => Item8K::ChangeInCreditEnhancement,
+            "6.04" => Item8K::FailureToMakeDistribution,
+            "6.05" => Item8K::SecuritiesActUpdatingDisclosure,
+            "6.06" => Item8K::StaticPool,
+            "7.01" => Item8K::RegulationFdDisclosure,
+            "8.01" => Item8K::OtherEvents,
+            "9.01" => Item8K::FinancialStatementsAndExhibits,
+            "1" => Item8K::LegacyChangeInControl,
+            "2" => Item8K::LegacyAcquisitionOrDisposition,
+            "3" => Item8K::LegacyBankruptcyOrReceivership,
+            "4" => Item8K::LegacyChangeInAccountant,
+            "5" => Item8K::LegacyOtherEvents,
+            "6" => Item8K::LegacyDirectorResignation,
+            "7" => Item8K::LegacyFinancialStatementsAndExhibits,
+            "8" => Item8K::LegacyChangeInFiscalYear,
+            "9" => Item8K::LegacyRegulationFdDisclosure,
+            "10" => Item8K::LegacyCodeOfEthicsAmendment,
+            "11" => Item8K::LegacyTradingSuspensionUnderBenefitPlans,
+            "12" => Item8K::LegacyResultsOfOperations,
+            _ => Item8K::Other(st.to_string()),
+        }
+    }
+
+    /// The item's number as it appears in the header.
+    pub fn code(&self) -> &str {
+        match self {
+            Item8K::EntryIntoMaterialDefinitiveAgreement => "1.01",
+            Item8K::TerminationOfMaterialDefinitiveAgreement => "1.02",
+            Item8K::BankruptcyOrReceivership => "1.03",
+            Item8K::MineSafety => "1.04",
+            Item8K::MaterialCybersecurityIncident => "1.05",
+            Item8K::CompletionOfAcquisitionOrDisposition => "2.01",
+            Item8K::ResultsOfOperations => "2.02",
+            Item8K::CreationOfDirectFinancialObligation => "2.03",
+            Item8K::TriggeringEvents => "2.04",
+            Item8K::ExitOrDisposalCosts => "2.05",
+            Item8K::MaterialImpairments => "2.06",
+            Item8K::DelistingNotice => "3.01",
+            Item8K::UnregisteredSalesOfEquity => "3.02",
+            Item8K::MaterialModificationOfRights => "3.03",
+            Item8K::ChangeInAccountant => "4.01",
+            Item8K::NonRelianceOnFinancialStatements => "4.02",
+            Item8K::ChangeInControl => "5.01",
+            Item8K::DepartureOfDirectorsOrOfficers => "5.02",
+            Item8K::AmendmentsToArticlesOrBylaws => "5.03",
+            Item8K::TradingSuspensionUnderBenefitPlans => "5.04",
+            Item8K::CodeOfEthicsAmendment => "5.05",
+            Item8K::ShellCompanyStatusChange => "5.06",
+            Item8K::ShareholderVote => "5.07",
+            Item8K::ShareholderDirectorNominations => "5.08",
+            Item8K::AbsInformationalMaterial => "6.01",
+            Item8K::ChangeOfServicerOrTrustee => "6.02",
+            Item8K::ChangeInCreditEnhancement => "6.03",
+            Item8K::FailureToMakeDistribution => "6.04",
+            Item8K::SecuritiesActUpdatingDisclosure => "6.05",
+            Item8K::StaticPool => "6.06",
+            Item8K::RegulationFdDisclosure => "7.01",
+            Item8K::OtherEvents => "8.01",
+            Item8K::FinancialStatementsAndExhibits => "9.01",
+            Item8K::LegacyChangeInControl => "1",
+            Item8K::LegacyAcquisitionOrDisposition => "2",
+            Item8K::LegacyBankruptcyOrReceivership => "3",
+            Item8K::LegacyChangeInAccountant => "4",
+            Item8K::LegacyOtherEvents => "5",
+            Item8K::LegacyDirectorResignation => "6",
+            Item8K::LegacyFinancialStatementsAndExhibits => "7",
+            Item8K::LegacyChangeInFiscalYear => "8",
+            Item8K::LegacyRegulationFdDisclosure => "9",
+            Item8K::LegacyCodeOfEthicsAmendment => "10",
+            Item8K::LegacyTradingSuspensionUnderBenefitPlans => "11",
+            Item8K::LegacyResultsOfOperations => "12",
+            Item8K::Other(code) => code,
+        }
+    }
+
+    /// The item's caption in the form, or `None` for `Other`.
+    pub fn title(&self) -> Option<&'static str> {
+        Some(match self {
+            Item8K::EntryIntoMaterialDefinitiveAgreement => "Entry into a Material Definitive Agreement",
+            Item8K::TerminationOfMaterialDefinitiveAgreement => "Termination of a Material Definitive Agreement",
+            Item8K::BankruptcyOrReceivership => "Bankruptcy or Receivership",
+            Item8K::MineSafety => "Mine Safety - Reporting of Shutdowns and Patterns of Violations",
+            Item8K::MaterialCybersecurityIncident => "Material Cybersecurity Incidents",
+            Item8K::CompletionOfAcquisitionOrDisposition => "Completion of Acquisition or Disposition of Assets",
+            Item8K::ResultsOfOperations => "Results of Operations and Financial Condition",
+            Item8K::CreationOfDirectFinancialObligation => "Creation of a Direct Financial Obligation or an Obligation under an Off-Balance Sheet Arrangement of a Registrant",
+            Item8K::TriggeringEvents => "Triggering Events That Accelerate or Increase a Direct Financial Obligation or an Obligation under an Off-Balance Sheet Arrangement",
+            Item8K::ExitOrDisposalCosts => "Costs Associated with Exit or Disposal Activities",
+            Item8K::MaterialImpairments => "Material Impairments",
+            Item8K::DelistingNotice => "Notice of Delisting or Failure to Satisfy a Continued Listing Rule or Standard; Transfer of Listing",
+            Item8K::UnregisteredSalesOfEquity => "Unregistered Sales of Equity Securities",
+            Item8K::MaterialModificationOfRights => "Material Modification to Rights of Security Holders",
+            Item8K::ChangeInAccountant => "Changes in Registrant's Certifying Accountant",
+            Item8K::NonRelianceOnFinancialStatements => "Non-Reliance on Previously Issued Financial Statements or a Related Audit Report or Completed Interim Review",
+            Item8K::ChangeInControl => "Changes in Control of Registrant",
+            Item8K::DepartureOfDirectorsOrOfficers => "Departure of Directors or Certain Officers; Election of Directors; Appointment of Certain Officers; Compensatory Arrangements of Certain Officers",
+            Item8K::AmendmentsToArticlesOrBylaws => "Amendments to Articles of Incorporation or Bylaws; Change in Fiscal Year",
+            Item8K::TradingSuspensionUnderBenefitPlans => "Temporary Suspension of Trading Under Registrant's Employee Benefit Plans",
+            Item8K::CodeOfEthicsAmendment => "Amendment to Registrant's Code of Ethics, or Waiver of a Provision of the Code of Ethics",
+            Item8K::ShellCompanyStatusChange => "Change in Shell Company Status",
+            Item8K::ShareholderVote => "Submission of Matters to a Vote of Security Holders",
+            Item8K::ShareholderDirectorNominations => "Shareholder Director Nominations",
+            Item8K::AbsInformationalMaterial => "ABS Informational and Computational Material",
+            Item8K::ChangeOfServicerOrTrustee => "Change of Servicer or Trustee",
+            Item8K::ChangeInCreditEnhancement => "Change in Credit Enhancement or Other External Support",
+            Item8K::FailureToMakeDistribution => "Failure to Make a Required Distribution",
+            Item8K::SecuritiesActUpdatingDisclosure => "Securities Act Updating Disclosure",
+            Item8K::StaticPool => "Static Pool",
+            Item8K::RegulationFdDisclosure => "Regulation FD Disclosure",
+            Item8K::OtherEvents => "Other Events",
+            Item8K::FinancialStatementsAndExhibits => "Financial Statements and Exhibits",
+            Item8K::LegacyChangeInControl => "Changes in Control of Registrant",
+            Item8K::LegacyAcquisitionOrDisposition => "Acquisition or Disposition of Assets",
+            Item8K::LegacyBankruptcyOrReceivership => "Bankruptcy or Receivership",
+            Item8K::LegacyChangeInAccountant => "Changes in Registrant's Certifying Accountant",
+            Item8K::LegacyOtherEvents => "Other Events",
+            Item8K::LegacyDirectorResignation => "Resignations of Registrant's Directors",
+            Item8K::LegacyFinancialStatementsAndExhibits => "Financial Statements and Exhibits",
+            Item8K::LegacyChangeInFiscalYear => "Change in Fiscal Year",
+            Item8K::LegacyRegulationFdDisclosure => "Regulation FD Disclosure",
+            Item8K::LegacyCodeOfEthicsAmendment => "Amendments to the Registrant's Code of Ethics, or Waiver of a Provision of the Code of Ethics",
+            Item8K::LegacyTradingSuspensionUnderBenefitPlans => "Temporary Suspension of Trading Under Registrant's Employee Benefit Plans",
+            Item8K::LegacyResultsOfOperations => "Results of Operations and Financial Condition",
+            Item8K::Other(_) => return None,
+        })
+    }
+
+    pub fn is_legacy(&self) -> bool {
+        matches!(
+            self,
+            Item8K::LegacyChangeInControl
+                | Item8K::LegacyAcquisitionOrDisposition
+                | Item8K::LegacyBankruptcyOrReceivership
+                | Item8K::LegacyChangeInAccountant
+                | Item8K::LegacyOtherEvents
+                | Item8K::LegacyDirectorResignation
+                | Item8K::LegacyFinancialStatementsAndExhibits
+                | Item8K::LegacyChangeInFiscalYear
+                | Item8K::LegacyRegulationFdDisclosure
+                | Item8K::LegacyCodeOfEthicsAmendment
+                | Item8K::LegacyTradingSuspensionUnderBenefitPlans
+                | Item8K::LegacyResultsOfOperations
+        )
+    }
+
+    /// The item under the current numbering which replaced a legacy item, so that filings from
+    /// before and after 2004 can be treated alike. Other items are returned unchanged.
+    pub fn to_current(&self) -> Item8K {
+        match self {
+            Item8K::LegacyChangeInControl => Item8K::ChangeInControl,
+            Item8K::LegacyAcquisitionOrDisposition => Item8K::CompletionOfAcquisitionOrDisposition,
+            Item8K::LegacyBankruptcyOrReceivership => Item8K::BankruptcyOrReceivership,
+            Item8K::LegacyChangeInAccountant => Item8K::ChangeInAccountant,
+            Item8K::LegacyOtherEvents => Item8K::OtherEvents,
+            Item8K::LegacyDirectorResignation => Item8K::DepartureOfDirectorsOrOfficers,
+            Item8K::LegacyFinancialStatementsAndExhibits => Item8K::FinancialStatementsAndExhibits,
+            Item8K::LegacyChangeInFiscalYear => Item8K::AmendmentsToArticlesOrBylaws,
+            Item8K::LegacyRegulationFdDisclosure => Item8K::RegulationFdDisclosure,
+            Item8K::LegacyCodeOfEthicsAmendment => Item8K::CodeOfEthicsAmendment,
+            Item8K::LegacyTradingSuspensionUnderBenefitPlans => {
+                Item8K::TradingSuspensionUnderBenefitPlans
+            }
+            Item8K::LegacyResultsOfOperations => Item8K::ResultsOfOperations,
+            item => item.clone(),
+        }
+    }
+}
+
+impl Display for Item8K {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.code())
+    }
+}
+
+impl Submission {
+    /// `items` read as 8-K items.
+    pub fn items_8k(&self) -> impl Iterator<Item = Item8K> + '_ {
+        self.items.iter().map(|item| Item8K::parse(item))
+    }
+}
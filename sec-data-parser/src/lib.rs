@@ -1,30 +1,133 @@
+pub use crate::address::{NormalizedAddress, State, Zip};
+pub use crate::archive::{FeedArchive, FeedSubmissions};
+pub use crate::borrowed::{ContainerRef, SubmissionRef};
 pub use crate::document_body::*;
-use crate::document_tree::parse_doc;
-use crate::document_tree::DocumentTree;
-use crate::error::Result;
+pub use crate::document_tree::DocumentTree;
+pub use crate::encoding::{DecodingPolicy, TextEncoding};
+pub use crate::error::{ErrorKind, ParseError, Result};
+pub use crate::form_type::{ExhibitType, Form, FormFamily, FormType};
+pub use crate::items::Item8K;
+pub use crate::location::Location;
 pub use crate::schema::*;
-use crate::tag::ContainerTag;
-use crate::tokens::tokenize_submission;
-use std::collections::VecDeque;
-use std::fs::read_to_string;
+pub use crate::sic::{Industry, Office};
+pub use crate::span::{snippet, Span};
+pub use crate::stream::DocumentStream;
+pub use crate::tag::{ContainerTag, ValueTag};
+pub use crate::types::{AccessionNumber, Cik, FileNumber, MonthDayPair, Sic};
+pub use crate::validate::Finding;
+pub use crate::warning::{ParseReport, Warning, WarningKind};
+pub use crate::writer::{submission_to_bytes, write_submission, write_tree};
+use std::fs::File;
+use std::io::{BufRead, BufReader, Read};
 use std::path::Path;
 
+mod address;
+mod archive;
+pub mod beneficial_ownership;
+mod borrowed;
+mod context;
 mod document_body;
 mod document_tree;
+mod encoding;
 mod error;
+pub mod form_d;
+mod form_type;
+mod items;
+mod location;
+pub mod nport;
+pub mod ownership;
 mod schema;
+mod sic;
+mod span;
+mod stream;
 mod tag;
+pub mod thirteen_f;
 mod tokens;
 mod types;
+mod uuencode;
+mod validate;
+mod warning;
+mod writer;
+mod xml;
 
-pub fn parse_submission(path: &Path) -> Result<Submission> {
-    let st = read_to_string(path).unwrap();
-    let mut tokens = VecDeque::from(tokenize_submission(st)?);
+#[derive(Clone, Debug, Default)]
+pub struct ParseOptions {
+    pub decoding: DecodingPolicy,
+    pub bodies: BodyMode,
+    /// Keep unknown or misplaced tags, reporting them as warnings, instead of failing.
+    pub lenient: bool,
+}
 
-    if let Ok(DocumentTree::ContainerNode(ContainerTag::Submission, parts)) = parse_doc(&mut tokens)
-    {
-        Submission::from_parts(&parts)
-    } else {
-        panic!("here1");
+impl ParseOptions {
+    pub fn parse_path(&self, path: &Path) -> Result<Submission> {
+        Ok(self.report_path(path)?.submission)
     }
+
+    pub fn parse_reader(&self, reader: impl Read) -> Result<Submission> {
+        Ok(self.report_reader(reader)?.submission)
+    }
+
+    pub fn parse_str(&self, st: &str) -> Result<Submission> {
+        self.parse_bytes(st.as_bytes())
+    }
+
+    pub fn parse_bytes(&self, bytes: &[u8]) -> Result<Submission> {
+        self.parse_borrowed(bytes)?.to_owned()
+    }
+
+    pub fn parse_borrowed<'a>(&self, bytes: &'a [u8]) -> Result<SubmissionRef<'a>> {
+        SubmissionRef::parse(bytes, self)
+    }
+
+    pub fn stream_reader<R: BufRead>(&self, reader: R) -> Result<(Submission, DocumentStream<R>)> {
+        DocumentStream::open(reader, self)
+    }
+
+    pub fn report_path(&self, path: &Path) -> Result<ParseReport> {
+        self.report_reader(File::open(path)?)
+    }
+
+    pub fn report_reader(&self, reader: impl Read) -> Result<ParseReport> {
+        let (mut submission, mut documents) = self.stream_reader(BufReader::new(reader))?;
+        for document in &mut documents {
+            submission.documents.push(document?);
+        }
+        documents.merge_trailing(&mut submission);
+        Ok(ParseReport {
+            submission,
+            warnings: documents.take_warnings(),
+        })
+    }
+
+    pub fn report_str(&self, st: &str) -> Result<ParseReport> {
+        self.report_bytes(st.as_bytes())
+    }
+
+    pub fn report_bytes(&self, bytes: &[u8]) -> Result<ParseReport> {
+        self.parse_borrowed(bytes)?.to_report()
+    }
+}
+
+pub fn parse_submission(path: &Path) -> Result<Submission> {
+    ParseOptions::default().parse_path(path)
+}
+
+pub fn parse_submission_from_reader(reader: impl Read) -> Result<Submission> {
+    ParseOptions::default().parse_reader(reader)
+}
+
+pub fn parse_submission_from_bytes(bytes: &[u8]) -> Result<Submission> {
+    ParseOptions::default().parse_bytes(bytes)
+}
+
+pub fn parse_submission_from_str(st: &str) -> Result<Submission> {
+    ParseOptions::default().parse_str(st)
+}
+
+pub fn stream_submission<R: BufRead>(reader: R) -> Result<(Submission, DocumentStream<R>)> {
+    ParseOptions::default().stream_reader(reader)
+}
+
+pub fn parse_submission_borrowed(bytes: &[u8]) -> Result<SubmissionRef<'_>> {
+    ParseOptions::default().parse_borrowed(bytes)
 }
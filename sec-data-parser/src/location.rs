@@ -0,0 +1,390 @@
+use crate::address::State;
+use crate::schema::{Address, CompanyData};
+use serde::Serialize;
+
+/// A place named by one of EDGAR's two-character location codes, as used in
+/// `<STATE-OF-INCORPORATION>` and `<STATE>`.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Serialize)]
+pub struct Location {
+    pub code: &'static str,
+    pub name: &'static str,
+    /// The ISO 3166-1 alpha-2 code of the country.
+    pub country: &'static str,
+    /// The ISO 3166-2 code for US states and territories and Canadian provinces.
+    pub subdivision: Option<&'static str>,
+}
+
+impl Location {
+    /// Looks up an EDGAR location code. The table follows EDGAR's published list of state and
+    /// country codes, apart from `XX` for an unknown place.
+    pub fn from_code(code: &str) -> Option<&'static Location> {
+        LOCATIONS.iter().find(|location| location.code == code)
+    }
+
+    /// Every location in the table.
+    pub fn all() -> &'static [Location] {
+        LOCATIONS
+    }
+
+    pub fn is_us(&self) -> bool {
+        self.country == "US"
+    }
+}
+
+impl State {
+    pub fn location(&self) -> Option<&'static Location> {
+        Location::from_code(self.code())
+    }
+}
+
+impl CompanyData {
+    pub fn incorporation_location(&self) -> Option<&'static Location> {
+        Location::from_code(self.state_of_incorporation.as_deref()?.trim())
+    }
+}
+
+impl Address {
+    pub fn location(&self) -> Option<&'static Location> {
+        Location::from_code(self.state.as_deref()?.trim())
+    }
+}
+
+const fn location(
+    code: &'static str,
+    name: &'static str,
+    country: &'static str,
+    subdivision: Option<&'static str>,
+) -> Location {
+    Location {
+        code,
+        name,
+        country,
+        subdivision,
+    }
+}
+
+static LOCATIONS: &[Location] = &[
+    location("AL", "Alabama", "US", Some("US-AL")),
+    location("AK", "Alaska", "US", Some("US-AK")),
+    location("AZ", "Arizona", "US", Some("US-AZ")),
+    location("AR", "Arkansas", "US", Some("US-AR")),
+    location("CA", "California", "US", Some("US-CA")),
+    location("CO", "Colorado", "US", Some("US-CO")),
+    location("CT", "Connecticut", "US", Some("US-CT")),
+    location("DE", "Delaware", "US", Some("US-DE")),
+    location("DC", "District of Columbia", "US", Some("US-DC")),
+    location("FL", "Florida", "US", Some("US-FL")),
+    location("GA", "Georgia", "US", Some("US-GA")),
+    location("HI", "Hawaii", "US", Some("US-HI")),
+    location("ID", "Idaho", "US", Some("US-ID")),
+    location("IL", "Illinois", "US", Some("US-IL")),
+    location("IN", "Indiana", "US", Some("US-IN")),
+    location("IA", "Iowa", "US", Some("US-IA")),
+    location("KS", "Kansas", "US", Some("US-KS")),
+    location("KY", "Kentucky", "US", Some("US-KY")),
+    location("LA", "Louisiana", "US", Some("US-LA")),
+    location("ME", "Maine", "US", Some("US-ME")),
+    location("MD", "Maryland", "US", Some("US-MD")),
+    location("MA", "Massachusetts", "US", Some("US-MA")),
+    location("MI", "Michigan", "US", Some("US-MI")),
+    location("MN", "Minnesota", "US", Some("US-MN")),
+    location("MS", "Mississippi", "US", Some("US-MS")),
+    location("MO", "Missouri", "US", Some("US-MO")),
+    location("MT", "Montana", "US", Some("US-MT")),
+    location("NE", "Nebraska", "US", Some("US-NE")),
+    location("NV", "Nevada", "US", Some("US-NV")),
+    location("NH", "New Hampshire", "US", Some("US-NH")),
+    location("NJ", "New Jersey", "US", Some("US-NJ")),
+    location("NM", "New Mexico", "US", Some("US-NM")),
+    location("NY", "New York", "US", Some("US-NY")),
+    location("NC", "North Carolina", "US", Some("US-NC")),
+    location("ND", "North Dakota", "US", Some("US-ND")),
+    location("OH", "Ohio", "US", Some("US-OH")),
+    location("OK", "Oklahoma", "US", Some("US-OK")),
+    location("OR", "Oregon", "US", Some("US-OR")),
+    location("PA", "Pennsylvania", "US", Some("US-PA")),
+    location("RI", "Rhode Island", "US", Some("US-RI")),
+    location("SC", "South Carolina", "US", Some("US-SC")),
+    location("SD", "South Dakota", "US", Some("US-SD")),
+    location("TN", "Tennessee", "US", Some("US-TN")),
+    location("TX", "Texas", "US", Some("US-TX")),
+    location("UT", "Utah", "US", Some("US-UT")),
+    location("VT", "Vermont", "US", Some("US-VT")),
+    location("VA", "Virginia", "US", Some("US-VA")),
+    location("WA", "Washington", "US", Some("US-WA")),
+    location("WV", "West Virginia", "US", Some("US-WV")),
+    location("WI", "Wisconsin", "US", Some("US-WI")),
+    location("WY", "Wyoming", "US", Some("US-WY")),
+    location("B5", "American Samoa", "US", Some("US-AS")),
+    location("GU", "Guam", "US", Some("US-GU")),
+    location("1V", "Northern Mariana Islands", "US", Some("US-MP")),
+    location("PR", "Puerto Rico", "US", Some("US-PR")),
+    location("VI", "Virgin Islands, U.S.", "US", Some("US-VI")),
+    location(
+        "2J",
+        "United States Minor Outlying Islands",
+        "US",
+        Some("US-UM"),
+    ),
+    location("A0", "Alberta", "CA", Some("CA-AB")),
+    location("A1", "British Columbia", "CA", Some("CA-BC")),
+    location("A2", "Manitoba", "CA", Some("CA-MB")),
+    location("A3", "New Brunswick", "CA", Some("CA-NB")),
+    location("A4", "Newfoundland", "CA", Some("CA-NL")),
+    location("A5", "Nova Scotia", "CA", Some("CA-NS")),
+    location("A6", "Ontario", "CA", Some("CA-ON")),
+    location("A7", "Prince Edward Island", "CA", Some("CA-PE")),
+    location("A8", "Quebec", "CA", Some("CA-QC")),
+    location("A9", "Saskatchewan", "CA", Some("CA-SK")),
+    location("B0", "Yukon", "CA", Some("CA-YT")),
+    location("X1", "United States", "US", None),
+    location("Z4", "Canada (Federal Level)", "CA", None),
+    location("B2", "Afghanistan", "AF", None),
+    location("Y6", "Aland Islands", "AX", None),
+    location("B3", "Albania", "AL", None),
+    location("B4", "Algeria", "DZ", None),
+    location("B6", "Andorra", "AD", None),
+    location("B7", "Angola", "AO", None),
+    location("1A", "Anguilla", "AI", None),
+    location("B8", "Antarctica", "AQ", None),
+    location("B9", "Antigua and Barbuda", "AG", None),
+    location("C1", "Argentina", "AR", None),
+    location("1B", "Armenia", "AM", None),
+    location("1C", "Aruba", "AW", None),
+    location("C3", "Australia", "AU", None),
+    location("C4", "Austria", "AT", None),
+    location("1D", "Azerbaijan", "AZ", None),
+    location("C5", "Bahamas", "BS", None),
+    location("C6", "Bahrain", "BH", None),
+    location("C7", "Bangladesh", "BD", None),
+    location("C8", "Barbados", "BB", None),
+    location("1F", "Belarus", "BY", None),
+    location("C9", "Belgium", "BE", None),
+    location("D1", "Belize", "BZ", None),
+    location("G6", "Benin", "BJ", None),
+    location("D0", "Bermuda", "BM", None),
+    location("D2", "Bhutan", "BT", None),
+    location("D3", "Bolivia", "BO", None),
+    location("1E", "Bosnia and Herzegovina", "BA", None),
+    location("B1", "Botswana", "BW", None),
+    location("D4", "Bouvet Island", "BV", None),
+    location("D5", "Brazil", "BR", None),
+    location("D6", "British Indian Ocean Territory", "IO", None),
+    location("D9", "Brunei Darussalam", "BN", None),
+    location("E0", "Bulgaria", "BG", None),
+    location("X2", "Burkina Faso", "BF", None),
+    location("E2", "Burundi", "BI", None),
+    location("E3", "Cambodia", "KH", None),
+    location("E4", "Cameroon", "CM", None),
+    location("E8", "Cape Verde", "CV", None),
+    location("E9", "Cayman Islands", "KY", None),
+    location("F0", "Central African Republic", "CF", None),
+    location("F2", "Chad", "TD", None),
+    location("F3", "Chile", "CL", None),
+    location("F4", "China", "CN", None),
+    location("F6", "Christmas Island", "CX", None),
+    location("F7", "Cocos (Keeling) Islands", "CC", None),
+    location("F8", "Colombia", "CO", None),
+    location("F9", "Comoros", "KM", None),
+    location("G0", "Congo", "CG", None),
+    location("Y3", "Congo, the Democratic Republic of the", "CD", None),
+    location("G1", "Cook Islands", "CK", None),
+    location("G2", "Costa Rica", "CR", None),
+    location("L7", "Cote d'Ivoire", "CI", None),
+    location("1M", "Croatia", "HR", None),
+    location("G3", "Cuba", "CU", None),
+    location("G4", "Cyprus", "CY", None),
+    location("2N", "Czech Republic", "CZ", None),
+    location("G7", "Denmark", "DK", None),
+    location("1G", "Djibouti", "DJ", None),
+    location("G9", "Dominica", "DM", None),
+    location("G8", "Dominican Republic", "DO", None),
+    location("H1", "Ecuador", "EC", None),
+    location("H2", "Egypt", "EG", None),
+    location("H3", "El Salvador", "SV", None),
+    location("H4", "Equatorial Guinea", "GQ", None),
+    location("1J", "Eritrea", "ER", None),
+    location("1H", "Estonia", "EE", None),
+    location("H5", "Ethiopia", "ET", None),
+    location("H7", "Falkland Islands (Malvinas)", "FK", None),
+    location("H6", "Faroe Islands", "FO", None),
+    location("H8", "Fiji", "FJ", None),
+    location("H9", "Finland", "FI", None),
+    location("I0", "France", "FR", None),
+    location("I3", "French Guiana", "GF", None),
+    location("I4", "French Polynesia", "PF", None),
+    location("2C", "French Southern Territories", "TF", None),
+    location("I5", "Gabon", "GA", None),
+    location("I6", "Gambia", "GM", None),
+    location("2Q", "Georgia", "GE", None),
+    location("2M", "Germany", "DE", None),
+    location("J0", "Ghana", "GH", None),
+    location("J1", "Gibraltar", "GI", None),
+    location("J3", "Greece", "GR", None),
+    location("J4", "Greenland", "GL", None),
+    location("J5", "Grenada", "GD", None),
+    location("J6", "Guadeloupe", "GP", None),
+    location("J8", "Guatemala", "GT", None),
+    location("Y7", "Guernsey", "GG", None),
+    location("J9", "Guinea", "GN", None),
+    location("S0", "Guinea-Bissau", "GW", None),
+    location("K0", "Guyana", "GY", None),
+    location("K1", "Haiti", "HT", None),
+    location("K4", "Heard Island and McDonald Islands", "HM", None),
+    location("X4", "Holy See (Vatican City State)", "VA", None),
+    location("K2", "Honduras", "HN", None),
+    location("K3", "Hong Kong", "HK", None),
+    location("K5", "Hungary", "HU", None),
+    location("K6", "Iceland", "IS", None),
+    location("K7", "India", "IN", None),
+    location("K8", "Indonesia", "ID", None),
+    location("K9", "Iran, Islamic Republic of", "IR", None),
+    location("L0", "Iraq", "IQ", None),
+    location("L2", "Ireland", "IE", None),
+    location("Y8", "Isle of Man", "IM", None),
+    location("L3", "Israel", "IL", None),
+    location("L6", "Italy", "IT", None),
+    location("L8", "Jamaica", "JM", None),
+    location("M0", "Japan", "JP", None),
+    location("Y9", "Jersey", "JE", None),
+    location("M2", "Jordan", "JO", None),
+    location("1P", "Kazakstan", "KZ", None),
+    location("M3", "Kenya", "KE", None),
+    location("J2", "Kiribati", "KI", None),
+    location("M4", "Korea, Democratic People's Republic of", "KP", None),
+    location("M5", "Korea, Republic of", "KR", None),
+    location("M6", "Kuwait", "KW", None),
+    location("1N", "Kyrgyzstan", "KG", None),
+    location("M7", "Lao People's Democratic Republic", "LA", None),
+    location("1R", "Latvia", "LV", None),
+    location("M8", "Lebanon", "LB", None),
+    location("M9", "Lesotho", "LS", None),
+    location("N0", "Liberia", "LR", None),
+    location("N1", "Libyan Arab Jamahiriya", "LY", None),
+    location("N2", "Liechtenstein", "LI", None),
+    location("1Q", "Lithuania", "LT", None),
+    location("N4", "Luxembourg", "LU", None),
+    location("N5", "Macau", "MO", None),
+    location(
+        "1U",
+        "Macedonia, the Former Yugoslav Republic of",
+        "MK",
+        None,
+    ),
+    location("N6", "Madagascar", "MG", None),
+    location("N7", "Malawi", "MW", None),
+    location("N8", "Malaysia", "MY", None),
+    location("N9", "Maldives", "MV", None),
+    location("O0", "Mali", "ML", None),
+    location("O1", "Malta", "MT", None),
+    location("1T", "Marshall Islands", "MH", None),
+    location("O2", "Martinique", "MQ", None),
+    location("O3", "Mauritania", "MR", None),
+    location("O4", "Mauritius", "MU", None),
+    location("2P", "Mayotte", "YT", None),
+    location("O5", "Mexico", "MX", None),
+    location("1K", "Micronesia, Federated States of", "FM", None),
+    location("1S", "Moldova, Republic of", "MD", None),
+    location("O9", "Monaco", "MC", None),
+    location("P0", "Mongolia", "MN", None),
+    location("Z5", "Montenegro", "ME", None),
+    location("P1", "Montserrat", "MS", None),
+    location("P2", "Morocco", "MA", None),
+    location("P3", "Mozambique", "MZ", None),
+    location("E1", "Myanmar", "MM", None),
+    location("T6", "Namibia", "NA", None),
+    location("P5", "Nauru", "NR", None),
+    location("P6", "Nepal", "NP", None),
+    location("P7", "Netherlands", "NL", None),
+    location("P8", "Netherlands Antilles", "AN", None),
+    location("1W", "New Caledonia", "NC", None),
+    location("Q2", "New Zealand", "NZ", None),
+    location("Q3", "Nicaragua", "NI", None),
+    location("Q4", "Niger", "NE", None),
+    location("Q5", "Nigeria", "NG", None),
+    location("Q6", "Niue", "NU", None),
+    location("Q7", "Norfolk Island", "NF", None),
+    location("Q8", "Norway", "NO", None),
+    location("P4", "Oman", "OM", None),
+    location("R0", "Pakistan", "PK", None),
+    location("1Y", "Palau", "PW", None),
+    location("1X", "Palestinian Territory, Occupied", "PS", None),
+    location("R1", "Panama", "PA", None),
+    location("R2", "Papua New Guinea", "PG", None),
+    location("R4", "Paraguay", "PY", None),
+    location("R5", "Peru", "PE", None),
+    location("R6", "Philippines", "PH", None),
+    location("R8", "Pitcairn", "PN", None),
+    location("R9", "Poland", "PL", None),
+    location("S1", "Portugal", "PT", None),
+    location("S3", "Qatar", "QA", None),
+    location("S4", "Reunion", "RE", None),
+    location("S5", "Romania", "RO", None),
+    location("1Z", "Russian Federation", "RU", None),
+    location("S6", "Rwanda", "RW", None),
+    location("Z0", "Saint Barthelemy", "BL", None),
+    location("U8", "Saint Helena", "SH", None),
+    location("U7", "Saint Kitts and Nevis", "KN", None),
+    location("U9", "Saint Lucia", "LC", None),
+    location("Z1", "Saint Martin", "MF", None),
+    location("V0", "Saint Pierre and Miquelon", "PM", None),
+    location("V1", "Saint Vincent and the Grenadines", "VC", None),
+    location("Y0", "Samoa", "WS", None),
+    location("S8", "San Marino", "SM", None),
+    location("S9", "Sao Tome and Principe", "ST", None),
+    location("T0", "Saudi Arabia", "SA", None),
+    location("T1", "Senegal", "SN", None),
+    location("Z2", "Serbia", "RS", None),
+    location("T2", "Seychelles", "SC", None),
+    location("T8", "Sierra Leone", "SL", None),
+    location("U0", "Singapore", "SG", None),
+    location("2B", "Slovakia", "SK", None),
+    location("2A", "Slovenia", "SI", None),
+    location("D7", "Solomon Islands", "SB", None),
+    location("U1", "Somalia", "SO", None),
+    location("T3", "South Africa", "ZA", None),
+    location(
+        "1L",
+        "South Georgia and the South Sandwich Islands",
+        "GS",
+        None,
+    ),
+    location("U3", "Spain", "ES", None),
+    location("F1", "Sri Lanka", "LK", None),
+    location("V2", "Sudan", "SD", None),
+    location("V3", "Suriname", "SR", None),
+    location("L9", "Svalbard and Jan Mayen", "SJ", None),
+    location("V6", "Swaziland", "SZ", None),
+    location("V7", "Sweden", "SE", None),
+    location("V8", "Switzerland", "CH", None),
+    location("V9", "Syrian Arab Republic", "SY", None),
+    location("F5", "Taiwan, Province of China", "TW", None),
+    location("2D", "Tajikistan", "TJ", None),
+    location("W0", "Tanzania, United Republic of", "TZ", None),
+    location("W1", "Thailand", "TH", None),
+    location("Z3", "Timor-Leste", "TL", None),
+    location("W2", "Togo", "TG", None),
+    location("W3", "Tokelau", "TK", None),
+    location("W4", "Tonga", "TO", None),
+    location("W5", "Trinidad and Tobago", "TT", None),
+    location("W6", "Tunisia", "TN", None),
+    location("W8", "Turkey", "TR", None),
+    location("2E", "Turkmenistan", "TM", None),
+    location("W7", "Turks and Caicos Islands", "TC", None),
+    location("2G", "Tuvalu", "TV", None),
+    location("W9", "Uganda", "UG", None),
+    location("2H", "Ukraine", "UA", None),
+    location("C0", "United Arab Emirates", "AE", None),
+    location("X0", "United Kingdom", "GB", None),
+    location("X3", "Uruguay", "UY", None),
+    location("2K", "Uzbekistan", "UZ", None),
+    location("2L", "Vanuatu", "VU", None),
+    location("X5", "Venezuela", "VE", None),
+    location("Q1", "Viet Nam", "VN", None),
+    location("D8", "Virgin Islands, British", "VG", None),
+    location("X8", "Wallis and Futuna", "WF", None),
+    location("U5", "Western Sahara", "EH", None),
+    location("T7", "Yemen", "YE", None),
+    location("Y4", "Zambia", "ZM", None),
+    location("Y5", "Zimbabwe", "ZW", None),
+];
@@ -0,0 +1,389 @@
+//! Form N-PORT, the monthly report of a registered fund's portfolio. Each report covers one series
+//! of the registrant, identified by the same `S000…` id as in the header's series and classes
+//! data, and its returns are broken down by class using the `C000…` class/contract ids.
+
+use crate::error::Result;
+use crate::form_type::Form;
+use crate::schema::{ClassContract, Series, Submission};
+use crate::types::Cik;
+use crate::xml::{
+    self, attribute_value, child, child_string, child_text, child_value, children, parse_bool,
+    parse_date, parse_number,
+};
+use chrono::NaiveDate;
+use roxmltree::Node;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NPort {
+    /// `NPORT-P`, or `NPORT-P/A` for an amendment.
+    pub submission_type: Option<String>,
+    /// The class/contract ids the report covers, as listed in its header.
+    pub class_ids: Vec<String>,
+    pub general: GeneralInfo,
+    pub fund: FundInfo,
+    pub holdings: Vec<Holding>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GeneralInfo {
+    pub registrant_name: Option<String>,
+    pub file_number: Option<String>,
+    pub cik: Option<Cik>,
+    pub lei: Option<String>,
+    pub series_name: Option<String>,
+    pub series_id: Option<String>,
+    pub series_lei: Option<String>,
+    pub fiscal_year_end: Option<NaiveDate>,
+    /// The last day of the month the report is for.
+    pub period_date: Option<NaiveDate>,
+    pub is_final_filing: Option<bool>,
+}
+
+/// The fund's balance sheet totals and returns. Amounts are in dollars.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FundInfo {
+    pub total_assets: Option<f64>,
+    pub total_liabilities: Option<f64>,
+    pub net_assets: Option<f64>,
+    pub class_returns: Vec<ClassReturns>,
+    /// The realized gains and unrealized appreciation from investments other than derivatives,
+    /// for each of the three months of the quarter.
+    pub other_gains: Vec<Gains>,
+    /// Share sales, reinvestments and redemptions, for each of the three months of the quarter.
+    pub flows: Vec<Flow>,
+}
+
+/// A class's total return for each of the three months of the quarter, in percent.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ClassReturns {
+    pub class_id: String,
+    pub returns: [Option<f64>; 3],
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Gains {
+    pub net_realized_gain: Option<f64>,
+    pub net_unrealized_appreciation: Option<f64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Flow {
+    pub sales: Option<f64>,
+    pub reinvestment: Option<f64>,
+    pub redemption: Option<f64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Holding {
+    pub name: Option<String>,
+    pub lei: Option<String>,
+    pub title: Option<String>,
+    pub cusip: Option<String>,
+    pub isin: Option<String>,
+    pub ticker: Option<String>,
+    /// The number of shares or units, or the principal amount, as given by `units`.
+    pub balance: Option<f64>,
+    /// `NS` for shares, `PA` for principal amount, `NC` for contracts or `OU` for other units.
+    pub units: Option<String>,
+    pub currency: Option<String>,
+    pub value_usd: Option<f64>,
+    /// The value as a percentage of the fund's net assets.
+    pub percent_of_net_assets: Option<f64>,
+    /// `Long` or `Short`, or `None` for holdings such as futures where it does not apply.
+    pub payoff_profile: Option<String>,
+    /// For example `EC` for common equity, `DBT` for debt or `DIR` for interest rate
+    /// derivatives.
+    pub asset_category: Option<String>,
+    /// For example `CORP` for a corporation or `UST` for the U.S. Treasury.
+    pub issuer_category: Option<String>,
+    pub country: Option<String>,
+    pub is_restricted: Option<bool>,
+    /// Level 1, 2 or 3 of the fair value hierarchy.
+    pub fair_value_level: Option<u8>,
+    pub derivative: Option<Derivative>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Derivative {
+    /// `FWD`, `FUT`, `SWP`, `OPT`, `SWO`, `WAR` or `OTH`.
+    pub category: Option<String>,
+    pub counterparties: Vec<Counterparty>,
+    pub notional_amount: Option<f64>,
+    /// The expiration, termination or settlement date, whichever the kind of derivative has.
+    pub expiration_date: Option<NaiveDate>,
+    pub unrealized_appreciation: Option<f64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Counterparty {
+    pub name: Option<String>,
+    pub lei: Option<String>,
+}
+
+// N-PORT writes `N/A` for values which do not apply.
+fn string(node: Node<'_, '_>, name: &'static str) -> Option<String> {
+    child_text(node, name)
+        .filter(|st| *st != "N/A")
+        .map(|st| st.to_string())
+}
+
+fn number(node: Node<'_, '_>, name: &'static str) -> Result<Option<f64>> {
+    match child_text(node, name) {
+        Some("N/A") => Ok(None),
+        _ => child_value(node, name, parse_number),
+    }
+}
+
+fn attribute_number(node: Option<Node<'_, '_>>, name: &'static str) -> Result<Option<f64>> {
+    match node {
+        Some(node) if node.attribute(name).map(str::trim) != Some("N/A") => {
+            attribute_value(node, name, parse_number)
+        }
+        _ => Ok(None),
+    }
+}
+
+fn parse_level(st: &str) -> Option<u8> {
+    match st {
+        "1" => Some(1),
+        "2" => Some(2),
+        "3" => Some(3),
+        _ => None,
+    }
+}
+
+impl GeneralInfo {
+    fn from_node(node: Node<'_, '_>) -> Result<GeneralInfo> {
+        Ok(GeneralInfo {
+            registrant_name: string(node, "regName"),
+            file_number: string(node, "regFileNumber"),
+            cik: child_value(node, "regCik", Cik::parse)?,
+            lei: string(node, "regLei"),
+            series_name: string(node, "seriesName"),
+            series_id: string(node, "seriesId"),
+            series_lei: string(node, "seriesLei"),
+            fiscal_year_end: child_value(node, "repPdEnd", parse_date)?,
+            period_date: child_value(node, "repPdDate", parse_date)?,
+            is_final_filing: child_value(node, "isFinalFiling", parse_bool)?,
+        })
+    }
+}
+
+impl FundInfo {
+    fn from_node(node: Node<'_, '_>) -> Result<FundInfo> {
+        let returns = child(node, "returnInfo");
+
+        let mut class_returns = Vec::new();
+        if let Some(list) = returns.and_then(|returns| child(returns, "monthlyTotReturns")) {
+            for class in children(list, "monthlyTotReturn") {
+                let class_id = class
+                    .attribute("classId")
+                    .map(str::trim)
+                    .filter(|id| !id.is_empty())
+                    .ok_or_else(|| {
+                        xml::malformed("missing classId on <monthlyTotReturn>".into())
+                    })?;
+                class_returns.push(ClassReturns {
+                    class_id: class_id.to_string(),
+                    returns: [
+                        attribute_number(Some(class), "rtn1")?,
+                        attribute_number(Some(class), "rtn2")?,
+                        attribute_number(Some(class), "rtn3")?,
+                    ],
+                });
+            }
+        }
+
+        let mut other_gains = Vec::new();
+        for name in ["othMon1", "othMon2", "othMon3"] {
+            let month = returns.and_then(|returns| child(returns, name));
+            other_gains.push(Gains {
+                net_realized_gain: attribute_number(month, "netRealizedGain")?,
+                net_unrealized_appreciation: attribute_number(month, "netUnrealizedAppr")?,
+            });
+        }
+
+        let mut flows = Vec::new();
+        for name in ["mon1Flow", "mon2Flow", "mon3Flow"] {
+            let month = child(node, name);
+            flows.push(Flow {
+                sales: attribute_number(month, "sales")?,
+                reinvestment: attribute_number(month, "reinvestment")?,
+                redemption: attribute_number(month, "redemption")?,
+            });
+        }
+
+        Ok(FundInfo {
+            total_assets: number(node, "totAssets")?,
+            total_liabilities: number(node, "totLiabs")?,
+            net_assets: number(node, "netAssets")?,
+            class_returns,
+            other_gains,
+            flows,
+        })
+    }
+}
+
+impl Holding {
+    fn from_node(node: Node<'_, '_>) -> Result<Holding> {
+        let identifiers = child(node, "identifiers");
+        let identifier = |name| {
+            identifiers
+                .and_then(|identifiers| child(identifiers, name))
+                .and_then(|identifier| identifier.attribute("value"))
+                .map(str::trim)
+                .filter(|st| !st.is_empty() && *st != "N/A")
+                .map(|st| st.to_string())
+        };
+        // Categories outside the fixed list are given as an attribute, with a description.
+        let category = |name, conditional| {
+            string(node, name).or_else(|| {
+                child(node, conditional)
+                    .and_then(|conditional| conditional.attribute(name))
+                    .map(|st| st.to_string())
+            })
+        };
+
+        Ok(Holding {
+            name: string(node, "name"),
+            lei: string(node, "lei"),
+            title: string(node, "title"),
+            // Holdings without a CUSIP, such as futures, give `000000000`.
+            cusip: string(node, "cusip").filter(|cusip| !cusip.trim_matches('0').is_empty()),
+            isin: identifier("isin"),
+            ticker: identifier("ticker"),
+            balance: number(node, "balance")?,
+            units: string(node, "units"),
+            currency: string(node, "curCd"),
+            value_usd: number(node, "valUSD")?,
+            percent_of_net_assets: number(node, "pctVal")?,
+            payoff_profile: string(node, "payoffProfile"),
+            asset_category: category("assetCat", "assetConditional"),
+            issuer_category: category("issuerCat", "issuerConditional"),
+            country: string(node, "invCountry"),
+            is_restricted: child_value(node, "isRestrictedSec", parse_bool)?,
+            fair_value_level: match child_text(node, "fairValLevel") {
+                Some("N/A") => None,
+                _ => child_value(node, "fairValLevel", parse_level)?,
+            },
+            derivative: match child(node, "derivativeInfo") {
+                Some(info) => Derivative::from_node(info)?,
+                None => None,
+            },
+        })
+    }
+}
+
+impl Derivative {
+    // Each kind of derivative has an element of its own inside `<derivativeInfo>`, with the
+    // category in its `derivCat` attribute.
+    fn from_node(info: Node<'_, '_>) -> Result<Option<Derivative>> {
+        let node = match info.children().find(|node| node.is_element()) {
+            Some(node) => node,
+            None => return Ok(None),
+        };
+
+        let counterparties = children(node, "counterparties")
+            .map(|counterparty| Counterparty {
+                name: string(counterparty, "counterpartyName"),
+                lei: string(counterparty, "counterpartyLei"),
+            })
+            .collect();
+        let mut expiration_date = None;
+        for name in ["expDate", "expDt", "terminationDt", "settlementDt"] {
+            if let Some(date) = child_value(node, name, parse_date)? {
+                expiration_date = Some(date);
+                break;
+            }
+        }
+
+        Ok(Some(Derivative {
+            category: node.attribute("derivCat").map(|st| st.to_string()),
+            counterparties,
+            notional_amount: number(node, "notionalAmt")?,
+            expiration_date,
+            unrealized_appreciation: number(node, "unrealizedAppr")?,
+        }))
+    }
+}
+
+impl NPort {
+    /// Parses the XML of an N-PORT report, without the `<XML>` wrapper.
+    pub fn parse(st: &str) -> Result<NPort> {
+        let document = xml::parse(st)?;
+        let root = xml::root(&document, "edgarSubmission")?;
+
+        let header = child(root, "headerData");
+        let class_ids = header
+            .and_then(|header| child(header, "filerInfo"))
+            .and_then(|info| child(info, "seriesClassInfo"))
+            .map(|info| {
+                children(info, "classId")
+                    .filter_map(xml::text)
+                    .map(|st| st.to_string())
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let form =
+            child(root, "formData").ok_or_else(|| xml::malformed("missing <formData>".into()))?;
+        let general =
+            child(form, "genInfo").ok_or_else(|| xml::malformed("missing <genInfo>".into()))?;
+        let fund =
+            child(form, "fundInfo").ok_or_else(|| xml::malformed("missing <fundInfo>".into()))?;
+
+        let mut holdings = Vec::new();
+        if let Some(list) = child(form, "invstOrSecs") {
+            for holding in children(list, "invstOrSec") {
+                holdings.push(Holding::from_node(holding)?);
+            }
+        }
+
+        Ok(NPort {
+            submission_type: header.and_then(|header| child_string(header, "submissionType")),
+            class_ids,
+            general: GeneralInfo::from_node(general)?,
+            fund: FundInfo::from_node(fund)?,
+            holdings,
+        })
+    }
+
+    /// The series in the submission's header which this report is for.
+    pub fn series<'a>(&self, submission: &'a Submission) -> Option<&'a Series> {
+        let series_id = self.general.series_id.as_deref()?;
+        submission
+            .series_and_classes_contracts_data
+            .as_ref()?
+            .series()
+            .find(|series| series.series_id == series_id)
+    }
+}
+
+impl ClassReturns {
+    /// The class/contract in the submission's header which these returns are for.
+    pub fn class_contract<'a>(&self, submission: &'a Submission) -> Option<&'a ClassContract> {
+        submission
+            .series_and_classes_contracts_data
+            .as_ref()?
+            .series()
+            .flat_map(|series| &series.class_contracts)
+            .find(|class| class.class_contract_id == self.class_id)
+    }
+}
+
+impl Submission {
+    /// Parses the report of an NPORT-P. Returns `None` for other forms and when bodies were not
+    /// loaded.
+    pub fn nport(&self) -> Option<Result<NPort>> {
+        if self.form().form != Form::NportP {
+            return None;
+        }
+        let xml = self
+            .documents
+            .iter()
+            .filter(|document| document.form().form == Form::NportP)
+            .find_map(|document| document.xml())?;
+        Some(NPort::parse(xml))
+    }
+}
@@ -0,0 +1,549 @@
+//! Forms 3, 4 and 5, in which insiders report their holdings of an issuer's securities and
+//! their transactions in them. Since 2003 these are filed as an `ownershipDocument` in the
+//! submission's primary document.
+
+use crate::error::Result;
+use crate::form_type::Form;
+use crate::schema::{Company, Submission};
+use crate::types::Cik;
+use crate::xml::{
+    self, child, child_string, child_text, child_value, children, parse_bool, parse_date,
+    parse_number, required_value,
+};
+use chrono::NaiveDate;
+use roxmltree::Node;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OwnershipDocument {
+    pub schema_version: Option<String>,
+    /// The form, `3`, `4` or `5`, with `/A` for amendments.
+    pub document_type: String,
+    pub period_of_report: Option<NaiveDate>,
+    /// Set on a Form 4 or 5 filed by someone no longer subject to section 16.
+    pub not_subject_to_section_16: Option<bool>,
+    pub issuer: Issuer,
+    pub reporting_owners: Vec<ReportingOwner>,
+    pub non_derivative_transactions: Vec<NonDerivativeTransaction>,
+    pub non_derivative_holdings: Vec<NonDerivativeHolding>,
+    pub derivative_transactions: Vec<DerivativeTransaction>,
+    pub derivative_holdings: Vec<DerivativeHolding>,
+    pub footnotes: Vec<Footnote>,
+    pub remarks: Option<String>,
+    pub signatures: Vec<Signature>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Issuer {
+    pub cik: Cik,
+    pub name: Option<String>,
+    pub trading_symbol: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReportingOwner {
+    pub cik: Cik,
+    pub name: Option<String>,
+    pub address: Option<OwnerAddress>,
+    pub relationship: Relationship,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OwnerAddress {
+    pub street1: Option<String>,
+    pub street2: Option<String>,
+    pub city: Option<String>,
+    pub state: Option<String>,
+    pub zip_code: Option<String>,
+    pub state_description: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct Relationship {
+    pub is_director: bool,
+    pub is_officer: bool,
+    pub is_ten_percent_owner: bool,
+    pub is_other: bool,
+    pub officer_title: Option<String>,
+    pub other_text: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AcquiredDisposed {
+    Acquired,
+    Disposed,
+}
+
+impl AcquiredDisposed {
+    pub fn parse(st: &str) -> Option<AcquiredDisposed> {
+        match st {
+            "A" => Some(AcquiredDisposed::Acquired),
+            "D" => Some(AcquiredDisposed::Disposed),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DirectOrIndirect {
+    Direct,
+    Indirect,
+}
+
+impl DirectOrIndirect {
+    pub fn parse(st: &str) -> Option<DirectOrIndirect> {
+        match st {
+            "D" => Some(DirectOrIndirect::Direct),
+            "I" => Some(DirectOrIndirect::Indirect),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TransactionCoding {
+    pub form_type: Option<String>,
+    /// The transaction code, such as `P` for an open market purchase or `S` for a sale.
+    pub code: String,
+    pub equity_swap_involved: Option<bool>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TransactionAmounts {
+    pub shares: Option<f64>,
+    pub price_per_share: Option<f64>,
+    pub acquired_disposed: Option<AcquiredDisposed>,
+}
+
+/// What is held once a transaction is complete, or at the time of a holding's report. Some
+/// securities are reported by value rather than by number of shares.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct PostTransactionAmounts {
+    pub shares_owned: Option<f64>,
+    pub value_owned: Option<f64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct OwnershipNature {
+    pub direct_or_indirect: Option<DirectOrIndirect>,
+    /// For indirect holdings, how they are held, such as `By Trust`.
+    pub nature: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct UnderlyingSecurity {
+    pub title: Option<String>,
+    pub shares: Option<f64>,
+    pub value: Option<f64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NonDerivativeTransaction {
+    pub security_title: Option<String>,
+    pub transaction_date: Option<NaiveDate>,
+    pub deemed_execution_date: Option<NaiveDate>,
+    pub coding: Option<TransactionCoding>,
+    pub amounts: TransactionAmounts,
+    pub post_transaction: PostTransactionAmounts,
+    pub ownership: OwnershipNature,
+    /// The ids of the footnotes referenced anywhere in this row.
+    pub footnote_ids: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NonDerivativeHolding {
+    pub security_title: Option<String>,
+    pub post_transaction: PostTransactionAmounts,
+    pub ownership: OwnershipNature,
+    pub footnote_ids: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DerivativeTransaction {
+    pub security_title: Option<String>,
+    pub conversion_or_exercise_price: Option<f64>,
+    pub transaction_date: Option<NaiveDate>,
+    pub deemed_execution_date: Option<NaiveDate>,
+    pub coding: Option<TransactionCoding>,
+    pub amounts: TransactionAmounts,
+    pub exercise_date: Option<NaiveDate>,
+    pub expiration_date: Option<NaiveDate>,
+    pub underlying: UnderlyingSecurity,
+    pub post_transaction: PostTransactionAmounts,
+    pub ownership: OwnershipNature,
+    pub footnote_ids: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DerivativeHolding {
+    pub security_title: Option<String>,
+    pub conversion_or_exercise_price: Option<f64>,
+    pub exercise_date: Option<NaiveDate>,
+    pub expiration_date: Option<NaiveDate>,
+    pub underlying: UnderlyingSecurity,
+    pub post_transaction: PostTransactionAmounts,
+    pub ownership: OwnershipNature,
+    pub footnote_ids: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Footnote {
+    pub id: String,
+    pub text: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Signature {
+    pub name: String,
+    pub date: Option<NaiveDate>,
+}
+
+// Most fields of the transaction tables wrap their content in `<value>`, next to any
+// `<footnoteId>` elements.
+fn value<'a>(node: Node<'a, '_>, name: &'static str) -> Option<&'a str> {
+    child_text(child(node, name)?, "value")
+}
+
+fn value_string(node: Node<'_, '_>, name: &'static str) -> Option<String> {
+    value(node, name).map(|st| st.to_string())
+}
+
+fn typed_value<T>(
+    node: Node<'_, '_>,
+    name: &'static str,
+    parse: fn(&str) -> Option<T>,
+) -> Result<Option<T>> {
+    value(node, name)
+        .map(|st| parse(st).ok_or_else(|| xml::bad_value(name, st)))
+        .transpose()
+}
+
+fn flag(node: Node<'_, '_>, name: &'static str) -> Result<bool> {
+    Ok(child_value(node, name, parse_bool)?.unwrap_or(false))
+}
+
+fn footnote_ids(node: Node<'_, '_>) -> Vec<String> {
+    let mut ids: Vec<String> = Vec::new();
+    for footnote in node
+        .descendants()
+        .filter(|n| n.is_element() && n.tag_name().name() == "footnoteId")
+    {
+        if let Some(id) = footnote.attribute("id") {
+            if !ids.iter().any(|seen| seen == id) {
+                ids.push(id.to_string());
+            }
+        }
+    }
+    ids
+}
+
+impl OwnershipDocument {
+    /// Parses the XML of a Form 3, 4 or 5, without the `<XML>` wrapper.
+    pub fn parse(st: &str) -> Result<OwnershipDocument> {
+        let document = xml::parse(st)?;
+        let root = xml::root(&document, "ownershipDocument")?;
+
+        let issuer =
+            child(root, "issuer").ok_or_else(|| xml::malformed("missing <issuer>".into()))?;
+        let issuer = Issuer {
+            cik: required_value(issuer, "issuerCik", Cik::parse)?,
+            name: child_string(issuer, "issuerName"),
+            trading_symbol: child_string(issuer, "issuerTradingSymbol"),
+        };
+
+        let mut non_derivative_transactions = Vec::new();
+        let mut non_derivative_holdings = Vec::new();
+        if let Some(table) = child(root, "nonDerivativeTable") {
+            for row in children(table, "nonDerivativeTransaction") {
+                non_derivative_transactions.push(NonDerivativeTransaction::from_node(row)?);
+            }
+            for row in children(table, "nonDerivativeHolding") {
+                non_derivative_holdings.push(NonDerivativeHolding::from_node(row)?);
+            }
+        }
+
+        let mut derivative_transactions = Vec::new();
+        let mut derivative_holdings = Vec::new();
+        if let Some(table) = child(root, "derivativeTable") {
+            for row in children(table, "derivativeTransaction") {
+                derivative_transactions.push(DerivativeTransaction::from_node(row)?);
+            }
+            for row in children(table, "derivativeHolding") {
+                derivative_holdings.push(DerivativeHolding::from_node(row)?);
+            }
+        }
+
+        let footnotes = match child(root, "footnotes") {
+            Some(footnotes) => children(footnotes, "footnote")
+                .map(|footnote| Footnote {
+                    id: footnote.attribute("id").unwrap_or_default().to_string(),
+                    text: xml::text(footnote).unwrap_or_default().to_string(),
+                })
+                .collect(),
+            None => Vec::new(),
+        };
+
+        let mut signatures = Vec::new();
+        for signature in children(root, "ownerSignature") {
+            signatures.push(Signature {
+                name: child_string(signature, "signatureName").unwrap_or_default(),
+                date: child_value(signature, "signatureDate", parse_date)?,
+            });
+        }
+
+        Ok(OwnershipDocument {
+            schema_version: child_string(root, "schemaVersion"),
+            document_type: xml::required_text(root, "documentType")?.to_string(),
+            period_of_report: child_value(root, "periodOfReport", parse_date)?,
+            not_subject_to_section_16: child_value(root, "notSubjectToSection16", parse_bool)?,
+            issuer,
+            reporting_owners: children(root, "reportingOwner")
+                .map(ReportingOwner::from_node)
+                .collect::<Result<_>>()?,
+            non_derivative_transactions,
+            non_derivative_holdings,
+            derivative_transactions,
+            derivative_holdings,
+            footnotes,
+            remarks: child_string(root, "remarks"),
+            signatures,
+        })
+    }
+
+    pub fn footnote(&self, id: &str) -> Option<&Footnote> {
+        self.footnotes.iter().find(|footnote| footnote.id == id)
+    }
+}
+
+impl ReportingOwner {
+    fn from_node(node: Node<'_, '_>) -> Result<ReportingOwner> {
+        let id = child(node, "reportingOwnerId")
+            .ok_or_else(|| xml::malformed("missing <reportingOwnerId>".into()))?;
+        let address = child(node, "reportingOwnerAddress").map(|address| OwnerAddress {
+            street1: child_string(address, "rptOwnerStreet1"),
+            street2: child_string(address, "rptOwnerStreet2"),
+            city: child_string(address, "rptOwnerCity"),
+            state: child_string(address, "rptOwnerState"),
+            zip_code: child_string(address, "rptOwnerZipCode"),
+            state_description: child_string(address, "rptOwnerStateDescription"),
+        });
+        let relationship = match child(node, "reportingOwnerRelationship") {
+            Some(relationship) => Relationship {
+                is_director: flag(relationship, "isDirector")?,
+                is_officer: flag(relationship, "isOfficer")?,
+                is_ten_percent_owner: flag(relationship, "isTenPercentOwner")?,
+                is_other: flag(relationship, "isOther")?,
+                officer_title: child_string(relationship, "officerTitle"),
+                other_text: child_string(relationship, "otherText"),
+            },
+            None => Relationship::default(),
+        };
+
+        Ok(ReportingOwner {
+            cik: required_value(id, "rptOwnerCik", Cik::parse)?,
+            name: child_string(id, "rptOwnerName"),
+            address,
+            relationship,
+        })
+    }
+
+    /// The `<REPORTING-OWNER>` in the submission's header for this owner.
+    pub fn company<'a>(&self, submission: &'a Submission) -> Option<&'a Company> {
+        submission
+            .reporting_owners
+            .iter()
+            .find(|company| company.cik() == Some(self.cik))
+    }
+}
+
+impl Issuer {
+    /// The submission's `<ISSUER>`, if it is this issuer.
+    pub fn company<'a>(&self, submission: &'a Submission) -> Option<&'a Company> {
+        submission
+            .issuer
+            .as_ref()
+            .filter(|company| company.cik() == Some(self.cik))
+    }
+}
+
+impl TransactionCoding {
+    fn from_node(node: Node<'_, '_>) -> Result<Option<TransactionCoding>> {
+        let code = match child_text(node, "transactionCode") {
+            Some(code) => code.to_string(),
+            None => return Ok(None),
+        };
+        Ok(Some(TransactionCoding {
+            form_type: child_string(node, "transactionFormType"),
+            code,
+            equity_swap_involved: child_value(node, "equitySwapInvolved", parse_bool)?,
+        }))
+    }
+}
+
+impl TransactionAmounts {
+    fn from_node(node: Option<Node<'_, '_>>) -> Result<TransactionAmounts> {
+        let node = match node {
+            Some(node) => node,
+            None => {
+                return Ok(TransactionAmounts {
+                    shares: None,
+                    price_per_share: None,
+                    acquired_disposed: None,
+                })
+            }
+        };
+        Ok(TransactionAmounts {
+            shares: typed_value(node, "transactionShares", parse_number)?,
+            price_per_share: typed_value(node, "transactionPricePerShare", parse_number)?,
+            acquired_disposed: typed_value(
+                node,
+                "transactionAcquiredDisposedCode",
+                AcquiredDisposed::parse,
+            )?,
+        })
+    }
+}
+
+impl PostTransactionAmounts {
+    fn from_node(node: Option<Node<'_, '_>>) -> Result<PostTransactionAmounts> {
+        match node {
+            Some(node) => Ok(PostTransactionAmounts {
+                shares_owned: typed_value(node, "sharesOwnedFollowingTransaction", parse_number)?,
+                value_owned: typed_value(node, "valueOwnedFollowingTransaction", parse_number)?,
+            }),
+            None => Ok(PostTransactionAmounts::default()),
+        }
+    }
+}
+
+impl OwnershipNature {
+    fn from_node(node: Option<Node<'_, '_>>) -> Result<OwnershipNature> {
+        match node {
+            Some(node) => Ok(OwnershipNature {
+                direct_or_indirect: typed_value(
+                    node,
+                    "directOrIndirectOwnership",
+                    DirectOrIndirect::parse,
+                )?,
+                nature: value_string(node, "natureOfOwnership"),
+            }),
+            None => Ok(OwnershipNature::default()),
+        }
+    }
+}
+
+impl UnderlyingSecurity {
+    fn from_node(node: Option<Node<'_, '_>>) -> Result<UnderlyingSecurity> {
+        match node {
+            Some(node) => Ok(UnderlyingSecurity {
+                title: value_string(node, "underlyingSecurityTitle"),
+                shares: typed_value(node, "underlyingSecurityShares", parse_number)?,
+                value: typed_value(node, "underlyingSecurityValue", parse_number)?,
+            }),
+            None => Ok(UnderlyingSecurity::default()),
+        }
+    }
+}
+
+impl NonDerivativeTransaction {
+    fn from_node(node: Node<'_, '_>) -> Result<NonDerivativeTransaction> {
+        Ok(NonDerivativeTransaction {
+            security_title: value_string(node, "securityTitle"),
+            transaction_date: typed_value(node, "transactionDate", parse_date)?,
+            deemed_execution_date: typed_value(node, "deemedExecutionDate", parse_date)?,
+            coding: match child(node, "transactionCoding") {
+                Some(coding) => TransactionCoding::from_node(coding)?,
+                None => None,
+            },
+            amounts: TransactionAmounts::from_node(child(node, "transactionAmounts"))?,
+            post_transaction: PostTransactionAmounts::from_node(child(
+                node,
+                "postTransactionAmounts",
+            ))?,
+            ownership: OwnershipNature::from_node(child(node, "ownershipNature"))?,
+            footnote_ids: footnote_ids(node),
+        })
+    }
+}
+
+impl NonDerivativeHolding {
+    fn from_node(node: Node<'_, '_>) -> Result<NonDerivativeHolding> {
+        Ok(NonDerivativeHolding {
+            security_title: value_string(node, "securityTitle"),
+            post_transaction: PostTransactionAmounts::from_node(child(
+                node,
+                "postTransactionAmounts",
+            ))?,
+            ownership: OwnershipNature::from_node(child(node, "ownershipNature"))?,
+            footnote_ids: footnote_ids(node),
+        })
+    }
+}
+
+impl DerivativeTransaction {
+    fn from_node(node: Node<'_, '_>) -> Result<DerivativeTransaction> {
+        Ok(DerivativeTransaction {
+            security_title: value_string(node, "securityTitle"),
+            conversion_or_exercise_price: typed_value(
+                node,
+                "conversionOrExercisePrice",
+                parse_number,
+            )?,
+            transaction_date: typed_value(node, "transactionDate", parse_date)?,
+            deemed_execution_date: typed_value(node, "deemedExecutionDate", parse_date)?,
+            coding: match child(node, "transactionCoding") {
+                Some(coding) => TransactionCoding::from_node(coding)?,
+                None => None,
+            },
+            amounts: TransactionAmounts::from_node(child(node, "transactionAmounts"))?,
+            exercise_date: typed_value(node, "exerciseDate", parse_date)?,
+            expiration_date: typed_value(node, "expirationDate", parse_date)?,
+            underlying: UnderlyingSecurity::from_node(child(node, "underlyingSecurity"))?,
+            post_transaction: PostTransactionAmounts::from_node(child(
+                node,
+                "postTransactionAmounts",
+            ))?,
+            ownership: OwnershipNature::from_node(child(node, "ownershipNature"))?,
+            footnote_ids: footnote_ids(node),
+        })
+    }
+}
+
+impl DerivativeHolding {
+    fn from_node(node: Node<'_, '_>) -> Result<DerivativeHolding> {
+        Ok(DerivativeHolding {
+            security_title: value_string(node, "securityTitle"),
+            conversion_or_exercise_price: typed_value(
+                node,
+                "conversionOrExercisePrice",
+                parse_number,
+            )?,
+            exercise_date: typed_value(node, "exerciseDate", parse_date)?,
+            expiration_date: typed_value(node, "expirationDate", parse_date)?,
+            underlying: UnderlyingSecurity::from_node(child(node, "underlyingSecurity"))?,
+            post_transaction: PostTransactionAmounts::from_node(child(
+                node,
+                "postTransactionAmounts",
+            ))?,
+            ownership: OwnershipNature::from_node(child(node, "ownershipNature"))?,
+            footnote_ids: footnote_ids(node),
+        })
+    }
+}
+
+impl Submission {
+    /// Parses the ownership document of a Form 3, 4 or 5. Returns `None` for other forms, for
+    /// paper filings without XML, and when bodies were not loaded.
+    pub fn ownership(&self) -> Option<Result<OwnershipDocument>> {
+        let form = self.form().form;
+        if !matches!(form, Form::Form3 | Form::Form4 | Form::Form5) {
+            return None;
+        }
+        let xml = self
+            .documents
+            .iter()
+            .filter(|document| document.form().form == form)
+            .find_map(|document| document.xml())?;
+        Some(OwnershipDocument::parse(xml))
+    }
+}
@@ -1,22 +1,86 @@
-use crate::document_body::TypedData;
+use crate::context::Context;
+use crate::document_body::{Body, TypedData};
 use crate::document_tree::DocumentTree;
 use crate::document_tree::DocumentTree::ContainerNode;
+use crate::encoding::TextEncoding;
 use crate::error::Result;
+use crate::span::Span;
 use crate::tag::{ContainerTag, ValueTag};
-use crate::types::{parse_bool, parse_date, parse_date_time, MonthDayPair};
+use crate::types::{
+    format_bool, format_date, format_date_time, parse_bool, parse_date, parse_date_time,
+    AccessionNumber, Cik, FileNumber, MonthDayPair, Sic,
+};
+use crate::warning::WarningKind;
 use chrono::{NaiveDate, NaiveDateTime};
 use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+
+/// A container this crate has no struct for, or which appeared somewhere unexpected, kept when
+/// parsing leniently.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct ExtraContainer {
+    pub tag: String,
+    pub values: Vec<(String, String)>,
+    pub containers: Vec<ExtraContainer>,
+}
+
+impl ExtraContainer {
+    // Document bodies have nowhere to go, so any inside the container are dropped.
+    pub fn from_tree(tag: &ContainerTag, parts: &[DocumentTree<'_>]) -> ExtraContainer {
+        let mut values = Vec::new();
+        let mut containers = Vec::new();
+        for part in parts {
+            match part {
+                DocumentTree::ValueNode(tag, value, _) => {
+                    values.push((tag.name().to_string(), value.to_string()))
+                }
+                DocumentTree::ContainerNode(tag, parts, _) => {
+                    containers.push(ExtraContainer::from_tree(tag, parts))
+                }
+                _ => (),
+            }
+        }
+
+        ExtraContainer {
+            tag: tag.name().to_string(),
+            values,
+            containers,
+        }
+    }
+
+    pub fn to_tree(&self) -> DocumentTree<'_> {
+        DocumentTree::container(
+            ContainerTag::Unknown(self.tag.clone()),
+            extra_parts(&self.values, &self.containers).collect(),
+        )
+    }
+}
+
+// Tags are written under their original names, which the writer does not need to recognize.
+fn extra_parts<'a>(
+    extra: &'a [(String, String)],
+    extra_containers: &'a [ExtraContainer],
+) -> impl Iterator<Item = DocumentTree<'a>> {
+    let values = extra
+        .iter()
+        .map(|(tag, value)| DocumentTree::value(ValueTag::Unknown(tag.clone()), value));
+    values.chain(extra_containers.iter().map(ExtraContainer::to_tree))
+}
 
 #[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub struct FilingValues {
     pub form_type: String,
     pub act: Option<String>,
-    pub file_number: Option<String>,
+    pub file_number: Option<FileNumber>,
     pub film_number: Option<String>,
+    pub extra: Vec<(String, String)>,
+    pub extra_containers: Vec<ExtraContainer>,
 }
 
 impl FilingValues {
-    pub fn from_parts(parts: &[DocumentTree]) -> Result<Self> {
+    pub fn from_parts(parts: &[DocumentTree<'_>], ctx: &mut Context) -> Result<Self> {
+        let mut extra = Vec::new();
+        let mut extra_containers = Vec::new();
         let mut form_type = None;
         let mut act = None;
         let mut file_number = None;
@@ -24,51 +88,78 @@ impl FilingValues {
 
         for part in parts {
             match &part {
-                DocumentTree::ValueNode(tag, value) => match tag {
+                DocumentTree::ValueNode(tag, value, _) => match tag {
                     ValueTag::FormType => {
-                        assert!(form_type.is_none());
-                        form_type = Some(value.clone());
+                        ctx.ensure_unset(&form_type, part)?;
+                        form_type = Some(value.to_string());
                     }
                     ValueTag::Act => {
-                        assert!(act.is_none());
-                        act = Some(value.clone());
+                        ctx.ensure_unset(&act, part)?;
+                        act = Some(value.to_string());
                     }
                     ValueTag::FileNumber => {
-                        assert!(file_number.is_none());
-                        file_number = Some(value.clone());
+                        ctx.unique_identifier(
+                            &mut file_number,
+                            part,
+                            FileNumber::parse,
+                            &mut extra,
+                        )?;
                     }
                     ValueTag::FilmNumber => {
-                        assert!(film_number.is_none());
-                        film_number = Some(value.clone());
+                        ctx.ensure_unset(&film_number, part)?;
+                        film_number = Some(value.to_string());
                     }
-                    _ => panic!("Unexpected: {:?}", &part),
+                    _ => ctx.keep_unknown(part, &mut extra, &mut extra_containers)?,
                 },
-                _ => panic!("Unexpected: {:?}", &part),
+                _ => ctx.keep_unknown(part, &mut extra, &mut extra_containers)?,
             }
         }
 
         Ok(FilingValues {
-            form_type: form_type.unwrap(),
+            form_type: ctx.required_value(form_type, ValueTag::FormType)?,
             act,
             file_number,
             film_number,
+            extra,
+            extra_containers,
         })
     }
+
+    // The inverse of `from_parts`.
+    pub fn to_parts(&self) -> Vec<DocumentTree<'_>> {
+        let mut parts = vec![DocumentTree::value(ValueTag::FormType, &self.form_type)];
+        parts.extend(DocumentTree::optional(ValueTag::Act, &self.act));
+        parts.extend(
+            self.file_number
+                .as_ref()
+                .map(|n| DocumentTree::value(ValueTag::FileNumber, n.to_string())),
+        );
+        parts.extend(DocumentTree::optional(
+            ValueTag::FilmNumber,
+            &self.film_number,
+        ));
+        parts.extend(extra_parts(&self.extra, &self.extra_containers));
+        parts
+    }
 }
 
 #[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub struct CompanyData {
     pub conformed_name: String,
-    pub cik: String,
+    pub cik: Cik,
     pub irs_number: Option<String>,
     pub state_of_incorporation: Option<String>,
     pub fiscal_year_end: Option<MonthDayPair>,
-    pub assigned_sic: Option<String>,
+    pub assigned_sic: Option<Sic>,
     pub relationship: Option<String>,
+    pub extra: Vec<(String, String)>,
+    pub extra_containers: Vec<ExtraContainer>,
 }
 
 impl CompanyData {
-    pub fn from_parts(parts: &[DocumentTree]) -> Result<Self> {
+    pub fn from_parts(parts: &[DocumentTree<'_>], ctx: &mut Context) -> Result<Self> {
+        let mut extra = Vec::new();
+        let mut extra_containers = Vec::new();
         let mut conformed_name = None;
         let mut cik = None;
         let mut irs_number = None;
@@ -79,51 +170,83 @@ impl CompanyData {
 
         for part in parts {
             match &part {
-                DocumentTree::ValueNode(tag, value) => match tag {
+                DocumentTree::ValueNode(tag, value, _) => match tag {
                     ValueTag::ConformedName => {
-                        assert!(conformed_name.is_none());
-                        conformed_name = Some(value.clone());
+                        ctx.ensure_unset(&conformed_name, part)?;
+                        conformed_name = Some(value.to_string());
                     }
                     ValueTag::Cik => {
-                        assert!(cik.is_none());
-                        cik = Some(value.clone());
+                        ctx.ensure_unset(&cik, part)?;
+                        cik = Some(Cik::parse(value).ok_or_else(|| ctx.bad_value(part))?);
                     }
                     ValueTag::IrsNumber => {
-                        assert!(irs_number.is_none());
-                        irs_number = Some(value.clone());
+                        ctx.ensure_unset(&irs_number, part)?;
+                        irs_number = Some(value.to_string());
                     }
                     ValueTag::StateOfInforporation => {
-                        assert!(state_of_incorporation.is_none());
-                        state_of_incorporation = Some(value.clone());
+                        ctx.ensure_unset(&state_of_incorporation, part)?;
+                        state_of_incorporation = Some(value.to_string());
                     }
                     ValueTag::FiscalYearEnd => {
-                        assert!(fiscal_year_end.is_none());
-                        fiscal_year_end = Some(MonthDayPair::parse(value));
+                        ctx.ensure_unset(&fiscal_year_end, part)?;
+                        fiscal_year_end =
+                            Some(MonthDayPair::parse(value).ok_or_else(|| ctx.bad_value(part))?);
                     }
                     ValueTag::AssignedSic => {
-                        assert!(assigned_sic.is_none());
-                        assigned_sic = Some(value.clone());
+                        ctx.unique_identifier(&mut assigned_sic, part, Sic::parse, &mut extra)?;
                     }
                     ValueTag::Relationship => {
-                        assert!(relationship.is_none());
-                        relationship = Some(value.clone());
+                        ctx.ensure_unset(&relationship, part)?;
+                        relationship = Some(value.to_string());
                     }
-                    _ => panic!("Unexpected: {:?}", &part),
+                    _ => ctx.keep_unknown(part, &mut extra, &mut extra_containers)?,
                 },
-                _ => panic!("Unexpected: {:?}", &part),
+                _ => ctx.keep_unknown(part, &mut extra, &mut extra_containers)?,
             }
         }
 
         Ok(CompanyData {
-            conformed_name: conformed_name.unwrap(),
-            cik: cik.unwrap(),
+            conformed_name: ctx.required_value(conformed_name, ValueTag::ConformedName)?,
+            cik: ctx.required_value(cik, ValueTag::Cik)?,
             irs_number,
             state_of_incorporation,
             fiscal_year_end,
             assigned_sic,
             relationship,
+            extra,
+            extra_containers,
         })
     }
+
+    pub fn to_parts(&self) -> Vec<DocumentTree<'_>> {
+        let mut parts = vec![
+            DocumentTree::value(ValueTag::ConformedName, &self.conformed_name),
+            DocumentTree::value(ValueTag::Cik, self.cik.to_string()),
+        ];
+        parts.extend(
+            self.assigned_sic
+                .map(|v| DocumentTree::value(ValueTag::AssignedSic, v.to_string())),
+        );
+        parts.extend(DocumentTree::optional(
+            ValueTag::IrsNumber,
+            &self.irs_number,
+        ));
+        parts.extend(DocumentTree::optional(
+            ValueTag::StateOfInforporation,
+            &self.state_of_incorporation,
+        ));
+        parts.extend(
+            self.fiscal_year_end
+                .as_ref()
+                .map(|v| DocumentTree::value(ValueTag::FiscalYearEnd, v.to_string())),
+        );
+        parts.extend(DocumentTree::optional(
+            ValueTag::Relationship,
+            &self.relationship,
+        ));
+        parts.extend(extra_parts(&self.extra, &self.extra_containers));
+        parts
+    }
 }
 
 #[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
@@ -134,10 +257,14 @@ pub struct Address {
     pub state: Option<String>,
     pub zip: Option<String>,
     pub phone: Option<String>,
+    pub extra: Vec<(String, String)>,
+    pub extra_containers: Vec<ExtraContainer>,
 }
 
 impl Address {
-    pub fn from_parts(parts: &[DocumentTree]) -> Result<Self> {
+    pub fn from_parts(parts: &[DocumentTree<'_>], ctx: &mut Context) -> Result<Self> {
+        let mut extra = Vec::new();
+        let mut extra_containers = Vec::new();
         let mut street1 = None;
         let mut street2 = None;
         let mut city = None;
@@ -147,34 +274,34 @@ impl Address {
 
         for part in parts {
             match &part {
-                DocumentTree::ValueNode(tag, value) => match tag {
+                DocumentTree::ValueNode(tag, value, _) => match tag {
                     ValueTag::Street1 => {
-                        assert!(street1.is_none());
-                        street1 = Some(value.clone());
+                        ctx.ensure_unset(&street1, part)?;
+                        street1 = Some(value.to_string());
                     }
                     ValueTag::Street2 => {
-                        assert!(street2.is_none());
-                        street2 = Some(value.clone());
+                        ctx.ensure_unset(&street2, part)?;
+                        street2 = Some(value.to_string());
                     }
                     ValueTag::City => {
-                        assert!(city.is_none());
-                        city = Some(value.clone());
+                        ctx.ensure_unset(&city, part)?;
+                        city = Some(value.to_string());
                     }
                     ValueTag::State => {
-                        assert!(state.is_none());
-                        state = Some(value.clone());
+                        ctx.ensure_unset(&state, part)?;
+                        state = Some(value.to_string());
                     }
                     ValueTag::Zip => {
-                        assert!(zip.is_none());
-                        zip = Some(value.clone());
+                        ctx.ensure_unset(&zip, part)?;
+                        zip = Some(value.to_string());
                     }
                     ValueTag::Phone => {
-                        assert!(phone.is_none());
-                        phone = Some(value.clone());
+                        ctx.ensure_unset(&phone, part)?;
+                        phone = Some(value.to_string());
                     }
-                    _ => panic!("Unexpected: {:?}", &part),
+                    _ => ctx.keep_unknown(part, &mut extra, &mut extra_containers)?,
                 },
-                _ => panic!("Unexpected: {:?}", &part),
+                _ => ctx.keep_unknown(part, &mut extra, &mut extra_containers)?,
             }
         }
 
@@ -185,43 +312,73 @@ impl Address {
             state,
             zip,
             phone,
+            extra,
+            extra_containers,
         })
     }
+
+    pub fn to_parts(&self) -> Vec<DocumentTree<'_>> {
+        let mut parts = Vec::new();
+        parts.extend(DocumentTree::optional(ValueTag::Street1, &self.street1));
+        parts.extend(DocumentTree::optional(ValueTag::Street2, &self.street2));
+        parts.extend(DocumentTree::optional(ValueTag::City, &self.city));
+        parts.extend(DocumentTree::optional(ValueTag::State, &self.state));
+        parts.extend(DocumentTree::optional(ValueTag::Zip, &self.zip));
+        parts.extend(DocumentTree::optional(ValueTag::Phone, &self.phone));
+        parts.extend(extra_parts(&self.extra, &self.extra_containers));
+        parts
+    }
 }
 
 #[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub struct FormerCompany {
     pub former_conformed_name: String,
     pub date_changed: NaiveDate,
+    pub extra: Vec<(String, String)>,
+    pub extra_containers: Vec<ExtraContainer>,
 }
 
 impl FormerCompany {
-    pub fn from_parts(parts: &[DocumentTree]) -> Result<Self> {
+    pub fn from_parts(parts: &[DocumentTree<'_>], ctx: &mut Context) -> Result<Self> {
+        let mut extra = Vec::new();
+        let mut extra_containers = Vec::new();
         let mut former_conformed_name = None;
         let mut date_changed = None;
 
         for part in parts {
             match &part {
-                DocumentTree::ValueNode(tag, value) => match tag {
+                DocumentTree::ValueNode(tag, value, _) => match tag {
                     ValueTag::FormerConformedName => {
-                        assert!(former_conformed_name.is_none());
-                        former_conformed_name = Some(value.clone());
+                        ctx.ensure_unset(&former_conformed_name, part)?;
+                        former_conformed_name = Some(value.to_string());
                     }
                     ValueTag::DateChanged => {
-                        assert!(date_changed.is_none());
-                        date_changed = Some(parse_date(value));
+                        ctx.ensure_unset(&date_changed, part)?;
+                        date_changed = Some(parse_date(value).ok_or_else(|| ctx.bad_value(part))?);
                     }
-                    _ => panic!("Unexpected: {:?}", &part),
+                    _ => ctx.keep_unknown(part, &mut extra, &mut extra_containers)?,
                 },
-                _ => panic!("Unexpected: {:?}", &part),
+                _ => ctx.keep_unknown(part, &mut extra, &mut extra_containers)?,
             }
         }
 
         Ok(FormerCompany {
-            former_conformed_name: former_conformed_name.unwrap(),
-            date_changed: date_changed.unwrap(),
+            former_conformed_name: ctx
+                .required_value(former_conformed_name, ValueTag::FormerConformedName)?,
+            date_changed: ctx.required_value(date_changed, ValueTag::DateChanged)?,
+            extra,
+            extra_containers,
         })
     }
+
+    pub fn to_parts(&self) -> Vec<DocumentTree<'_>> {
+        let mut parts = vec![
+            DocumentTree::value(ValueTag::FormerConformedName, &self.former_conformed_name),
+            DocumentTree::value(ValueTag::DateChanged, format_date(&self.date_changed)),
+        ];
+        parts.extend(extra_parts(&self.extra, &self.extra_containers));
+        parts
+    }
 }
 
 #[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
@@ -233,10 +390,22 @@ pub struct Company {
     pub owner_data: Option<CompanyData>,
     pub former_name: Vec<FormerCompany>,
     pub former_company: Vec<FormerCompany>,
+    pub extra: Vec<(String, String)>,
+    pub extra_containers: Vec<ExtraContainer>,
 }
 
 impl Company {
-    pub fn from_parts(parts: &[DocumentTree]) -> Result<Self> {
+    /// The CIK from `<COMPANY-DATA>`, or from `<OWNER-DATA>` for reporting owners.
+    pub fn cik(&self) -> Option<Cik> {
+        self.company_data
+            .as_ref()
+            .or(self.owner_data.as_ref())
+            .map(|data| data.cik)
+    }
+
+    pub fn from_parts(parts: &[DocumentTree<'_>], ctx: &mut Context) -> Result<Self> {
+        let mut extra = Vec::new();
+        let mut extra_containers = Vec::new();
         let mut company_data = None;
         let mut filing_values = Vec::new();
         let mut business_address = None;
@@ -247,37 +416,45 @@ impl Company {
 
         for part in parts {
             match &part {
-                DocumentTree::ContainerNode(tag, parts) => match tag {
+                DocumentTree::ContainerNode(tag, parts, span) => match tag {
                     ContainerTag::CompanyData => {
-                        assert!(company_data.is_none());
-                        company_data = Some(CompanyData::from_parts(parts)?)
+                        ctx.ensure_unset(&company_data, part)?;
+                        company_data =
+                            Some(ctx.nested(tag, parts, *span, CompanyData::from_parts)?)
                     }
                     ContainerTag::FilingValues => {
-                        filing_values.push(FilingValues::from_parts(parts)?);
+                        filing_values.push(ctx.nested(
+                            tag,
+                            parts,
+                            *span,
+                            FilingValues::from_parts,
+                        )?);
                     }
                     ContainerTag::BusinessAddress => {
-                        assert!(business_address.is_none());
-                        business_address = Some(Address::from_parts(parts)?)
+                        ctx.ensure_unset(&business_address, part)?;
+                        business_address =
+                            Some(ctx.nested(tag, parts, *span, Address::from_parts)?)
                     }
                     ContainerTag::MailAddress => {
-                        assert!(mail_address.is_none());
-                        mail_address = Some(Address::from_parts(parts)?)
+                        ctx.ensure_unset(&mail_address, part)?;
+                        mail_address = Some(ctx.nested(tag, parts, *span, Address::from_parts)?)
                     }
                     ContainerTag::FormerCompany => {
-                        let _fc = FormerCompany::from_parts(parts)?;
+                        let _fc = ctx.nested(tag, parts, *span, FormerCompany::from_parts)?;
                         former_company.push(_fc);
                     }
                     ContainerTag::OwnerData => {
-                        assert!(owner_data.is_none());
-                        owner_data = Some(CompanyData::from_parts(parts)?);
+                        ctx.ensure_unset(&owner_data, part)?;
+                        owner_data =
+                            Some(ctx.nested(tag, parts, *span, CompanyData::from_parts)?);
                     }
                     ContainerTag::FormerName => {
-                        let _fn = FormerCompany::from_parts(parts)?;
+                        let _fn = ctx.nested(tag, parts, *span, FormerCompany::from_parts)?;
                         former_name.push(_fn);
                     }
-                    _ => unimplemented!("{:?}", tag),
+                    _ => ctx.keep_unknown(part, &mut extra, &mut extra_containers)?,
                 },
-                _ => panic!("Unexpected: {:?}", &part),
+                _ => ctx.keep_unknown(part, &mut extra, &mut extra_containers)?,
             }
         }
 
@@ -289,8 +466,51 @@ impl Company {
             owner_data,
             former_name,
             former_company,
+            extra,
+            extra_containers,
         })
     }
+
+    pub fn to_parts(&self) -> Vec<DocumentTree<'_>> {
+        let mut parts = Vec::new();
+        parts.extend(
+            self.company_data
+                .iter()
+                .map(|c| DocumentTree::container(ContainerTag::CompanyData, c.to_parts())),
+        );
+        parts.extend(
+            self.owner_data
+                .iter()
+                .map(|c| DocumentTree::container(ContainerTag::OwnerData, c.to_parts())),
+        );
+        parts.extend(
+            self.filing_values
+                .iter()
+                .map(|f| DocumentTree::container(ContainerTag::FilingValues, f.to_parts())),
+        );
+        parts.extend(
+            self.business_address
+                .iter()
+                .map(|a| DocumentTree::container(ContainerTag::BusinessAddress, a.to_parts())),
+        );
+        parts.extend(
+            self.mail_address
+                .iter()
+                .map(|a| DocumentTree::container(ContainerTag::MailAddress, a.to_parts())),
+        );
+        parts.extend(
+            self.former_company
+                .iter()
+                .map(|f| DocumentTree::container(ContainerTag::FormerCompany, f.to_parts())),
+        );
+        parts.extend(
+            self.former_name
+                .iter()
+                .map(|f| DocumentTree::container(ContainerTag::FormerName, f.to_parts())),
+        );
+        parts.extend(extra_parts(&self.extra, &self.extra_containers));
+        parts
+    }
 }
 
 #[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
@@ -298,58 +518,99 @@ pub struct Document {
     pub doc_type: String,
     pub sequence: u32,
     pub filename: Option<String>,
-    pub body: Option<TypedData>,
+    pub body: Option<Body>,
+    pub encoding: Option<TextEncoding>,
     pub description: Option<String>,
     pub flawed: bool,
+    pub extra: Vec<(String, String)>,
+    pub extra_containers: Vec<ExtraContainer>,
 }
 
 impl Document {
-    pub fn from_parts(parts: &[DocumentTree]) -> Result<Self> {
+    pub fn from_parts(parts: &[DocumentTree<'_>], ctx: &mut Context) -> Result<Self> {
+        let mut extra = Vec::new();
+        let mut extra_containers = Vec::new();
         let mut doc_type = None;
         let mut sequence = None;
         let mut filename = None;
         let mut body = None;
+        let mut encoding = None;
         let mut description = None;
         let mut flawed = false;
 
         for part in parts {
             match &part {
-                DocumentTree::ValueNode(tag, value) => match tag {
+                DocumentTree::ValueNode(tag, value, _) => match tag {
                     ValueTag::Type => {
-                        assert!(doc_type.is_none());
-                        doc_type = Some(value.clone());
+                        ctx.ensure_unset(&doc_type, part)?;
+                        doc_type = Some(value.to_string());
                     }
                     ValueTag::Sequence => {
-                        assert!(sequence.is_none());
-                        sequence = Some(value.parse().unwrap());
+                        ctx.ensure_unset(&sequence, part)?;
+                        sequence = Some(value.parse().map_err(|_| ctx.bad_value(part))?);
                     }
                     ValueTag::Filename => {
-                        assert!(filename.is_none());
-                        filename = Some(value.clone());
+                        ctx.ensure_unset(&filename, part)?;
+                        filename = Some(value.to_string());
                     }
                     ValueTag::Description => {
-                        assert!(description.is_none());
-                        description = Some(value.clone());
+                        ctx.ensure_unset(&description, part)?;
+                        description = Some(value.to_string());
                     }
                     ValueTag::Flawed => {
+                        ctx.warn(WarningKind::FlawedDocument, part.span());
                         flawed = true;
                     }
-                    _ => panic!("Unexpected: {:?}", &part),
+                    _ => ctx.keep_unknown(part, &mut extra, &mut extra_containers)?,
                 },
-                DocumentTree::TextNode(t) => body = Some(TypedData::from_string(t)),
-                _ => panic!("Unexpected: {:?}", &part),
+                DocumentTree::TextNode(t, span) => {
+                    let (typed_data, text_encoding) =
+                        TypedData::from_bytes(t, ctx.options.decoding)
+                            .map_err(|e| ctx.error(e.kind, Some(*span)))?;
+                    body = Some(Body::Loaded(typed_data));
+                    encoding = Some(text_encoding);
+                }
+                DocumentTree::TextRangeNode(range, _) => body = Some(Body::Lazy(*range)),
+                _ => ctx.keep_unknown(part, &mut extra, &mut extra_containers)?,
             }
         }
 
         Ok(Document {
-            doc_type: doc_type.unwrap(),
-            sequence: sequence.unwrap(),
+            doc_type: ctx.required_value(doc_type, ValueTag::Type)?,
+            sequence: ctx.required_value(sequence, ValueTag::Sequence)?,
             filename,
             body,
+            encoding,
             description,
             flawed,
+            extra,
+            extra_containers,
         })
     }
+
+    pub fn to_parts(&self) -> Vec<DocumentTree<'_>> {
+        let mut parts = vec![
+            DocumentTree::value(ValueTag::Type, &self.doc_type),
+            DocumentTree::value(ValueTag::Sequence, self.sequence.to_string()),
+        ];
+        parts.extend(DocumentTree::optional(ValueTag::Filename, &self.filename));
+        parts.extend(DocumentTree::optional(
+            ValueTag::Description,
+            &self.description,
+        ));
+        parts.extend(DocumentTree::flag(ValueTag::Flawed, self.flawed));
+        parts.extend(self.body.as_ref().map(|body| match body {
+            Body::Loaded(typed_data) => {
+                let mut text = b"\n".to_vec();
+                text.extend(typed_data.to_text_block(self.encoding));
+                text.push(b'\n');
+                DocumentTree::TextNode(Cow::Owned(text), Span::default())
+            }
+            Body::Lazy(range) => DocumentTree::TextRangeNode(*range, Span::default()),
+        }));
+        parts.extend(extra_parts(&self.extra, &self.extra_containers));
+        parts
+    }
 }
 
 #[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
@@ -357,53 +618,77 @@ pub struct ClassContract {
     pub class_contract_id: String,
     pub class_contract_name: String,
     pub class_contract_ticker_symbol: Option<String>,
+    pub extra: Vec<(String, String)>,
+    pub extra_containers: Vec<ExtraContainer>,
 }
 
 impl ClassContract {
-    pub fn from_parts(parts: &[DocumentTree]) -> Result<Self> {
+    pub fn from_parts(parts: &[DocumentTree<'_>], ctx: &mut Context) -> Result<Self> {
+        let mut extra = Vec::new();
+        let mut extra_containers = Vec::new();
         let mut class_contract_id = None;
         let mut class_contract_name = None;
         let mut class_contract_ticker_symbol = None;
 
         for part in parts {
             match &part {
-                DocumentTree::ValueNode(tag, value) => match tag {
+                DocumentTree::ValueNode(tag, value, _) => match tag {
                     ValueTag::ClassContractId => {
-                        assert!(class_contract_id.is_none());
-                        class_contract_id = Some(value.clone());
+                        ctx.ensure_unset(&class_contract_id, part)?;
+                        class_contract_id = Some(value.to_string());
                     }
                     ValueTag::ClassContractName => {
-                        assert!(class_contract_name.is_none());
-                        class_contract_name = Some(value.clone());
+                        ctx.ensure_unset(&class_contract_name, part)?;
+                        class_contract_name = Some(value.to_string());
                     }
                     ValueTag::ClassContractTickerSymbol => {
-                        assert!(class_contract_ticker_symbol.is_none());
-                        class_contract_ticker_symbol = Some(value.clone());
+                        ctx.ensure_unset(&class_contract_ticker_symbol, part)?;
+                        class_contract_ticker_symbol = Some(value.to_string());
                     }
-                    _ => panic!("Unexpected: {:?}", &part),
+                    _ => ctx.keep_unknown(part, &mut extra, &mut extra_containers)?,
                 },
-                _ => panic!("Unexpected: {:?}", &part),
+                _ => ctx.keep_unknown(part, &mut extra, &mut extra_containers)?,
             }
         }
 
         Ok(ClassContract {
-            class_contract_id: class_contract_id.unwrap(),
-            class_contract_name: class_contract_name.unwrap(),
+            class_contract_id: ctx.required_value(class_contract_id, ValueTag::ClassContractId)?,
+            class_contract_name: ctx
+                .required_value(class_contract_name, ValueTag::ClassContractName)?,
             class_contract_ticker_symbol,
+            extra,
+            extra_containers,
         })
     }
+
+    pub fn to_parts(&self) -> Vec<DocumentTree<'_>> {
+        let mut parts = vec![
+            DocumentTree::value(ValueTag::ClassContractId, &self.class_contract_id),
+            DocumentTree::value(ValueTag::ClassContractName, &self.class_contract_name),
+        ];
+        parts.extend(DocumentTree::optional(
+            ValueTag::ClassContractTickerSymbol,
+            &self.class_contract_ticker_symbol,
+        ));
+        parts.extend(extra_parts(&self.extra, &self.extra_containers));
+        parts
+    }
 }
 
 #[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub struct Series {
-    pub owner_cik: Option<String>,
+    pub owner_cik: Option<Cik>,
     pub series_id: String,
     pub series_name: String,
     pub class_contracts: Vec<ClassContract>,
+    pub extra: Vec<(String, String)>,
+    pub extra_containers: Vec<ExtraContainer>,
 }
 
 impl Series {
-    pub fn from_parts(parts: &[DocumentTree]) -> Result<Self> {
+    pub fn from_parts(parts: &[DocumentTree<'_>], ctx: &mut Context) -> Result<Self> {
+        let mut extra = Vec::new();
+        let mut extra_containers = Vec::new();
         let mut owner_cik = None;
         let mut series_id = None;
         let mut series_name = None;
@@ -411,167 +696,257 @@ impl Series {
 
         for part in parts {
             match &part {
-                DocumentTree::ValueNode(tag, value) => match tag {
+                DocumentTree::ValueNode(tag, value, _) => match tag {
                     ValueTag::OwnerCik => {
-                        assert!(owner_cik.is_none());
-                        owner_cik = Some(value.clone());
+                        ctx.unique_identifier(&mut owner_cik, part, Cik::parse, &mut extra)?;
                     }
                     ValueTag::SeriesId => {
-                        assert!(series_id.is_none());
-                        series_id = Some(value.clone());
+                        ctx.ensure_unset(&series_id, part)?;
+                        series_id = Some(value.to_string());
                     }
                     ValueTag::SeriesName => {
-                        assert!(series_name.is_none());
-                        series_name = Some(value.clone());
+                        ctx.ensure_unset(&series_name, part)?;
+                        series_name = Some(value.to_string());
                     }
-                    _ => panic!("Unexpected: {:?}", &part),
+                    _ => ctx.keep_unknown(part, &mut extra, &mut extra_containers)?,
                 },
-                DocumentTree::ContainerNode(tag, parts) => match tag {
+                DocumentTree::ContainerNode(tag, parts, span) => match tag {
                     ContainerTag::ClassContract => {
-                        let class_contract = ClassContract::from_parts(parts)?;
+                        let class_contract =
+                            ctx.nested(tag, parts, *span, ClassContract::from_parts)?;
                         class_contracts.push(class_contract);
                     }
-                    _ => unimplemented!("{:?}", tag),
+                    _ => ctx.keep_unknown(part, &mut extra, &mut extra_containers)?,
                 },
 
-                _ => panic!("Unexpected: {:?}", &part),
+                _ => ctx.keep_unknown(part, &mut extra, &mut extra_containers)?,
             }
         }
 
         Ok(Series {
             owner_cik,
-            series_id: series_id.unwrap(),
-            series_name: series_name.unwrap(),
+            series_id: ctx.required_value(series_id, ValueTag::SeriesId)?,
+            series_name: ctx.required_value(series_name, ValueTag::SeriesName)?,
             class_contracts,
+            extra,
+            extra_containers,
         })
     }
+
+    pub fn to_parts(&self) -> Vec<DocumentTree<'_>> {
+        let mut parts = Vec::new();
+        parts.extend(
+            self.owner_cik
+                .map(|v| DocumentTree::value(ValueTag::OwnerCik, v.to_string())),
+        );
+        parts.push(DocumentTree::value(ValueTag::SeriesId, &self.series_id));
+        parts.push(DocumentTree::value(ValueTag::SeriesName, &self.series_name));
+        parts.extend(
+            self.class_contracts
+                .iter()
+                .map(|c| DocumentTree::container(ContainerTag::ClassContract, c.to_parts())),
+        );
+        parts.extend(extra_parts(&self.extra, &self.extra_containers));
+        parts
+    }
 }
 
 #[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub struct AcquiringData {
-    pub cik: String,
+    pub cik: Cik,
     pub series: Series,
+    pub extra: Vec<(String, String)>,
+    pub extra_containers: Vec<ExtraContainer>,
 }
 
 impl AcquiringData {
-    pub fn from_parts(parts: &[DocumentTree]) -> Result<Self> {
+    pub fn from_parts(parts: &[DocumentTree<'_>], ctx: &mut Context) -> Result<Self> {
+        let mut extra = Vec::new();
+        let mut extra_containers = Vec::new();
         let mut series = None;
         let mut cik = None;
 
         for part in parts {
             match &part {
-                DocumentTree::ValueNode(ValueTag::Cik, value) => {
-                    assert!(cik.is_none());
-                    cik = Some(value.clone());
+                DocumentTree::ValueNode(ValueTag::Cik, value, _) => {
+                    ctx.ensure_unset(&cik, part)?;
+                    cik = Some(Cik::parse(value).ok_or_else(|| ctx.bad_value(part))?);
                 }
-                DocumentTree::ContainerNode(ContainerTag::Series, parts) => {
-                    assert!(series.is_none());
-                    series = Some(Series::from_parts(parts)?);
+                DocumentTree::ContainerNode(ContainerTag::Series, parts, span) => {
+                    ctx.ensure_unset(&series, part)?;
+                    series = Some(ctx.nested(
+                        &ContainerTag::Series,
+                        parts,
+                        *span,
+                        Series::from_parts,
+                    )?);
                 }
-                _ => panic!("Unexpected: {:?}", &part),
+                _ => ctx.keep_unknown(part, &mut extra, &mut extra_containers)?,
             }
         }
 
         Ok(AcquiringData {
-            series: series.unwrap(),
-            cik: cik.unwrap(),
+            series: ctx.required_container(series, ContainerTag::Series)?,
+            cik: ctx.required_value(cik, ValueTag::Cik)?,
+            extra,
+            extra_containers,
         })
     }
+
+    pub fn to_parts(&self) -> Vec<DocumentTree<'_>> {
+        let mut parts = vec![
+            DocumentTree::value(ValueTag::Cik, self.cik.to_string()),
+            DocumentTree::container(ContainerTag::Series, self.series.to_parts()),
+        ];
+        parts.extend(extra_parts(&self.extra, &self.extra_containers));
+        parts
+    }
 }
 
 #[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub struct TargetData {
-    pub cik: String,
+    pub cik: Cik,
     pub series: Vec<Series>,
+    pub extra: Vec<(String, String)>,
+    pub extra_containers: Vec<ExtraContainer>,
 }
 
 impl TargetData {
-    pub fn from_parts(parts: &[DocumentTree]) -> Result<Self> {
+    pub fn from_parts(parts: &[DocumentTree<'_>], ctx: &mut Context) -> Result<Self> {
+        let mut extra = Vec::new();
+        let mut extra_containers = Vec::new();
         let mut series = Vec::new();
         let mut cik = None;
 
         for part in parts {
             match &part {
-                DocumentTree::ValueNode(ValueTag::Cik, value) => {
-                    assert!(cik.is_none());
-                    cik = Some(value.clone());
+                DocumentTree::ValueNode(ValueTag::Cik, value, _) => {
+                    ctx.ensure_unset(&cik, part)?;
+                    cik = Some(Cik::parse(value).ok_or_else(|| ctx.bad_value(part))?);
                 }
-                DocumentTree::ContainerNode(ContainerTag::Series, parts) => {
-                    series.push(Series::from_parts(parts)?);
+                DocumentTree::ContainerNode(ContainerTag::Series, parts, span) => {
+                    series.push(ctx.nested(
+                        &ContainerTag::Series,
+                        parts,
+                        *span,
+                        Series::from_parts,
+                    )?);
                 }
-                _ => panic!("Unexpected: {:?}", &part),
+                _ => ctx.keep_unknown(part, &mut extra, &mut extra_containers)?,
             }
         }
 
         Ok(TargetData {
             series,
-            cik: cik.unwrap(),
+            cik: ctx.required_value(cik, ValueTag::Cik)?,
+            extra,
+            extra_containers,
         })
     }
+
+    pub fn to_parts(&self) -> Vec<DocumentTree<'_>> {
+        let mut parts = vec![DocumentTree::value(ValueTag::Cik, self.cik.to_string())];
+        parts.extend(
+            self.series
+                .iter()
+                .map(|s| DocumentTree::container(ContainerTag::Series, s.to_parts())),
+        );
+        parts.extend(extra_parts(&self.extra, &self.extra_containers));
+        parts
+    }
 }
 
 #[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub struct Merger {
     pub acquiring_data: AcquiringData,
     pub target_data: Vec<TargetData>,
+    pub extra: Vec<(String, String)>,
+    pub extra_containers: Vec<ExtraContainer>,
 }
 
 impl Merger {
-    pub fn from_parts(parts: &[DocumentTree]) -> Result<Self> {
+    pub fn from_parts(parts: &[DocumentTree<'_>], ctx: &mut Context) -> Result<Self> {
+        let mut extra = Vec::new();
+        let mut extra_containers = Vec::new();
         let mut acquiring_data = None;
         let mut target_data = Vec::new();
 
         for part in parts {
             match &part {
-                ContainerNode(tag, parts) => match tag {
+                ContainerNode(tag, parts, span) => match tag {
                     ContainerTag::AcquiringData => {
-                        assert!(acquiring_data.is_none());
-                        acquiring_data = Some(AcquiringData::from_parts(parts)?)
+                        ctx.ensure_unset(&acquiring_data, part)?;
+                        acquiring_data =
+                            Some(ctx.nested(tag, parts, *span, AcquiringData::from_parts)?)
                     }
                     ContainerTag::TargetData => {
-                        target_data.push(TargetData::from_parts(parts)?);
+                        target_data.push(ctx.nested(tag, parts, *span, TargetData::from_parts)?);
                     }
-                    _ => panic!("Unexpected: {:?}", &part),
+                    _ => ctx.keep_unknown(part, &mut extra, &mut extra_containers)?,
                 },
-                _ => panic!("Unexpected: {:?}", &part),
+                _ => ctx.keep_unknown(part, &mut extra, &mut extra_containers)?,
             }
         }
         Ok(Merger {
-            acquiring_data: acquiring_data.unwrap(),
+            acquiring_data: ctx.required_container(acquiring_data, ContainerTag::AcquiringData)?,
             target_data,
+            extra,
+            extra_containers,
         })
     }
+
+    pub fn to_parts(&self) -> Vec<DocumentTree<'_>> {
+        let mut parts = vec![DocumentTree::container(
+            ContainerTag::AcquiringData,
+            self.acquiring_data.to_parts(),
+        )];
+        parts.extend(
+            self.target_data
+                .iter()
+                .map(|t| DocumentTree::container(ContainerTag::TargetData, t.to_parts())),
+        );
+        parts.extend(extra_parts(&self.extra, &self.extra_containers));
+        parts
+    }
 }
 
 #[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub struct NewSeriesAndClassesContracts {
-    pub owner_cik: Option<String>,
+    pub owner_cik: Option<Cik>,
     pub new_series: Vec<Series>,
     pub new_classes_contract: Vec<Series>,
+    pub extra: Vec<(String, String)>,
+    pub extra_containers: Vec<ExtraContainer>,
 }
 
 impl NewSeriesAndClassesContracts {
-    pub fn from_parts(parts: &[DocumentTree]) -> Result<Self> {
+    pub fn from_parts(parts: &[DocumentTree<'_>], ctx: &mut Context) -> Result<Self> {
+        let mut extra = Vec::new();
+        let mut extra_containers = Vec::new();
         let mut new_series = Vec::new();
         let mut new_classes_contract = Vec::new();
         let mut owner_cik = None;
 
         for part in parts {
             match &part {
-                DocumentTree::ValueNode(ValueTag::OwnerCik, value) => {
-                    assert!(owner_cik.is_none());
-                    owner_cik = Some(value.clone());
+                DocumentTree::ValueNode(ValueTag::OwnerCik, _, _) => {
+                    ctx.unique_identifier(&mut owner_cik, part, Cik::parse, &mut extra)?;
                 }
-                DocumentTree::ContainerNode(tag, parts) => match tag {
+                DocumentTree::ContainerNode(tag, parts, span) => match tag {
                     ContainerTag::NewSeries => {
-                        new_series.push(Series::from_parts(parts)?);
+                        new_series.push(ctx.nested(tag, parts, *span, Series::from_parts)?);
                     }
                     ContainerTag::NewClassesContracts => {
-                        new_classes_contract.push(Series::from_parts(parts)?);
-                    }
-                    _ => unimplemented!("{:?}", tag),
+                        new_classes_contract.push(ctx.nested(
+                            tag,
+                            parts,
+                            *span,
+                            Series::from_parts,
+                        )?);
+                    }
+                    _ => ctx.keep_unknown(part, &mut extra, &mut extra_containers)?,
                 },
-                _ => panic!("Unexpected: {:?}", &part),
+                _ => ctx.keep_unknown(part, &mut extra, &mut extra_containers)?,
             }
         }
 
@@ -579,59 +954,117 @@ impl NewSeriesAndClassesContracts {
             new_series,
             owner_cik,
             new_classes_contract,
+            extra,
+            extra_containers,
         })
     }
+
+    pub fn to_parts(&self) -> Vec<DocumentTree<'_>> {
+        let mut parts = Vec::new();
+        parts.extend(
+            self.owner_cik
+                .map(|v| DocumentTree::value(ValueTag::OwnerCik, v.to_string())),
+        );
+        parts.extend(
+            self.new_series
+                .iter()
+                .map(|s| DocumentTree::container(ContainerTag::NewSeries, s.to_parts())),
+        );
+        parts.extend(
+            self.new_classes_contract
+                .iter()
+                .map(|s| DocumentTree::container(ContainerTag::NewClassesContracts, s.to_parts())),
+        );
+        parts.extend(extra_parts(&self.extra, &self.extra_containers));
+        parts
+    }
 }
 
 #[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub struct SeriesAndClassesContracts {
     pub series: Vec<Series>,
+    pub extra: Vec<(String, String)>,
+    pub extra_containers: Vec<ExtraContainer>,
 }
 
 impl SeriesAndClassesContracts {
-    pub fn from_parts(parts: &[DocumentTree]) -> Result<Self> {
+    pub fn from_parts(parts: &[DocumentTree<'_>], ctx: &mut Context) -> Result<Self> {
+        let mut extra = Vec::new();
+        let mut extra_containers = Vec::new();
         let mut series = Vec::new();
 
         for part in parts {
             match &part {
-                DocumentTree::ContainerNode(tag, parts) => match tag {
+                DocumentTree::ContainerNode(tag, parts, span) => match tag {
                     ContainerTag::Series => {
-                        let s = Series::from_parts(parts)?;
+                        let s = ctx.nested(tag, parts, *span, Series::from_parts)?;
                         series.push(s);
                     }
-                    _ => unimplemented!("{:?}", tag),
+                    _ => ctx.keep_unknown(part, &mut extra, &mut extra_containers)?,
                 },
-                _ => panic!("Unexpected: {:?}", &part),
+                _ => ctx.keep_unknown(part, &mut extra, &mut extra_containers)?,
             }
         }
 
-        Ok(SeriesAndClassesContracts { series })
+        Ok(SeriesAndClassesContracts {
+            series,
+            extra,
+            extra_containers,
+        })
+    }
+
+    pub fn to_parts(&self) -> Vec<DocumentTree<'_>> {
+        let mut parts: Vec<_> = self
+            .series
+            .iter()
+            .map(|s| DocumentTree::container(ContainerTag::Series, s.to_parts()))
+            .collect();
+        parts.extend(extra_parts(&self.extra, &self.extra_containers));
+        parts
     }
 }
 
 #[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub struct MergerSeriesAndClassContracts {
     pub mergers: Vec<Merger>,
+    pub extra: Vec<(String, String)>,
+    pub extra_containers: Vec<ExtraContainer>,
 }
 
 impl MergerSeriesAndClassContracts {
-    pub fn from_parts(parts: &[DocumentTree]) -> Result<Self> {
+    pub fn from_parts(parts: &[DocumentTree<'_>], ctx: &mut Context) -> Result<Self> {
+        let mut extra = Vec::new();
+        let mut extra_containers = Vec::new();
         let mut mergers = Vec::new();
 
         for part in parts {
             match &part {
-                DocumentTree::ContainerNode(tag, parts) => match tag {
+                DocumentTree::ContainerNode(tag, parts, span) => match tag {
                     ContainerTag::Merger => {
-                        let merger = Merger::from_parts(parts)?;
+                        let merger = ctx.nested(tag, parts, *span, Merger::from_parts)?;
                         mergers.push(merger);
                     }
-                    _ => unimplemented!("{:?}", tag),
+                    _ => ctx.keep_unknown(part, &mut extra, &mut extra_containers)?,
                 },
-                _ => panic!("Unexpected: {:?}", &part),
+                _ => ctx.keep_unknown(part, &mut extra, &mut extra_containers)?,
             }
         }
 
-        Ok(MergerSeriesAndClassContracts { mergers })
+        Ok(MergerSeriesAndClassContracts {
+            mergers,
+            extra,
+            extra_containers,
+        })
+    }
+
+    pub fn to_parts(&self) -> Vec<DocumentTree<'_>> {
+        let mut parts: Vec<_> = self
+            .mergers
+            .iter()
+            .map(|m| DocumentTree::container(ContainerTag::Merger, m.to_parts()))
+            .collect();
+        parts.extend(extra_parts(&self.extra, &self.extra_containers));
+        parts
     }
 }
 
@@ -640,35 +1073,72 @@ pub struct SeriesAndClassesContractsData {
     pub existing_series_and_classes_contracts: Option<SeriesAndClassesContracts>,
     pub merger_series_and_classes_contracts: Option<MergerSeriesAndClassContracts>,
     pub new_series_and_classes_contracts: Option<NewSeriesAndClassesContracts>,
+    pub extra: Vec<(String, String)>,
+    pub extra_containers: Vec<ExtraContainer>,
 }
 
 impl SeriesAndClassesContractsData {
-    pub fn from_parts(parts: &[DocumentTree]) -> Result<Self> {
+    /// Every series listed, whether existing, new, or party to a merger.
+    pub fn series(&self) -> impl Iterator<Item = &Series> {
+        let existing = self
+            .existing_series_and_classes_contracts
+            .iter()
+            .flat_map(|existing| &existing.series);
+        let new = self
+            .new_series_and_classes_contracts
+            .iter()
+            .flat_map(|new| new.new_series.iter().chain(&new.new_classes_contract));
+        let mergers = self
+            .merger_series_and_classes_contracts
+            .iter()
+            .flat_map(|mergers| &mergers.mergers)
+            .flat_map(|merger| {
+                std::iter::once(&merger.acquiring_data.series)
+                    .chain(merger.target_data.iter().flat_map(|target| &target.series))
+            });
+        existing.chain(new).chain(mergers)
+    }
+
+    pub fn from_parts(parts: &[DocumentTree<'_>], ctx: &mut Context) -> Result<Self> {
+        let mut extra = Vec::new();
+        let mut extra_containers = Vec::new();
         let mut existing_series_and_classes_contracts = None;
         let mut merger_series_and_classes_contracts = None;
         let mut new_series_and_classes_contracts = None;
 
         for part in parts {
             match &part {
-                DocumentTree::ContainerNode(tag, parts) => match tag {
+                DocumentTree::ContainerNode(tag, parts, span) => match tag {
                     ContainerTag::ExistingSeriesAndClassesContracts => {
-                        assert!(existing_series_and_classes_contracts.is_none());
-                        existing_series_and_classes_contracts =
-                            Some(SeriesAndClassesContracts::from_parts(parts)?);
+                        ctx.ensure_unset(&existing_series_and_classes_contracts, part)?;
+                        existing_series_and_classes_contracts = Some(ctx.nested(
+                            tag,
+                            parts,
+                            *span,
+                            SeriesAndClassesContracts::from_parts,
+                        )?);
                     }
                     ContainerTag::MergerSeriesAndClassesContracts => {
-                        assert!(merger_series_and_classes_contracts.is_none());
-                        merger_series_and_classes_contracts =
-                            Some(MergerSeriesAndClassContracts::from_parts(parts)?);
+                        ctx.ensure_unset(&merger_series_and_classes_contracts, part)?;
+                        merger_series_and_classes_contracts = Some(ctx.nested(
+                            tag,
+                            parts,
+                            *span,
+                            MergerSeriesAndClassContracts::from_parts,
+                        )?);
                     }
                     ContainerTag::NewSeriesAndClassesContracts => {
-                        assert!(new_series_and_classes_contracts.is_none());
-                        new_series_and_classes_contracts =
-                            Some(NewSeriesAndClassesContracts::from_parts(parts)?);
-                    }
-                    _ => unimplemented!("{:?}", tag),
+                        ctx.ensure_unset(&new_series_and_classes_contracts, part)?;
+                        new_series_and_classes_contracts = Some(ctx.nested(
+                            tag,
+                            parts,
+                            *span,
+                            NewSeriesAndClassesContracts::from_parts,
+                        )?);
+                    }
+                    _ => ctx.keep_unknown(part, &mut extra, &mut extra_containers)?,
                 },
-                _ => panic!("Unexpected: {:?}", &part),
+                _ => ctx.keep_unknown(part, &mut extra, &mut extra_containers)?,
             }
         }
 
@@ -676,14 +1146,36 @@ impl SeriesAndClassesContractsData {
             existing_series_and_classes_contracts,
             merger_series_and_classes_contracts,
             new_series_and_classes_contracts,
+            extra,
+            extra_containers,
         })
     }
+
+    pub fn to_parts(&self) -> Vec<DocumentTree<'_>> {
+        let mut parts = Vec::new();
+        parts.extend(self.existing_series_and_classes_contracts.iter().map(|s| {
+            DocumentTree::container(
+                ContainerTag::ExistingSeriesAndClassesContracts,
+                s.to_parts(),
+            )
+        }));
+        parts.extend(self.merger_series_and_classes_contracts.iter().map(|m| {
+            DocumentTree::container(ContainerTag::MergerSeriesAndClassesContracts, m.to_parts())
+        }));
+        parts.extend(self.new_series_and_classes_contracts.iter().map(|n| {
+            DocumentTree::container(ContainerTag::NewSeriesAndClassesContracts, n.to_parts())
+        }));
+        parts.extend(extra_parts(&self.extra, &self.extra_containers));
+        parts
+    }
 }
 
 #[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub struct Submission {
-    pub accession_number: String,
+    pub accession_number: AccessionNumber,
     pub filing_type: String,
+    /// As declared in the header, which `validate` checks against `documents`.
+    pub public_document_count: Option<usize>,
     pub items: Vec<String>,
     pub filing_date: NaiveDate,
     pub date_of_filing_date_change: Option<NaiveDate>,
@@ -710,20 +1202,20 @@ pub struct Submission {
     pub no_quarterly_activity: Option<bool>,
     pub no_annual_activity: Option<bool>,
     pub abs_asset_class: Option<String>,
-    pub depositor_cik: Option<String>,
-    pub sponsor_cik: Option<String>,
+    pub depositor_cik: Option<Cik>,
+    pub sponsor_cik: Option<Cik>,
     pub category: Option<String>,
     pub registered_entity: Option<bool>,
     pub depositor: Option<Company>,
     pub securitizer: Option<Company>,
     pub references_429: Option<String>,
-    pub securitizer_cik: Option<String>,
-    pub issuing_entity_cik: Option<String>,
+    pub securitizer_cik: Option<Cik>,
+    pub issuing_entity_cik: Option<Cik>,
     pub issuing_entity_name: Option<String>,
     pub paper: bool,
     pub confirming_copy: bool,
-    pub securitizer_file_number: Option<String>,
-    pub depositor_file_number: Option<String>,
+    pub securitizer_file_number: Option<FileNumber>,
+    pub depositor_file_number: Option<FileNumber>,
     pub timestamp: Option<NaiveDateTime>,
     pub private_to_public: bool,
     pub filed_for: Vec<Company>,
@@ -732,14 +1224,18 @@ pub struct Submission {
     pub deletion: bool,
     pub correction: bool,
     pub sros: Option<String>,
-    pub previous_accession_number: Option<String>,
+    pub previous_accession_number: Option<AccessionNumber>,
+    pub extra: Vec<(String, String)>,
+    pub extra_containers: Vec<ExtraContainer>,
 }
 
 impl Submission {
-    pub fn from_parts(parts: &[DocumentTree]) -> Result<Self> {
+    pub fn from_parts(parts: &[DocumentTree<'_>], ctx: &mut Context) -> Result<Self> {
+        let mut extra = Vec::new();
+        let mut extra_containers = Vec::new();
         let mut accession_number = None;
         let mut filing_type = None;
-        let mut public_document_count: usize = 0;
+        let mut public_document_count: Option<usize> = None;
         let mut items = Vec::new();
         let mut filing_date = None;
         let mut date_of_filing_date_change = None;
@@ -792,124 +1288,136 @@ impl Submission {
 
         for part in parts {
             match &part {
-                DocumentTree::ValueNode(tag, value) => match tag {
+                DocumentTree::ValueNode(tag, value, _) => match tag {
                     ValueTag::AccessionNumber => {
-                        assert!(accession_number.is_none());
-                        accession_number = Some(value.clone());
+                        ctx.ensure_unset(&accession_number, part)?;
+                        accession_number =
+                            Some(AccessionNumber::parse(value).ok_or_else(|| ctx.bad_value(part))?);
                     }
                     ValueTag::Type => {
-                        assert!(filing_type.is_none());
-                        filing_type = Some(value.clone());
+                        ctx.ensure_unset(&filing_type, part)?;
+                        filing_type = Some(value.to_string());
                     }
                     ValueTag::PublicDocumentCount => {
-                        assert_eq!(0, public_document_count);
-                        public_document_count = value.parse().unwrap();
+                        ctx.ensure_unset(&public_document_count, part)?;
+                        public_document_count =
+                            Some(value.parse().map_err(|_| ctx.bad_value(part))?);
                     }
                     ValueTag::Items => {
-                        items.push(value.clone());
+                        items.push(value.to_string());
                     }
                     ValueTag::FilingDate => {
-                        assert!(filing_date.is_none());
-                        filing_date = Some(parse_date(value));
+                        ctx.ensure_unset(&filing_date, part)?;
+                        filing_date = Some(parse_date(value).ok_or_else(|| ctx.bad_value(part))?);
                     }
                     ValueTag::DateOfFilingDateChange => {
-                        assert!(date_of_filing_date_change.is_none());
-                        date_of_filing_date_change = Some(parse_date(value));
+                        ctx.ensure_unset(&date_of_filing_date_change, part)?;
+                        date_of_filing_date_change =
+                            Some(parse_date(value).ok_or_else(|| ctx.bad_value(part))?);
                     }
                     ValueTag::EffectivenessDate => {
-                        assert!(effectiveness_date.is_none());
-                        effectiveness_date = Some(parse_date(value));
+                        ctx.ensure_unset(&effectiveness_date, part)?;
+                        effectiveness_date =
+                            Some(parse_date(value).ok_or_else(|| ctx.bad_value(part))?);
                     }
                     ValueTag::Period => {
-                        assert!(period.is_none());
-                        period = Some(parse_date(value));
+                        ctx.ensure_unset(&period, part)?;
+                        period = Some(parse_date(value).ok_or_else(|| ctx.bad_value(part))?);
                     }
                     ValueTag::GroupMembers => {
-                        group_members.push(value.clone());
+                        group_members.push(value.to_string());
                     }
                     ValueTag::Reference462B => {
-                        assert!(reference_462b.is_none());
-                        reference_462b = Some(value.clone());
+                        ctx.ensure_unset(&reference_462b, part)?;
+                        reference_462b = Some(value.to_string());
                     }
                     ValueTag::IsFilerANewRegistrant => {
-                        assert!(is_filer_a_new_registrant.is_none());
-                        is_filer_a_new_registrant = Some(parse_bool(value));
+                        ctx.ensure_unset(&is_filer_a_new_registrant, part)?;
+                        is_filer_a_new_registrant =
+                            Some(parse_bool(value).ok_or_else(|| ctx.bad_value(part))?);
                     }
                     ValueTag::IsFilerAWellKnownSeasonedIssuer => {
-                        assert!(is_filer_a_well_known_seasoned_issuer.is_none());
-                        is_filer_a_well_known_seasoned_issuer = Some(parse_bool(value));
+                        ctx.ensure_unset(&is_filer_a_well_known_seasoned_issuer, part)?;
+                        is_filer_a_well_known_seasoned_issuer =
+                            Some(parse_bool(value).ok_or_else(|| ctx.bad_value(part))?);
                     }
                     ValueTag::FiledPursuantToGeneralInstructionA2 => {
-                        assert!(filed_pursuant_to_general_instruction_a2.is_none());
-                        filed_pursuant_to_general_instruction_a2 = Some(parse_bool(value));
+                        ctx.ensure_unset(&filed_pursuant_to_general_instruction_a2, part)?;
+                        filed_pursuant_to_general_instruction_a2 =
+                            Some(parse_bool(value).ok_or_else(|| ctx.bad_value(part))?);
                     }
                     ValueTag::IsFund24F2Eligible => {
-                        assert!(is_fund_24f2_eligible.is_none());
-                        is_fund_24f2_eligible = Some(parse_bool(value));
+                        ctx.ensure_unset(&is_fund_24f2_eligible, part)?;
+                        is_fund_24f2_eligible =
+                            Some(parse_bool(value).ok_or_else(|| ctx.bad_value(part))?);
                     }
                     ValueTag::ActionDate => {
-                        assert!(action_date.is_none());
-                        action_date = Some(parse_date(value));
+                        ctx.ensure_unset(&action_date, part)?;
+                        action_date = Some(parse_date(value).ok_or_else(|| ctx.bad_value(part))?);
                     }
                     ValueTag::ReceivedDate => {
-                        assert!(received_date.is_none());
-                        received_date = Some(parse_date(value));
+                        ctx.ensure_unset(&received_date, part)?;
+                        received_date = Some(parse_date(value).ok_or_else(|| ctx.bad_value(part))?);
                     }
                     ValueTag::MaIIndividual => {
-                        assert!(ma_i_individual.is_none());
-                        ma_i_individual = Some(value.clone());
+                        ctx.ensure_unset(&ma_i_individual, part)?;
+                        ma_i_individual = Some(value.to_string());
                     }
                     ValueTag::AbsRule => {
-                        assert!(abs_rule.is_none());
-                        abs_rule = Some(value.clone());
+                        ctx.ensure_unset(&abs_rule, part)?;
+                        abs_rule = Some(value.to_string());
                     }
                     ValueTag::PeriodStart => {
-                        assert!(period_start.is_none());
-                        period_start = Some(parse_date(value));
+                        ctx.ensure_unset(&period_start, part)?;
+                        period_start = Some(parse_date(value).ok_or_else(|| ctx.bad_value(part))?);
                     }
                     ValueTag::NoQuarterlyActivity => {
-                        assert!(no_quarterly_activity.is_none());
-                        no_quarterly_activity = Some(parse_bool(value));
+                        ctx.ensure_unset(&no_quarterly_activity, part)?;
+                        no_quarterly_activity =
+                            Some(parse_bool(value).ok_or_else(|| ctx.bad_value(part))?);
                     }
                     ValueTag::NoAnnualActivity => {
-                        assert!(no_annual_activity.is_none());
-                        no_annual_activity = Some(parse_bool(value));
+                        ctx.ensure_unset(&no_annual_activity, part)?;
+                        no_annual_activity =
+                            Some(parse_bool(value).ok_or_else(|| ctx.bad_value(part))?);
                     }
                     ValueTag::AbsAssetClass => {
-                        assert!(abs_asset_class.is_none());
-                        abs_asset_class = Some(value.clone());
+                        ctx.ensure_unset(&abs_asset_class, part)?;
+                        abs_asset_class = Some(value.to_string());
                     }
                     ValueTag::DepositorCik => {
-                        assert!(depositor_cik.is_none());
-                        depositor_cik = Some(value.clone());
+                        ctx.unique_identifier(&mut depositor_cik, part, Cik::parse, &mut extra)?;
                     }
                     ValueTag::SponsorCik => {
-                        assert!(sponsor_cik.is_none());
-                        sponsor_cik = Some(value.clone());
+                        ctx.unique_identifier(&mut sponsor_cik, part, Cik::parse, &mut extra)?;
                     }
                     ValueTag::Category => {
-                        assert!(category.is_none());
-                        category = Some(value.clone())
+                        ctx.ensure_unset(&category, part)?;
+                        category = Some(value.to_string())
                     }
                     ValueTag::RegisteredEntity => {
-                        assert!(registered_entity.is_none());
-                        registered_entity = Some(parse_bool(value));
+                        ctx.ensure_unset(&registered_entity, part)?;
+                        registered_entity =
+                            Some(parse_bool(value).ok_or_else(|| ctx.bad_value(part))?);
                     }
                     ValueTag::References429 => {
-                        assert!(references_429.is_none());
-                        references_429 = Some(value.clone());
+                        ctx.ensure_unset(&references_429, part)?;
+                        references_429 = Some(value.to_string());
                     }
                     ValueTag::SecuritizerCik => {
-                        assert!(securitizer_cik.is_none());
-                        securitizer_cik = Some(value.clone());
+                        ctx.unique_identifier(&mut securitizer_cik, part, Cik::parse, &mut extra)?;
                     }
                     ValueTag::IssuingEntityCik => {
-                        assert!(issuing_entity_cik.is_none());
-                        issuing_entity_cik = Some(value.clone());
+                        ctx.unique_identifier(
+                            &mut issuing_entity_cik,
+                            part,
+                            Cik::parse,
+                            &mut extra,
+                        )?;
                     }
                     ValueTag::IssuingEntityName => {
-                        assert!(issuing_entity_name.is_none());
-                        issuing_entity_name = Some(value.clone());
+                        ctx.ensure_unset(&issuing_entity_name, part)?;
+                        issuing_entity_name = Some(value.to_string());
                     }
                     ValueTag::Paper => {
                         paper = true;
@@ -918,22 +1426,28 @@ impl Submission {
                         confirming_copy = true;
                     }
                     ValueTag::SecuritizerFileNumber => {
-                        securitizer_file_number = Some(value.clone());
+                        securitizer_file_number = ctx
+                            .identifier(part, FileNumber::parse, &mut extra)?
+                            .or(securitizer_file_number);
                     }
                     ValueTag::DepositorFileNumber => {
-                        depositor_file_number = Some(value.clone());
+                        depositor_file_number = ctx
+                            .identifier(part, FileNumber::parse, &mut extra)?
+                            .or(depositor_file_number);
                     }
                     ValueTag::Timestamp => {
-                        timestamp = Some(parse_date_time(value));
+                        timestamp =
+                            Some(parse_date_time(value).ok_or_else(|| ctx.bad_value(part))?);
                     }
                     ValueTag::PrivateToPublic => {
                         private_to_public = true;
                     }
                     ValueTag::PublicReferenceAcc => {
-                        public_reference_acc = Some(value.clone());
+                        public_reference_acc = Some(value.to_string());
                     }
                     ValueTag::PublicRelDate => {
-                        public_rel_date = Some(parse_date(value));
+                        public_rel_date =
+                            Some(parse_date(value).ok_or_else(|| ctx.bad_value(part))?);
                     }
                     ValueTag::Deletion => {
                         deletion = true;
@@ -942,65 +1456,75 @@ impl Submission {
                         correction = true;
                     }
                     ValueTag::Sros => {
-                        sros = Some(value.clone());
+                        sros = Some(value.to_string());
                     }
                     ValueTag::PreviousAccessionNumber => {
-                        previous_accession_number = Some(value.clone());
+                        previous_accession_number = ctx
+                            .identifier(part, AccessionNumber::parse, &mut extra)?
+                            .or(previous_accession_number);
                     }
-                    _ => panic!("Unexpected: {:?}", &part),
+                    _ => ctx.keep_unknown(part, &mut extra, &mut extra_containers)?,
                 },
-                DocumentTree::ContainerNode(tag, parts) => match tag {
+                DocumentTree::ContainerNode(tag, parts, span) => match tag {
                     ContainerTag::Filer => {
-                        let filer = Company::from_parts(parts)?;
+                        let filer = ctx.nested(tag, parts, *span, Company::from_parts)?;
                         filers.push(filer);
                     }
                     ContainerTag::Document => {
-                        let document = Document::from_parts(parts)?;
+                        let document = ctx.nested(tag, parts, *span, Document::from_parts)?;
                         documents.push(document);
                     }
                     ContainerTag::SeriesAndClassesContractsData => {
-                        assert!(series_and_classes_contracts_data.is_none());
-                        series_and_classes_contracts_data =
-                            Some(SeriesAndClassesContractsData::from_parts(parts)?);
+                        ctx.ensure_unset(&series_and_classes_contracts_data, part)?;
+                        series_and_classes_contracts_data = Some(ctx.nested(
+                            tag,
+                            parts,
+                            *span,
+                            SeriesAndClassesContractsData::from_parts,
+                        )?);
                     }
                     ContainerTag::ReportingOwner => {
-                        let reporting_owner = Company::from_parts(parts)?;
+                        let reporting_owner = ctx.nested(tag, parts, *span, Company::from_parts)?;
                         reporting_owners.push(reporting_owner);
                     }
                     ContainerTag::Issuer => {
-                        assert!(issuer.is_none());
-                        issuer = Some(Company::from_parts(parts)?);
+                        ctx.ensure_unset(&issuer, part)?;
+                        issuer = Some(ctx.nested(tag, parts, *span, Company::from_parts)?);
                     }
                     ContainerTag::SubjectCompany => {
-                        subject_company.push(Company::from_parts(parts)?);
+                        subject_company.push(ctx.nested(tag, parts, *span, Company::from_parts)?);
                     }
                     ContainerTag::FiledBy => {
-                        // Technically an n=1, but not asserted because at least one historic
-                        // filing duplicates it.
-                        filed_by = Some(Company::from_parts(parts)?);
+                        // Technically an n=1, but only warned about because at least one
+                        // historic filing duplicates it.
+                        if filed_by.is_some() {
+                            ctx.warn(WarningKind::DuplicateContainer(tag.clone()), Some(*span));
+                        }
+                        filed_by = Some(ctx.nested(tag, parts, *span, Company::from_parts)?);
                     }
                     ContainerTag::Depositor => {
-                        assert!(depositor.is_none());
-                        depositor = Some(Company::from_parts(parts)?);
+                        ctx.ensure_unset(&depositor, part)?;
+                        depositor = Some(ctx.nested(tag, parts, *span, Company::from_parts)?);
                     }
                     ContainerTag::Securitizer => {
-                        assert!(securitizer.is_none());
-                        securitizer = Some(Company::from_parts(parts)?);
+                        ctx.ensure_unset(&securitizer, part)?;
+                        securitizer = Some(ctx.nested(tag, parts, *span, Company::from_parts)?);
                     }
                     ContainerTag::FiledFor => {
-                        filed_for.push(Company::from_parts(parts)?);
+                        filed_for.push(ctx.nested(tag, parts, *span, Company::from_parts)?);
                     }
-                    _ => unimplemented!("{:?}", tag),
+                    _ => ctx.keep_unknown(part, &mut extra, &mut extra_containers)?,
                 },
-                _ => panic!("Unexpected: {:?}", &part),
+                _ => ctx.keep_unknown(part, &mut extra, &mut extra_containers)?,
             }
         }
 
         Ok(Submission {
-            accession_number: accession_number.unwrap(),
-            filing_type: filing_type.unwrap(),
+            accession_number: ctx.required_value(accession_number, ValueTag::AccessionNumber)?,
+            filing_type: ctx.required_value(filing_type, ValueTag::Type)?,
+            public_document_count,
             items,
-            filing_date: filing_date.unwrap(),
+            filing_date: ctx.required_value(filing_date, ValueTag::FilingDate)?,
             date_of_filing_date_change,
             effectiveness_date,
             filers,
@@ -1048,6 +1572,212 @@ impl Submission {
             correction,
             sros,
             previous_accession_number,
+            extra,
+            extra_containers,
         })
     }
+
+    pub fn to_parts(&self) -> Vec<DocumentTree<'_>> {
+        let mut parts = vec![
+            DocumentTree::value(ValueTag::AccessionNumber, self.accession_number.to_string()),
+            DocumentTree::value(ValueTag::Type, &self.filing_type),
+        ];
+        parts
+            .extend(self.public_document_count.map(|count| {
+                DocumentTree::value(ValueTag::PublicDocumentCount, count.to_string())
+            }));
+        parts.extend(
+            self.items
+                .iter()
+                .map(|v| DocumentTree::value(ValueTag::Items, v)),
+        );
+        parts.extend(
+            self.period
+                .map(|d| DocumentTree::value(ValueTag::Period, format_date(&d))),
+        );
+        parts.push(DocumentTree::value(
+            ValueTag::FilingDate,
+            format_date(&self.filing_date),
+        ));
+        parts.extend(
+            self.date_of_filing_date_change
+                .map(|d| DocumentTree::value(ValueTag::DateOfFilingDateChange, format_date(&d))),
+        );
+        parts.extend(
+            self.effectiveness_date
+                .map(|d| DocumentTree::value(ValueTag::EffectivenessDate, format_date(&d))),
+        );
+        parts.extend(
+            self.group_members
+                .iter()
+                .map(|v| DocumentTree::value(ValueTag::GroupMembers, v)),
+        );
+        parts.extend(DocumentTree::optional(
+            ValueTag::Reference462B,
+            &self.reference_462b,
+        ));
+        parts.extend(
+            self.is_filer_a_new_registrant
+                .map(|b| DocumentTree::value(ValueTag::IsFilerANewRegistrant, format_bool(b))),
+        );
+        parts.extend(self.is_filer_a_well_known_seasoned_issuer.map(|b| {
+            DocumentTree::value(ValueTag::IsFilerAWellKnownSeasonedIssuer, format_bool(b))
+        }));
+        parts.extend(self.filed_pursuant_to_general_instruction_a2.map(|b| {
+            DocumentTree::value(
+                ValueTag::FiledPursuantToGeneralInstructionA2,
+                format_bool(b),
+            )
+        }));
+        parts.extend(
+            self.is_fund_24f2_eligible
+                .map(|b| DocumentTree::value(ValueTag::IsFund24F2Eligible, format_bool(b))),
+        );
+        parts.extend(
+            self.action_date
+                .map(|d| DocumentTree::value(ValueTag::ActionDate, format_date(&d))),
+        );
+        parts.extend(
+            self.received_date
+                .map(|d| DocumentTree::value(ValueTag::ReceivedDate, format_date(&d))),
+        );
+        parts.extend(DocumentTree::optional(
+            ValueTag::MaIIndividual,
+            &self.ma_i_individual,
+        ));
+        parts.extend(DocumentTree::optional(ValueTag::AbsRule, &self.abs_rule));
+        parts.extend(
+            self.period_start
+                .map(|d| DocumentTree::value(ValueTag::PeriodStart, format_date(&d))),
+        );
+        parts.extend(
+            self.no_quarterly_activity
+                .map(|b| DocumentTree::value(ValueTag::NoQuarterlyActivity, format_bool(b))),
+        );
+        parts.extend(
+            self.no_annual_activity
+                .map(|b| DocumentTree::value(ValueTag::NoAnnualActivity, format_bool(b))),
+        );
+        parts.extend(DocumentTree::optional(
+            ValueTag::AbsAssetClass,
+            &self.abs_asset_class,
+        ));
+        parts.extend(
+            self.depositor_cik
+                .map(|v| DocumentTree::value(ValueTag::DepositorCik, v.to_string())),
+        );
+        parts.extend(
+            self.sponsor_cik
+                .map(|v| DocumentTree::value(ValueTag::SponsorCik, v.to_string())),
+        );
+        parts.extend(DocumentTree::optional(ValueTag::Category, &self.category));
+        parts.extend(
+            self.registered_entity
+                .map(|b| DocumentTree::value(ValueTag::RegisteredEntity, format_bool(b))),
+        );
+        parts.extend(DocumentTree::optional(
+            ValueTag::References429,
+            &self.references_429,
+        ));
+        parts.extend(
+            self.securitizer_cik
+                .map(|v| DocumentTree::value(ValueTag::SecuritizerCik, v.to_string())),
+        );
+        parts.extend(
+            self.issuing_entity_cik
+                .map(|v| DocumentTree::value(ValueTag::IssuingEntityCik, v.to_string())),
+        );
+        parts.extend(DocumentTree::optional(
+            ValueTag::IssuingEntityName,
+            &self.issuing_entity_name,
+        ));
+        parts.extend(DocumentTree::flag(ValueTag::Paper, self.paper));
+        parts.extend(DocumentTree::flag(
+            ValueTag::ConfirmingCopy,
+            self.confirming_copy,
+        ));
+        parts.extend(
+            self.securitizer_file_number
+                .as_ref()
+                .map(|n| DocumentTree::value(ValueTag::SecuritizerFileNumber, n.to_string())),
+        );
+        parts.extend(
+            self.depositor_file_number
+                .as_ref()
+                .map(|n| DocumentTree::value(ValueTag::DepositorFileNumber, n.to_string())),
+        );
+        parts.extend(
+            self.timestamp
+                .map(|t| DocumentTree::value(ValueTag::Timestamp, format_date_time(&t))),
+        );
+        parts.extend(DocumentTree::flag(
+            ValueTag::PrivateToPublic,
+            self.private_to_public,
+        ));
+        parts.extend(DocumentTree::optional(
+            ValueTag::PublicReferenceAcc,
+            &self.public_reference_acc,
+        ));
+        parts.extend(
+            self.public_rel_date
+                .map(|d| DocumentTree::value(ValueTag::PublicRelDate, format_date(&d))),
+        );
+        parts.extend(DocumentTree::flag(ValueTag::Deletion, self.deletion));
+        parts.extend(DocumentTree::flag(ValueTag::Correction, self.correction));
+        parts.extend(DocumentTree::optional(ValueTag::Sros, &self.sros));
+        parts.extend(
+            self.previous_accession_number
+                .map(|v| DocumentTree::value(ValueTag::PreviousAccessionNumber, v.to_string())),
+        );
+        parts.extend(self.series_and_classes_contracts_data.iter().map(|c| {
+            DocumentTree::container(ContainerTag::SeriesAndClassesContractsData, c.to_parts())
+        }));
+        parts.extend(
+            self.filers
+                .iter()
+                .map(|c| DocumentTree::container(ContainerTag::Filer, c.to_parts())),
+        );
+        parts.extend(
+            self.reporting_owners
+                .iter()
+                .map(|c| DocumentTree::container(ContainerTag::ReportingOwner, c.to_parts())),
+        );
+        parts.extend(
+            self.issuer
+                .iter()
+                .map(|c| DocumentTree::container(ContainerTag::Issuer, c.to_parts())),
+        );
+        parts.extend(
+            self.subject_company
+                .iter()
+                .map(|c| DocumentTree::container(ContainerTag::SubjectCompany, c.to_parts())),
+        );
+        parts.extend(
+            self.filed_by
+                .iter()
+                .map(|c| DocumentTree::container(ContainerTag::FiledBy, c.to_parts())),
+        );
+        parts.extend(
+            self.filed_for
+                .iter()
+                .map(|c| DocumentTree::container(ContainerTag::FiledFor, c.to_parts())),
+        );
+        parts.extend(
+            self.depositor
+                .iter()
+                .map(|c| DocumentTree::container(ContainerTag::Depositor, c.to_parts())),
+        );
+        parts.extend(
+            self.securitizer
+                .iter()
+                .map(|c| DocumentTree::container(ContainerTag::Securitizer, c.to_parts())),
+        );
+        parts.extend(extra_parts(&self.extra, &self.extra_containers));
+        parts.extend(
+            self.documents
+                .iter()
+                .map(|c| DocumentTree::container(ContainerTag::Document, c.to_parts())),
+        );
+        parts
+    }
 }
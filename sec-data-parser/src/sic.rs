@@ -0,0 +1,1795 @@
+use crate::schema::CompanyData;
+use crate::types::Sic;
+use serde::Serialize;
+use std::fmt::{Display, Formatter};
+
+/// An industry from the SEC's list of Standard Industrial Classification codes, with the office
+/// of the Division of Corporation Finance that reviews filings in it.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Serialize)]
+pub struct Industry {
+    pub sic: Sic,
+    /// The industry title as the SEC writes it, e.g. `SERVICES-PREPACKAGED SOFTWARE`.
+    pub title: &'static str,
+    pub office: Office,
+}
+
+/// A review office of the Division of Corporation Finance.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Serialize)]
+pub enum Office {
+    EnergyAndTransportation,
+    Finance,
+    IndustrialApplicationsAndServices,
+    LifeSciences,
+    Manufacturing,
+    RealEstateAndConstruction,
+    TradeAndServices,
+    Technology,
+    StructuredFinance,
+    International,
+}
+
+impl Office {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Office::EnergyAndTransportation => "Office of Energy & Transportation",
+            Office::Finance => "Office of Finance",
+            Office::IndustrialApplicationsAndServices => {
+                "Office of Industrial Applications and Services"
+            }
+            Office::LifeSciences => "Office of Life Sciences",
+            Office::Manufacturing => "Office of Manufacturing",
+            Office::RealEstateAndConstruction => "Office of Real Estate & Construction",
+            Office::TradeAndServices => "Office of Trade & Services",
+            Office::Technology => "Office of Technology",
+            Office::StructuredFinance => "Office of Structured Finance",
+            Office::International => "Office of International Corp Fin",
+        }
+    }
+}
+
+impl Display for Office {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl Sic {
+    /// Looks up the code in the SEC's list. Office assignments follow the list as published
+    /// when the table was written; the SEC moves industries between offices from time to time.
+    pub fn industry(&self) -> Option<&'static Industry> {
+        INDUSTRIES
+            .binary_search_by_key(self, |industry| industry.sic)
+            .ok()
+            .map(|i| &INDUSTRIES[i])
+    }
+}
+
+impl CompanyData {
+    pub fn sic(&self) -> Option<&'static Industry> {
+        self.assigned_sic?.industry()
+    }
+}
+
+const fn industry(code: u16, title: &'static str, office: Office) -> Industry {
+    Industry {
+        sic: Sic(code),
+        title,
+        office,
+    }
+}
+
+// Sorted by code, for `Sic::industry`.
+static INDUSTRIES: &[Industry] = &[
+    industry(
+        100,
+        "AGRICULTURAL PRODUCTION-CROPS",
+        Office::IndustrialApplicationsAndServices,
+    ),
+    industry(
+        200,
+        "AGRICULTURAL PROD-LIVESTOCK & ANIMAL SPECIALTIES",
+        Office::IndustrialApplicationsAndServices,
+    ),
+    industry(
+        700,
+        "AGRICULTURAL SERVICES",
+        Office::IndustrialApplicationsAndServices,
+    ),
+    industry(800, "FORESTRY", Office::IndustrialApplicationsAndServices),
+    industry(
+        900,
+        "FISHING, HUNTING AND TRAPPING",
+        Office::IndustrialApplicationsAndServices,
+    ),
+    industry(1000, "METAL MINING", Office::EnergyAndTransportation),
+    industry(
+        1040,
+        "GOLD AND SILVER ORES",
+        Office::EnergyAndTransportation,
+    ),
+    industry(
+        1090,
+        "MISCELLANEOUS METAL ORES",
+        Office::EnergyAndTransportation,
+    ),
+    industry(
+        1220,
+        "BITUMINOUS COAL & LIGNITE MINING",
+        Office::EnergyAndTransportation,
+    ),
+    industry(
+        1221,
+        "BITUMINOUS COAL & LIGNITE SURFACE MINING",
+        Office::EnergyAndTransportation,
+    ),
+    industry(
+        1311,
+        "CRUDE PETROLEUM & NATURAL GAS",
+        Office::EnergyAndTransportation,
+    ),
+    industry(
+        1381,
+        "DRILLING OIL & GAS WELLS",
+        Office::EnergyAndTransportation,
+    ),
+    industry(
+        1382,
+        "OIL & GAS FIELD EXPLORATION SERVICES",
+        Office::EnergyAndTransportation,
+    ),
+    industry(
+        1389,
+        "OIL & GAS FIELD SERVICES, NEC",
+        Office::EnergyAndTransportation,
+    ),
+    industry(
+        1400,
+        "MINING & QUARRYING OF NONMETALLIC MINERALS (NO FUELS)",
+        Office::EnergyAndTransportation,
+    ),
+    industry(
+        1520,
+        "GENERAL BLDG CONTRACTORS - RESIDENTIAL BLDGS",
+        Office::RealEstateAndConstruction,
+    ),
+    industry(
+        1531,
+        "OPERATIVE BUILDERS",
+        Office::RealEstateAndConstruction,
+    ),
+    industry(
+        1540,
+        "GENERAL BLDG CONTRACTORS - NONRESIDENTIAL BLDGS",
+        Office::RealEstateAndConstruction,
+    ),
+    industry(
+        1600,
+        "HEAVY CONSTRUCTION OTHER THAN BLDG CONST - CONTRACTORS",
+        Office::RealEstateAndConstruction,
+    ),
+    industry(
+        1623,
+        "WATER, SEWER, PIPELINE, COMM & POWER LINE CONSTRUCTION",
+        Office::RealEstateAndConstruction,
+    ),
+    industry(
+        1700,
+        "CONSTRUCTION - SPECIAL TRADE CONTRACTORS",
+        Office::RealEstateAndConstruction,
+    ),
+    industry(1731, "ELECTRICAL WORK", Office::RealEstateAndConstruction),
+    industry(
+        2000,
+        "FOOD AND KINDRED PRODUCTS",
+        Office::IndustrialApplicationsAndServices,
+    ),
+    industry(
+        2011,
+        "MEAT PACKING PLANTS",
+        Office::IndustrialApplicationsAndServices,
+    ),
+    industry(
+        2013,
+        "SAUSAGES & OTHER PREPARED MEAT PRODUCTS",
+        Office::IndustrialApplicationsAndServices,
+    ),
+    industry(
+        2015,
+        "POULTRY SLAUGHTERING AND PROCESSING",
+        Office::IndustrialApplicationsAndServices,
+    ),
+    industry(
+        2020,
+        "DAIRY PRODUCTS",
+        Office::IndustrialApplicationsAndServices,
+    ),
+    industry(
+        2024,
+        "ICE CREAM & FROZEN DESSERTS",
+        Office::IndustrialApplicationsAndServices,
+    ),
+    industry(
+        2030,
+        "CANNED, FROZEN & PRESERVD FRUIT, VEG & FOOD SPECIALTIES",
+        Office::IndustrialApplicationsAndServices,
+    ),
+    industry(
+        2033,
+        "CANNED, FRUITS, VEG, PRESERVES, JAMS & JELLIES",
+        Office::IndustrialApplicationsAndServices,
+    ),
+    industry(
+        2040,
+        "GRAIN MILL PRODUCTS",
+        Office::IndustrialApplicationsAndServices,
+    ),
+    industry(
+        2050,
+        "BAKERY PRODUCTS",
+        Office::IndustrialApplicationsAndServices,
+    ),
+    industry(
+        2052,
+        "COOKIES & CRACKERS",
+        Office::IndustrialApplicationsAndServices,
+    ),
+    industry(
+        2060,
+        "SUGAR & CONFECTIONERY PRODUCTS",
+        Office::IndustrialApplicationsAndServices,
+    ),
+    industry(
+        2070,
+        "FATS & OILS",
+        Office::IndustrialApplicationsAndServices,
+    ),
+    industry(2080, "BEVERAGES", Office::IndustrialApplicationsAndServices),
+    industry(
+        2082,
+        "MALT BEVERAGES",
+        Office::IndustrialApplicationsAndServices,
+    ),
+    industry(
+        2086,
+        "BOTTLED & CANNED SOFT DRINKS & CARBONATED WATERS",
+        Office::IndustrialApplicationsAndServices,
+    ),
+    industry(
+        2090,
+        "MISCELLANEOUS FOOD PREPARATIONS & KINDRED PRODUCTS",
+        Office::IndustrialApplicationsAndServices,
+    ),
+    industry(
+        2092,
+        "PREPARED FRESH OR FROZEN FISH & SEAFOODS",
+        Office::IndustrialApplicationsAndServices,
+    ),
+    industry(
+        2100,
+        "TOBACCO PRODUCTS",
+        Office::IndustrialApplicationsAndServices,
+    ),
+    industry(
+        2111,
+        "CIGARETTES",
+        Office::IndustrialApplicationsAndServices,
+    ),
+    industry(2200, "TEXTILE MILL PRODUCTS", Office::Manufacturing),
+    industry(
+        2211,
+        "BROADWOVEN FABRIC MILLS, COTTON",
+        Office::Manufacturing,
+    ),
+    industry(
+        2221,
+        "BROADWOVEN FABRIC MILLS, MAN MADE FIBER & SILK",
+        Office::Manufacturing,
+    ),
+    industry(2250, "KNITTING MILLS", Office::Manufacturing),
+    industry(2253, "KNIT OUTERWEAR MILLS", Office::Manufacturing),
+    industry(2273, "CARPETS & RUGS", Office::Manufacturing),
+    industry(
+        2300,
+        "APPAREL & OTHER FINISHD PRODS OF FABRICS & SIMILAR MATL",
+        Office::Manufacturing,
+    ),
+    industry(
+        2320,
+        "MEN'S & BOYS' FURNISHGS, WORK CLOTHG, & ALLIED GARMENTS",
+        Office::Manufacturing,
+    ),
+    industry(
+        2330,
+        "WOMEN'S, MISSES', AND JUNIORS OUTERWEAR",
+        Office::Manufacturing,
+    ),
+    industry(
+        2340,
+        "WOMEN'S, MISSES', CHILDREN'S & INFANTS' UNDERGARMENTS",
+        Office::Manufacturing,
+    ),
+    industry(
+        2390,
+        "MISCELLANEOUS FABRICATED TEXTILE PRODUCTS",
+        Office::Manufacturing,
+    ),
+    industry(
+        2400,
+        "LUMBER & WOOD PRODUCTS (NO FURNITURE)",
+        Office::RealEstateAndConstruction,
+    ),
+    industry(
+        2421,
+        "SAWMILLS & PLANTING MILLS, GENERAL",
+        Office::RealEstateAndConstruction,
+    ),
+    industry(
+        2430,
+        "MILLWOOD, VENEER, PLYWOOD, & STRUCTURAL WOOD MEMBERS",
+        Office::RealEstateAndConstruction,
+    ),
+    industry(2451, "MOBILE HOMES", Office::RealEstateAndConstruction),
+    industry(
+        2452,
+        "PREFABRICATED WOOD BLDGS & COMPONENTS",
+        Office::RealEstateAndConstruction,
+    ),
+    industry(2510, "HOUSEHOLD FURNITURE", Office::Manufacturing),
+    industry(
+        2511,
+        "WOOD HOUSEHOLD FURNITURE, (NO UPHOLSTERED)",
+        Office::Manufacturing,
+    ),
+    industry(2520, "OFFICE FURNITURE", Office::Manufacturing),
+    industry(2522, "OFFICE FURNITURE (NO WOOD)", Office::Manufacturing),
+    industry(
+        2531,
+        "PUBLIC BLDG & RELATED FURNITURE",
+        Office::Manufacturing,
+    ),
+    industry(
+        2540,
+        "PARTITIONS, SHELVG, LOCKERS, & OFFICE & STORE FIXTURES",
+        Office::Manufacturing,
+    ),
+    industry(
+        2590,
+        "MISCELLANEOUS FURNITURE & FIXTURES",
+        Office::Manufacturing,
+    ),
+    industry(
+        2600,
+        "PAPERS & ALLIED PRODUCTS",
+        Office::IndustrialApplicationsAndServices,
+    ),
+    industry(
+        2611,
+        "PULP MILLS",
+        Office::IndustrialApplicationsAndServices,
+    ),
+    industry(
+        2621,
+        "PAPER MILLS",
+        Office::IndustrialApplicationsAndServices,
+    ),
+    industry(
+        2631,
+        "PAPERBOARD MILLS",
+        Office::IndustrialApplicationsAndServices,
+    ),
+    industry(
+        2650,
+        "PAPERBOARD CONTAINERS & BOXES",
+        Office::IndustrialApplicationsAndServices,
+    ),
+    industry(
+        2670,
+        "CONVERTED PAPER & PAPERBOARD PRODS (NO CONTANERS/BOXES)",
+        Office::IndustrialApplicationsAndServices,
+    ),
+    industry(
+        2673,
+        "PLASTICS, FOIL & COATED PAPER BAGS",
+        Office::IndustrialApplicationsAndServices,
+    ),
+    industry(
+        2700,
+        "PRINTING, PUBLISHING & ALLIED INDUSTRIES",
+        Office::TradeAndServices,
+    ),
+    industry(
+        2711,
+        "NEWSPAPERS: PUBLISHING OR PUBLISHING & PRINTING",
+        Office::TradeAndServices,
+    ),
+    industry(
+        2721,
+        "PERIODICALS: PUBLISHING OR PUBLISHING & PRINTING",
+        Office::TradeAndServices,
+    ),
+    industry(
+        2731,
+        "BOOKS: PUBLISHING OR PUBLISHING & PRINTING",
+        Office::TradeAndServices,
+    ),
+    industry(2732, "BOOK PRINTING", Office::TradeAndServices),
+    industry(2741, "MISCELLANEOUS PUBLISHING", Office::TradeAndServices),
+    industry(2750, "COMMERCIAL PRINTING", Office::TradeAndServices),
+    industry(2761, "MANIFOLD BUSINESS FORMS", Office::TradeAndServices),
+    industry(2771, "GREETING CARDS", Office::TradeAndServices),
+    industry(
+        2780,
+        "BLANKBOOKS, LOOSELEAF BINDERS & BOOKBINDG & RELATD WORK",
+        Office::TradeAndServices,
+    ),
+    industry(
+        2790,
+        "SERVICE INDUSTRIES FOR THE PRINTING TRADE",
+        Office::TradeAndServices,
+    ),
+    industry(
+        2800,
+        "CHEMICALS & ALLIED PRODUCTS",
+        Office::IndustrialApplicationsAndServices,
+    ),
+    industry(
+        2810,
+        "INDUSTRIAL INORGANIC CHEMICALS",
+        Office::IndustrialApplicationsAndServices,
+    ),
+    industry(
+        2820,
+        "PLASTIC MATERIAL, SYNTH RESIN/RUBBER, CELLULOS (NO GLASS)",
+        Office::IndustrialApplicationsAndServices,
+    ),
+    industry(
+        2821,
+        "PLASTIC MATERIALS, SYNTH RESINS & NONVULCAN ELASTOMERS",
+        Office::IndustrialApplicationsAndServices,
+    ),
+    industry(
+        2833,
+        "MEDICINAL CHEMICALS & BOTANICAL PRODUCTS",
+        Office::LifeSciences,
+    ),
+    industry(2834, "PHARMACEUTICAL PREPARATIONS", Office::LifeSciences),
+    industry(
+        2835,
+        "IN VITRO & IN VIVO DIAGNOSTIC SUBSTANCES",
+        Office::LifeSciences,
+    ),
+    industry(
+        2836,
+        "BIOLOGICAL PRODUCTS, (NO DIAGNOSTIC SUBSTANCES)",
+        Office::LifeSciences,
+    ),
+    industry(
+        2840,
+        "SOAP, DETERGENTS, CLEANG PREPARATIONS, PERFUMES, COSMETICS",
+        Office::IndustrialApplicationsAndServices,
+    ),
+    industry(
+        2842,
+        "SPECIALTY CLEANING, POLISHING AND SANITATION PREPARATIONS",
+        Office::IndustrialApplicationsAndServices,
+    ),
+    industry(
+        2844,
+        "PERFUMES, COSMETICS & OTHER TOILET PREPARATIONS",
+        Office::IndustrialApplicationsAndServices,
+    ),
+    industry(
+        2851,
+        "PAINTS, VARNISHES, LACQUERS, ENAMELS & ALLIED PRODS",
+        Office::IndustrialApplicationsAndServices,
+    ),
+    industry(
+        2860,
+        "INDUSTRIAL ORGANIC CHEMICALS",
+        Office::IndustrialApplicationsAndServices,
+    ),
+    industry(
+        2870,
+        "AGRICULTURAL CHEMICALS",
+        Office::IndustrialApplicationsAndServices,
+    ),
+    industry(
+        2890,
+        "MISCELLANEOUS CHEMICAL PRODUCTS",
+        Office::IndustrialApplicationsAndServices,
+    ),
+    industry(
+        2891,
+        "ADHESIVES & SEALANTS",
+        Office::IndustrialApplicationsAndServices,
+    ),
+    industry(2911, "PETROLEUM REFINING", Office::EnergyAndTransportation),
+    industry(
+        2950,
+        "ASPHALT PAVING & ROOFING MATERIALS",
+        Office::EnergyAndTransportation,
+    ),
+    industry(
+        2990,
+        "MISCELLANEOUS PRODUCTS OF PETROLEUM & COAL",
+        Office::EnergyAndTransportation,
+    ),
+    industry(3011, "TIRES & INNER TUBES", Office::Manufacturing),
+    industry(3020, "RUBBER & PLASTICS FOOTWEAR", Office::Manufacturing),
+    industry(
+        3050,
+        "GASKETS, PACKG & SEALG DEVICES & RUBBER & PLASTICS HOSE",
+        Office::Manufacturing,
+    ),
+    industry(
+        3060,
+        "FABRICATED RUBBER PRODUCTS, NEC",
+        Office::Manufacturing,
+    ),
+    industry(
+        3080,
+        "MISCELLANEOUS PLASTICS PRODUCTS",
+        Office::Manufacturing,
+    ),
+    industry(
+        3081,
+        "UNSUPPORTED PLASTICS FILM & SHEET",
+        Office::Manufacturing,
+    ),
+    industry(3086, "PLASTICS FOAM PRODUCTS", Office::Manufacturing),
+    industry(3089, "PLASTICS PRODUCTS, NEC", Office::Manufacturing),
+    industry(3100, "LEATHER & LEATHER PRODUCTS", Office::Manufacturing),
+    industry(3140, "FOOTWEAR, (NO RUBBER)", Office::Manufacturing),
+    industry(3211, "FLAT GLASS", Office::Manufacturing),
+    industry(
+        3220,
+        "GLASS & GLASSWARE, PRESSED OR BLOWN",
+        Office::Manufacturing,
+    ),
+    industry(3221, "GLASS CONTAINERS", Office::Manufacturing),
+    industry(
+        3231,
+        "GLASS PRODUCTS, MADE OF PURCHASED GLASS",
+        Office::Manufacturing,
+    ),
+    industry(3241, "CEMENT, HYDRAULIC", Office::Manufacturing),
+    industry(3250, "STRUCTURAL CLAY PRODUCTS", Office::Manufacturing),
+    industry(3260, "POTTERY & RELATED PRODUCTS", Office::Manufacturing),
+    industry(
+        3270,
+        "CONCRETE, GYPSUM & PLASTER PRODUCTS",
+        Office::Manufacturing,
+    ),
+    industry(
+        3272,
+        "CONCRETE PRODUCTS, EXCEPT BLOCK & BRICK",
+        Office::Manufacturing,
+    ),
+    industry(3281, "CUT STONE & STONE PRODUCTS", Office::Manufacturing),
+    industry(
+        3290,
+        "ABRASIVE, ASBESTOS & MISC NONMETALLIC MINERAL PRODS",
+        Office::Manufacturing,
+    ),
+    industry(
+        3310,
+        "STEEL WORKS, BLAST FURNACES & ROLLING & FINISHING MILLS",
+        Office::Manufacturing,
+    ),
+    industry(
+        3312,
+        "STEEL WORKS, BLAST FURNACES & ROLLING MILLS (COKE OVENS)",
+        Office::Manufacturing,
+    ),
+    industry(3317, "STEEL PIPE & TUBES", Office::Manufacturing),
+    industry(3320, "IRON & STEEL FOUNDRIES", Office::Manufacturing),
+    industry(
+        3330,
+        "PRIMARY SMELTING & REFINING OF NONFERROUS METALS",
+        Office::Manufacturing,
+    ),
+    industry(
+        3334,
+        "PRIMARY PRODUCTION OF ALUMINUM",
+        Office::Manufacturing,
+    ),
+    industry(
+        3341,
+        "SECONDARY SMELTING & REFINING OF NONFERROUS METALS",
+        Office::Manufacturing,
+    ),
+    industry(
+        3350,
+        "ROLLING DRAWING & EXTRUDING OF NONFERROUS METALS",
+        Office::Manufacturing,
+    ),
+    industry(
+        3357,
+        "DRAWING & INSULATING OF NONFERROUS WIRE",
+        Office::Manufacturing,
+    ),
+    industry(
+        3360,
+        "NONFERROUS FOUNDRIES (CASTINGS)",
+        Office::Manufacturing,
+    ),
+    industry(
+        3390,
+        "MISCELLANEOUS PRIMARY METAL PRODUCTS",
+        Office::Manufacturing,
+    ),
+    industry(3411, "METAL CANS", Office::Manufacturing),
+    industry(
+        3412,
+        "METAL SHIPPING BARRELS, DRUMS, KEGS & PAILS",
+        Office::Manufacturing,
+    ),
+    industry(
+        3420,
+        "CUTLERY, HANDTOOLS & GENERAL HARDWARE",
+        Office::Manufacturing,
+    ),
+    industry(
+        3430,
+        "HEATING EQUIP, EXCEPT ELEC & WARM AIR; & PLUMBING FIXTURES",
+        Office::Manufacturing,
+    ),
+    industry(
+        3433,
+        "HEATING EQUIPMENT, EXCEPT ELECTRIC & WARM AIR FURNACES",
+        Office::Manufacturing,
+    ),
+    industry(
+        3440,
+        "FABRICATED STRUCTURAL METAL PRODUCTS",
+        Office::Manufacturing,
+    ),
+    industry(
+        3442,
+        "METAL DOORS, SASH, FRAMES, MOLDINGS & TRIM",
+        Office::Manufacturing,
+    ),
+    industry(
+        3443,
+        "FABRICATED PLATE WORK (BOILER SHOPS)",
+        Office::Manufacturing,
+    ),
+    industry(3444, "SHEET METAL WORK", Office::Manufacturing),
+    industry(
+        3448,
+        "PREFABRICATED METAL BUILDINGS & COMPONENTS",
+        Office::Manufacturing,
+    ),
+    industry(3451, "SCREW MACHINE PRODUCTS", Office::Manufacturing),
+    industry(
+        3452,
+        "BOLTS, NUTS, SCREWS, RIVETS & WASHERS",
+        Office::Manufacturing,
+    ),
+    industry(3460, "METAL FORGINGS & STAMPINGS", Office::Manufacturing),
+    industry(
+        3470,
+        "COATING, ENGRAVING & ALLIED SERVICES",
+        Office::Manufacturing,
+    ),
+    industry(
+        3480,
+        "ORDNANCE & ACCESSORIES, (NO VEHICLES/GUIDED MISSILES)",
+        Office::Manufacturing,
+    ),
+    industry(
+        3490,
+        "MISCELLANEOUS FABRICATED METAL PRODUCTS",
+        Office::Manufacturing,
+    ),
+    industry(3510, "ENGINES & TURBINES", Office::Manufacturing),
+    industry(3523, "FARM MACHINERY & EQUIPMENT", Office::Manufacturing),
+    industry(
+        3524,
+        "LAWN & GARDEN TRACTORS & HOME LAWN & GARDENS EQUIP",
+        Office::Manufacturing,
+    ),
+    industry(
+        3530,
+        "CONSTRUCTION, MINING & MATERIALS HANDLING MACHINERY & EQUIP",
+        Office::Manufacturing,
+    ),
+    industry(
+        3531,
+        "CONSTRUCTION MACHINERY & EQUIP",
+        Office::Manufacturing,
+    ),
+    industry(
+        3532,
+        "MINING MACHINERY & EQUIP (NO OIL & GAS FIELD MACH & EQUIP)",
+        Office::Manufacturing,
+    ),
+    industry(
+        3533,
+        "OIL & GAS FIELD MACHINERY & EQUIPMENT",
+        Office::Manufacturing,
+    ),
+    industry(
+        3537,
+        "INDUSTRIAL TRUCKS, TRACTORS, TRAILORS & STACKERS",
+        Office::Manufacturing,
+    ),
+    industry(
+        3540,
+        "METALWORKG MACHINERY & EQUIPMENT",
+        Office::Manufacturing,
+    ),
+    industry(
+        3541,
+        "MACHINE TOOLS, METAL CUTTING TYPES",
+        Office::Manufacturing,
+    ),
+    industry(
+        3550,
+        "SPECIAL INDUSTRY MACHINERY (NO METALWORKING MACHINERY)",
+        Office::Manufacturing,
+    ),
+    industry(
+        3555,
+        "PRINTING TRADES MACHINERY & EQUIPMENT",
+        Office::Manufacturing,
+    ),
+    industry(
+        3559,
+        "SPECIAL INDUSTRY MACHINERY, NEC",
+        Office::Manufacturing,
+    ),
+    industry(
+        3560,
+        "GENERAL INDUSTRIAL MACHINERY & EQUIPMENT",
+        Office::Manufacturing,
+    ),
+    industry(3561, "PUMPS & PUMPING EQUIPMENT", Office::Manufacturing),
+    industry(3562, "BALL & ROLLER BEARINGS", Office::Manufacturing),
+    industry(
+        3564,
+        "INDUSTRIAL & COMMERCIAL FANS & BLOWERS & AIR PURIFING EQUIP",
+        Office::Manufacturing,
+    ),
+    industry(
+        3567,
+        "INDUSTRIAL PROCESS FURNACES & OVENS",
+        Office::Manufacturing,
+    ),
+    industry(
+        3569,
+        "GENERAL INDUSTRIAL MACHINERY & EQUIPMENT, NEC",
+        Office::Manufacturing,
+    ),
+    industry(3570, "COMPUTER & OFFICE EQUIPMENT", Office::Technology),
+    industry(3571, "ELECTRONIC COMPUTERS", Office::Technology),
+    industry(3572, "COMPUTER STORAGE DEVICES", Office::Technology),
+    industry(3575, "COMPUTER TERMINALS", Office::Technology),
+    industry(
+        3576,
+        "COMPUTER COMMUNICATIONS EQUIPMENT",
+        Office::Technology,
+    ),
+    industry(
+        3577,
+        "COMPUTER PERIPHERAL EQUIPMENT, NEC",
+        Office::Technology,
+    ),
+    industry(
+        3578,
+        "CALCULATING & ACCOUNTING MACHINES (NO ELECTRONIC COMPUTERS)",
+        Office::Technology,
+    ),
+    industry(3579, "OFFICE MACHINES, NEC", Office::Technology),
+    industry(
+        3580,
+        "REFRIGERATION & SERVICE INDUSTRY MACHINERY",
+        Office::Manufacturing,
+    ),
+    industry(
+        3585,
+        "AIR-COND & WARM AIR HEATG EQUIP & COMM & INDL REFRIG EQUIP",
+        Office::Manufacturing,
+    ),
+    industry(
+        3590,
+        "MISC INDUSTRIAL & COMMERCIAL MACHINERY & EQUIPMENT",
+        Office::Manufacturing,
+    ),
+    industry(
+        3600,
+        "ELECTRONIC & OTHER ELECTRICAL EQUIPMENT (NO COMPUTER EQUIP)",
+        Office::Manufacturing,
+    ),
+    industry(
+        3612,
+        "POWER, DISTRIBUTION & SPECIALTY TRANSFORMERS",
+        Office::Manufacturing,
+    ),
+    industry(
+        3613,
+        "SWITCHGEAR & SWITCHBOARD APPARATUS",
+        Office::Manufacturing,
+    ),
+    industry(
+        3620,
+        "ELECTRICAL INDUSTRIAL APPARATUS",
+        Office::Manufacturing,
+    ),
+    industry(3621, "MOTORS & GENERATORS", Office::Manufacturing),
+    industry(3630, "HOUSEHOLD APPLIANCES", Office::Manufacturing),
+    industry(3634, "ELECTRIC HOUSEWARES & FANS", Office::Manufacturing),
+    industry(
+        3640,
+        "ELECTRIC LIGHTING & WIRING EQUIPMENT",
+        Office::Manufacturing,
+    ),
+    industry(
+        3651,
+        "HOUSEHOLD AUDIO & VIDEO EQUIPMENT",
+        Office::Manufacturing,
+    ),
+    industry(
+        3652,
+        "PHONOGRAPH RECORDS & PRERECORDED AUDIO TAPES & DISKS",
+        Office::Manufacturing,
+    ),
+    industry(3661, "TELEPHONE & TELEGRAPH APPARATUS", Office::Technology),
+    industry(
+        3663,
+        "RADIO & TV BROADCASTING & COMMUNICATIONS EQUIPMENT",
+        Office::Technology,
+    ),
+    industry(3669, "COMMUNICATIONS EQUIPMENT, NEC", Office::Technology),
+    industry(
+        3670,
+        "ELECTRONIC COMPONENTS & ACCESSORIES",
+        Office::Technology,
+    ),
+    industry(3672, "PRINTED CIRCUIT BOARDS", Office::Technology),
+    industry(3674, "SEMICONDUCTORS & RELATED DEVICES", Office::Technology),
+    industry(
+        3677,
+        "ELECTRONIC COILS, TRANSFORMERS & OTHER INDUCTORS",
+        Office::Technology,
+    ),
+    industry(3678, "ELECTRONIC CONNECTORS", Office::Technology),
+    industry(3679, "ELECTRONIC COMPONENTS, NEC", Office::Technology),
+    industry(
+        3690,
+        "MISCELLANEOUS ELECTRICAL MACHINERY, EQUIPMENT & SUPPLIES",
+        Office::Manufacturing,
+    ),
+    industry(
+        3695,
+        "MAGNETIC & OPTICAL RECORDING MEDIA",
+        Office::Technology,
+    ),
+    industry(
+        3711,
+        "MOTOR VEHICLES & PASSENGER CAR BODIES",
+        Office::Manufacturing,
+    ),
+    industry(3713, "TRUCK & BUS BODIES", Office::Manufacturing),
+    industry(
+        3714,
+        "MOTOR VEHICLE PARTS & ACCESSORIES",
+        Office::Manufacturing,
+    ),
+    industry(3715, "TRUCK TRAILERS", Office::Manufacturing),
+    industry(3716, "MOTOR HOMES", Office::Manufacturing),
+    industry(3720, "AIRCRAFT & PARTS", Office::Manufacturing),
+    industry(3721, "AIRCRAFT", Office::Manufacturing),
+    industry(
+        3724,
+        "AIRCRAFT ENGINES & ENGINE PARTS",
+        Office::Manufacturing,
+    ),
+    industry(
+        3728,
+        "AIRCRAFT PARTS & AUXILIARY EQUIPMENT, NEC",
+        Office::Manufacturing,
+    ),
+    industry(
+        3730,
+        "SHIP & BOAT BUILDING & REPAIRING",
+        Office::Manufacturing,
+    ),
+    industry(3743, "RAILROAD EQUIPMENT", Office::Manufacturing),
+    industry(3751, "MOTORCYCLES, BICYCLES & PARTS", Office::Manufacturing),
+    industry(
+        3760,
+        "GUIDED MISSILES & SPACE VEHICLES & PARTS",
+        Office::Manufacturing,
+    ),
+    industry(
+        3790,
+        "MISCELLANEOUS TRANSPORTATION EQUIPMENT",
+        Office::Manufacturing,
+    ),
+    industry(
+        3812,
+        "SEARCH, DETECTION, NAVIGATION, GUIDANCE, AERONAUTICAL SYS",
+        Office::Manufacturing,
+    ),
+    industry(
+        3821,
+        "LABORATORY APPARATUS & FURNITURE",
+        Office::LifeSciences,
+    ),
+    industry(
+        3822,
+        "AUTO CONTROLS FOR REGULATING RESIDENTIAL & COMML ENVIRONMENTS",
+        Office::Manufacturing,
+    ),
+    industry(
+        3823,
+        "INDUSTRIAL INSTRUMENTS FOR MEASUREMENT, DISPLAY, AND CONTROL",
+        Office::Manufacturing,
+    ),
+    industry(
+        3824,
+        "TOTALIZING FLUID METERS & COUNTING DEVICES",
+        Office::Manufacturing,
+    ),
+    industry(
+        3825,
+        "INSTRUMENTS FOR MEAS & TESTING OF ELECTRICITY & ELEC SIGNALS",
+        Office::Manufacturing,
+    ),
+    industry(
+        3826,
+        "LABORATORY ANALYTICAL INSTRUMENTS",
+        Office::LifeSciences,
+    ),
+    industry(3827, "OPTICAL INSTRUMENTS & LENSES", Office::Manufacturing),
+    industry(
+        3829,
+        "MEASURING & CONTROLLING DEVICES, NEC",
+        Office::Manufacturing,
+    ),
+    industry(
+        3841,
+        "SURGICAL & MEDICAL INSTRUMENTS & APPARATUS",
+        Office::LifeSciences,
+    ),
+    industry(
+        3842,
+        "ORTHOPEDIC, PROSTHETIC & SURGICAL APPLIANCES & SUPPLIES",
+        Office::LifeSciences,
+    ),
+    industry(3843, "DENTAL EQUIPMENT & SUPPLIES", Office::LifeSciences),
+    industry(
+        3844,
+        "X-RAY APPARATUS & TUBES & RELATED IRRADIATION APPARATUS",
+        Office::LifeSciences,
+    ),
+    industry(
+        3845,
+        "ELECTROMEDICAL & ELECTROTHERAPEUTIC APPARATUS",
+        Office::LifeSciences,
+    ),
+    industry(3851, "OPHTHALMIC GOODS", Office::LifeSciences),
+    industry(
+        3861,
+        "PHOTOGRAPHIC EQUIPMENT & SUPPLIES",
+        Office::Manufacturing,
+    ),
+    industry(
+        3873,
+        "WATCHES, CLOCKS, CLOCKWORK OPERATED DEVICES/PARTS",
+        Office::Manufacturing,
+    ),
+    industry(
+        3910,
+        "JEWELRY, SILVERWARE & PLATED WARE",
+        Office::Manufacturing,
+    ),
+    industry(3911, "JEWELRY, PRECIOUS METAL", Office::Manufacturing),
+    industry(3940, "DOLLS & STUFFED TOYS", Office::Manufacturing),
+    industry(
+        3944,
+        "GAMES, TOYS & CHILDREN'S VEHICLES (NO DOLLS & BICYCLES)",
+        Office::Manufacturing,
+    ),
+    industry(
+        3949,
+        "SPORTING & ATHLETIC GOODS, NEC",
+        Office::Manufacturing,
+    ),
+    industry(
+        3950,
+        "PENS, PENCILS & OTHER ARTISTS' MATERIALS",
+        Office::Manufacturing,
+    ),
+    industry(3960, "COSTUME JEWELRY & NOVELTIES", Office::Manufacturing),
+    industry(
+        3990,
+        "MISCELLANEOUS MANUFACTURING INDUSTRIES",
+        Office::Manufacturing,
+    ),
+    industry(
+        4011,
+        "RAILROADS, LINE-HAUL OPERATING",
+        Office::EnergyAndTransportation,
+    ),
+    industry(
+        4013,
+        "RAILROAD SWITCHING & TERMINAL ESTABLISHMENTS",
+        Office::EnergyAndTransportation,
+    ),
+    industry(
+        4100,
+        "LOCAL & SUBURBAN TRANSIT & INTERURBAN HWY PASSENGER TRANS",
+        Office::EnergyAndTransportation,
+    ),
+    industry(
+        4210,
+        "TRUCKING & COURIER SERVICES (NO AIR)",
+        Office::EnergyAndTransportation,
+    ),
+    industry(4213, "TRUCKING (NO LOCAL)", Office::EnergyAndTransportation),
+    industry(
+        4220,
+        "PUBLIC WAREHOUSING & STORAGE",
+        Office::RealEstateAndConstruction,
+    ),
+    industry(
+        4231,
+        "TERMINAL MAINTENANCE FACILITIES FOR MOTOR FREIGHT TRANSPORT",
+        Office::EnergyAndTransportation,
+    ),
+    industry(
+        4400,
+        "WATER TRANSPORTATION",
+        Office::EnergyAndTransportation,
+    ),
+    industry(
+        4412,
+        "DEEP SEA FOREIGN TRANSPORTATION OF FREIGHT",
+        Office::EnergyAndTransportation,
+    ),
+    industry(
+        4512,
+        "AIR TRANSPORTATION, SCHEDULED",
+        Office::EnergyAndTransportation,
+    ),
+    industry(
+        4513,
+        "AIR COURIER SERVICES",
+        Office::EnergyAndTransportation,
+    ),
+    industry(
+        4522,
+        "AIR TRANSPORTATION, NONSCHEDULED",
+        Office::EnergyAndTransportation,
+    ),
+    industry(
+        4581,
+        "AIRPORTS, FLYING FIELDS & AIRPORT TERMINAL SERVICES",
+        Office::EnergyAndTransportation,
+    ),
+    industry(
+        4610,
+        "PIPE LINES (NO NATURAL GAS)",
+        Office::EnergyAndTransportation,
+    ),
+    industry(
+        4700,
+        "TRANSPORTATION SERVICES",
+        Office::EnergyAndTransportation,
+    ),
+    industry(
+        4731,
+        "ARRANGEMENT OF TRANSPORTATION OF FREIGHT & CARGO",
+        Office::EnergyAndTransportation,
+    ),
+    industry(4812, "RADIOTELEPHONE COMMUNICATIONS", Office::Technology),
+    industry(
+        4813,
+        "TELEPHONE COMMUNICATIONS (NO RADIOTELEPHONE)",
+        Office::Technology,
+    ),
+    industry(
+        4822,
+        "TELEGRAPH & OTHER MESSAGE COMMUNICATIONS",
+        Office::Technology,
+    ),
+    industry(4832, "RADIO BROADCASTING STATIONS", Office::Technology),
+    industry(4833, "TELEVISION BROADCASTING STATIONS", Office::Technology),
+    industry(
+        4841,
+        "CABLE & OTHER PAY TELEVISION SERVICES",
+        Office::Technology,
+    ),
+    industry(4899, "COMMUNICATIONS SERVICES, NEC", Office::Technology),
+    industry(
+        4900,
+        "ELECTRIC, GAS & SANITARY SERVICES",
+        Office::EnergyAndTransportation,
+    ),
+    industry(4911, "ELECTRIC SERVICES", Office::EnergyAndTransportation),
+    industry(
+        4922,
+        "NATURAL GAS TRANSMISSION",
+        Office::EnergyAndTransportation,
+    ),
+    industry(
+        4923,
+        "NATURAL GAS TRANSMISSION & DISTRIBUTION",
+        Office::EnergyAndTransportation,
+    ),
+    industry(
+        4924,
+        "NATURAL GAS DISTRIBUTION",
+        Office::EnergyAndTransportation,
+    ),
+    industry(
+        4931,
+        "ELECTRIC & OTHER SERVICES COMBINED",
+        Office::EnergyAndTransportation,
+    ),
+    industry(
+        4932,
+        "GAS & OTHER SERVICES COMBINED",
+        Office::EnergyAndTransportation,
+    ),
+    industry(4940, "WATER SUPPLY", Office::EnergyAndTransportation),
+    industry(4950, "SANITARY SERVICES", Office::EnergyAndTransportation),
+    industry(4953, "REFUSE SYSTEMS", Office::EnergyAndTransportation),
+    industry(
+        4955,
+        "HAZARDOUS WASTE MANAGEMENT",
+        Office::EnergyAndTransportation,
+    ),
+    industry(
+        4961,
+        "STEAM & AIR-CONDITIONING SUPPLY",
+        Office::EnergyAndTransportation,
+    ),
+    industry(
+        4991,
+        "COGENERATION SERVICES & SMALL POWER PRODUCERS",
+        Office::EnergyAndTransportation,
+    ),
+    industry(5000, "WHOLESALE-DURABLE GOODS", Office::TradeAndServices),
+    industry(
+        5010,
+        "WHOLESALE-MOTOR VEHICLES & MOTOR VEHICLE PARTS & SUPPLIES",
+        Office::TradeAndServices,
+    ),
+    industry(
+        5013,
+        "WHOLESALE-MOTOR VEHICLE SUPPLIES & NEW PARTS",
+        Office::TradeAndServices,
+    ),
+    industry(
+        5020,
+        "WHOLESALE-FURNITURE & HOME FURNISHINGS",
+        Office::TradeAndServices,
+    ),
+    industry(
+        5030,
+        "WHOLESALE-LUMBER & OTHER CONSTRUCTION MATERIALS",
+        Office::TradeAndServices,
+    ),
+    industry(
+        5031,
+        "WHOLESALE-LUMBER, PLYWOOD, MILLWORK & WOOD PANELS",
+        Office::TradeAndServices,
+    ),
+    industry(
+        5040,
+        "WHOLESALE-PROFESSIONAL & COMMERCIAL EQUIPMENT & SUPPLIES",
+        Office::TradeAndServices,
+    ),
+    industry(
+        5045,
+        "WHOLESALE-COMPUTERS & PERIPHERAL EQUIPMENT & SOFTWARE",
+        Office::TradeAndServices,
+    ),
+    industry(
+        5047,
+        "WHOLESALE-MEDICAL, DENTAL & HOSPITAL EQUIPMENT & SUPPLIES",
+        Office::TradeAndServices,
+    ),
+    industry(
+        5050,
+        "WHOLESALE-METALS SERVICE CENTERS & OFFICES",
+        Office::TradeAndServices,
+    ),
+    industry(
+        5051,
+        "WHOLESALE-METALS SERVICE CENTERS & OFFICES",
+        Office::TradeAndServices,
+    ),
+    industry(
+        5063,
+        "WHOLESALE-ELECTRICAL APPARATUS & EQUIPMENT, WIRING SUPPLIES",
+        Office::TradeAndServices,
+    ),
+    industry(
+        5064,
+        "WHOLESALE-ELECTRICAL APPLIANCES, TV & RADIO SETS",
+        Office::TradeAndServices,
+    ),
+    industry(
+        5065,
+        "WHOLESALE-ELECTRONIC PARTS & EQUIPMENT, NEC",
+        Office::TradeAndServices,
+    ),
+    industry(
+        5070,
+        "WHOLESALE-HARDWARE & PLUMBING & HEATING EQUIPMENT & SUPPLIES",
+        Office::TradeAndServices,
+    ),
+    industry(5072, "WHOLESALE-HARDWARE", Office::TradeAndServices),
+    industry(
+        5080,
+        "WHOLESALE-MACHINERY, EQUIPMENT & SUPPLIES",
+        Office::TradeAndServices,
+    ),
+    industry(
+        5082,
+        "WHOLESALE-CONSTRUCTION & MINING (NO PETRO) MACHINERY & EQUIP",
+        Office::TradeAndServices,
+    ),
+    industry(
+        5084,
+        "WHOLESALE-INDUSTRIAL MACHINERY & EQUIPMENT",
+        Office::TradeAndServices,
+    ),
+    industry(
+        5090,
+        "WHOLESALE-MISC DURABLE GOODS",
+        Office::TradeAndServices,
+    ),
+    industry(
+        5094,
+        "WHOLESALE-JEWELRY, WATCHES, PRECIOUS STONES & METALS",
+        Office::TradeAndServices,
+    ),
+    industry(
+        5099,
+        "WHOLESALE-DURABLE GOODS, NEC",
+        Office::TradeAndServices,
+    ),
+    industry(
+        5110,
+        "WHOLESALE-PAPER AND PAPER PRODUCTS",
+        Office::TradeAndServices,
+    ),
+    industry(
+        5122,
+        "WHOLESALE-DRUGS PROPRIETARIES & DRUGGISTS' SUNDRIES",
+        Office::TradeAndServices,
+    ),
+    industry(
+        5130,
+        "WHOLESALE-APPAREL, PIECE GOODS & NOTIONS",
+        Office::TradeAndServices,
+    ),
+    industry(
+        5140,
+        "WHOLESALE-GROCERIES & RELATED PRODUCTS",
+        Office::TradeAndServices,
+    ),
+    industry(
+        5141,
+        "WHOLESALE-GROCERIES, GENERAL LINE",
+        Office::TradeAndServices,
+    ),
+    industry(
+        5150,
+        "WHOLESALE-FARM PRODUCT RAW MATERIALS",
+        Office::TradeAndServices,
+    ),
+    industry(
+        5160,
+        "WHOLESALE-CHEMICALS & ALLIED PRODUCTS",
+        Office::TradeAndServices,
+    ),
+    industry(
+        5171,
+        "WHOLESALE-PETROLEUM BULK STATIONS & TERMINALS",
+        Office::TradeAndServices,
+    ),
+    industry(
+        5172,
+        "WHOLESALE-PETROLEUM & PETROLEUM PRODUCTS (NO BULK STATIONS)",
+        Office::TradeAndServices,
+    ),
+    industry(
+        5180,
+        "WHOLESALE-BEER, WINE & DISTILLED ALCOHOLIC BEVERAGES",
+        Office::TradeAndServices,
+    ),
+    industry(
+        5190,
+        "WHOLESALE-MISCELLANEOUS NONDURABLE GOODS",
+        Office::TradeAndServices,
+    ),
+    industry(
+        5200,
+        "RETAIL-BUILDING MATERIALS, HARDWARE, GARDEN SUPPLY",
+        Office::TradeAndServices,
+    ),
+    industry(
+        5211,
+        "RETAIL-LUMBER & OTHER BUILDING MATERIALS DEALERS",
+        Office::TradeAndServices,
+    ),
+    industry(5271, "RETAIL-MOBILE HOME DEALERS", Office::TradeAndServices),
+    industry(5311, "RETAIL-DEPARTMENT STORES", Office::TradeAndServices),
+    industry(5331, "RETAIL-VARIETY STORES", Office::TradeAndServices),
+    industry(
+        5399,
+        "RETAIL-MISC GENERAL MERCHANDISE STORES",
+        Office::TradeAndServices,
+    ),
+    industry(5400, "RETAIL-FOOD STORES", Office::TradeAndServices),
+    industry(5411, "RETAIL-GROCERY STORES", Office::TradeAndServices),
+    industry(5412, "RETAIL-CONVENIENCE STORES", Office::TradeAndServices),
+    industry(
+        5500,
+        "RETAIL-AUTO DEALERS & GASOLINE STATIONS",
+        Office::TradeAndServices,
+    ),
+    industry(
+        5531,
+        "RETAIL-AUTO & HOME SUPPLY STORES",
+        Office::TradeAndServices,
+    ),
+    industry(
+        5600,
+        "RETAIL-APPAREL & ACCESSORY STORES",
+        Office::TradeAndServices,
+    ),
+    industry(
+        5621,
+        "RETAIL-WOMEN'S CLOTHING STORES",
+        Office::TradeAndServices,
+    ),
+    industry(
+        5651,
+        "RETAIL-FAMILY CLOTHING STORES",
+        Office::TradeAndServices,
+    ),
+    industry(5661, "RETAIL-SHOE STORES", Office::TradeAndServices),
+    industry(
+        5700,
+        "RETAIL-HOME FURNITURE, FURNISHINGS & EQUIPMENT STORES",
+        Office::TradeAndServices,
+    ),
+    industry(5712, "RETAIL-FURNITURE STORES", Office::TradeAndServices),
+    industry(
+        5731,
+        "RETAIL-RADIO, TV & CONSUMER ELECTRONICS STORES",
+        Office::TradeAndServices,
+    ),
+    industry(
+        5734,
+        "RETAIL-COMPUTER & COMPUTER SOFTWARE STORES",
+        Office::TradeAndServices,
+    ),
+    industry(
+        5735,
+        "RETAIL-RECORD & PRERECORDED TAPE STORES",
+        Office::TradeAndServices,
+    ),
+    industry(
+        5810,
+        "RETAIL-EATING & DRINKING PLACES",
+        Office::TradeAndServices,
+    ),
+    industry(5812, "RETAIL-EATING PLACES", Office::TradeAndServices),
+    industry(
+        5900,
+        "RETAIL-MISCELLANEOUS RETAIL",
+        Office::TradeAndServices,
+    ),
+    industry(
+        5912,
+        "RETAIL-DRUG STORES AND PROPRIETARY STORES",
+        Office::TradeAndServices,
+    ),
+    industry(
+        5940,
+        "RETAIL-MISCELLANEOUS SHOPPING GOODS STORES",
+        Office::TradeAndServices,
+    ),
+    industry(5944, "RETAIL-JEWELRY STORES", Office::TradeAndServices),
+    industry(
+        5945,
+        "RETAIL-HOBBY, TOY & GAME SHOPS",
+        Office::TradeAndServices,
+    ),
+    industry(5960, "RETAIL-NONSTORE RETAILERS", Office::TradeAndServices),
+    industry(
+        5961,
+        "RETAIL-CATALOG & MAIL-ORDER HOUSES",
+        Office::TradeAndServices,
+    ),
+    industry(5990, "RETAIL-RETAIL STORES, NEC", Office::TradeAndServices),
+    industry(6021, "NATIONAL COMMERCIAL BANKS", Office::Finance),
+    industry(6022, "STATE COMMERCIAL BANKS", Office::Finance),
+    industry(6029, "COMMERCIAL BANKS, NEC", Office::Finance),
+    industry(
+        6035,
+        "SAVINGS INSTITUTION, FEDERALLY CHARTERED",
+        Office::Finance,
+    ),
+    industry(
+        6036,
+        "SAVINGS INSTITUTIONS, NOT FEDERALLY CHARTERED",
+        Office::Finance,
+    ),
+    industry(
+        6099,
+        "FUNCTIONS RELATED TO DEPOSITORY BANKING, NEC",
+        Office::Finance,
+    ),
+    industry(
+        6111,
+        "FEDERAL & FEDERALLY-SPONSORED CREDIT AGENCIES",
+        Office::Finance,
+    ),
+    industry(6141, "PERSONAL CREDIT INSTITUTIONS", Office::Finance),
+    industry(
+        6153,
+        "SHORT-TERM BUSINESS CREDIT INSTITUTIONS",
+        Office::Finance,
+    ),
+    industry(
+        6159,
+        "MISCELLANEOUS BUSINESS CREDIT INSTITUTION",
+        Office::Finance,
+    ),
+    industry(
+        6162,
+        "MORTGAGE BANKERS & LOAN CORRESPONDENTS",
+        Office::Finance,
+    ),
+    industry(6163, "LOAN BROKERS", Office::Finance),
+    industry(6172, "FINANCE LESSORS", Office::Finance),
+    industry(6189, "ASSET-BACKED SECURITIES", Office::StructuredFinance),
+    industry(6199, "FINANCE SERVICES", Office::Finance),
+    industry(
+        6200,
+        "SECURITY & COMMODITY BROKERS, DEALERS, EXCHANGES & SERVICES",
+        Office::Finance,
+    ),
+    industry(
+        6211,
+        "SECURITY BROKERS, DEALERS & FLOTATION COMPANIES",
+        Office::Finance,
+    ),
+    industry(
+        6221,
+        "COMMODITY CONTRACTS BROKERS & DEALERS",
+        Office::Finance,
+    ),
+    industry(6282, "INVESTMENT ADVICE", Office::Finance),
+    industry(6311, "LIFE INSURANCE", Office::Finance),
+    industry(6321, "ACCIDENT & HEALTH INSURANCE", Office::Finance),
+    industry(6324, "HOSPITAL & MEDICAL SERVICE PLANS", Office::Finance),
+    industry(6331, "FIRE, MARINE & CASUALTY INSURANCE", Office::Finance),
+    industry(6351, "SURETY INSURANCE", Office::Finance),
+    industry(6361, "TITLE INSURANCE", Office::Finance),
+    industry(6399, "INSURANCE CARRIERS, NEC", Office::Finance),
+    industry(6411, "INSURANCE AGENTS, BROKERS & SERVICE", Office::Finance),
+    industry(6500, "REAL ESTATE", Office::RealEstateAndConstruction),
+    industry(
+        6510,
+        "REAL ESTATE OPERATORS (NO DEVELOPERS) & LESSORS",
+        Office::RealEstateAndConstruction,
+    ),
+    industry(
+        6512,
+        "OPERATORS OF NONRESIDENTIAL BUILDINGS",
+        Office::RealEstateAndConstruction,
+    ),
+    industry(
+        6513,
+        "OPERATORS OF APARTMENT BUILDINGS",
+        Office::RealEstateAndConstruction,
+    ),
+    industry(
+        6519,
+        "LESSORS OF REAL PROPERTY, NEC",
+        Office::RealEstateAndConstruction,
+    ),
+    industry(
+        6531,
+        "REAL ESTATE AGENTS & MANAGERS (FOR OTHERS)",
+        Office::RealEstateAndConstruction,
+    ),
+    industry(
+        6532,
+        "REAL ESTATE DEALERS (FOR THEIR OWN ACCOUNT)",
+        Office::RealEstateAndConstruction,
+    ),
+    industry(
+        6552,
+        "LAND SUBDIVIDERS & DEVELOPERS (NO CEMETERIES)",
+        Office::RealEstateAndConstruction,
+    ),
+    industry(6770, "BLANK CHECKS", Office::RealEstateAndConstruction),
+    industry(6792, "OIL ROYALTY TRADERS", Office::EnergyAndTransportation),
+    industry(6794, "PATENT OWNERS & LESSORS", Office::TradeAndServices),
+    industry(
+        6795,
+        "MINERAL ROYALTY TRADERS",
+        Office::EnergyAndTransportation,
+    ),
+    industry(
+        6798,
+        "REAL ESTATE INVESTMENT TRUSTS",
+        Office::RealEstateAndConstruction,
+    ),
+    industry(6799, "INVESTORS, NEC", Office::Finance),
+    industry(
+        7000,
+        "HOTELS, ROOMING HOUSES, CAMPS & OTHER LODGING PLACES",
+        Office::RealEstateAndConstruction,
+    ),
+    industry(7011, "HOTELS & MOTELS", Office::RealEstateAndConstruction),
+    industry(7200, "SERVICES-PERSONAL SERVICES", Office::TradeAndServices),
+    industry(7310, "SERVICES-ADVERTISING", Office::TradeAndServices),
+    industry(
+        7311,
+        "SERVICES-ADVERTISING AGENCIES",
+        Office::TradeAndServices,
+    ),
+    industry(
+        7320,
+        "SERVICES-CONSUMER CREDIT REPORTING, COLLECTION AGENCIES",
+        Office::TradeAndServices,
+    ),
+    industry(
+        7330,
+        "SERVICES-MAILING, REPRODUCTION, COMMERCIAL ART & PHOTOGRAPHY",
+        Office::TradeAndServices,
+    ),
+    industry(
+        7331,
+        "SERVICES-DIRECT MAIL ADVERTISING SERVICES",
+        Office::TradeAndServices,
+    ),
+    industry(
+        7340,
+        "SERVICES-TO DWELLINGS & OTHER BUILDINGS",
+        Office::TradeAndServices,
+    ),
+    industry(
+        7350,
+        "SERVICES-MISCELLANEOUS EQUIPMENT RENTAL & LEASING",
+        Office::TradeAndServices,
+    ),
+    industry(
+        7359,
+        "SERVICES-EQUIPMENT RENTAL & LEASING, NEC",
+        Office::TradeAndServices,
+    ),
+    industry(
+        7361,
+        "SERVICES-EMPLOYMENT AGENCIES",
+        Office::TradeAndServices,
+    ),
+    industry(
+        7363,
+        "SERVICES-HELP SUPPLY SERVICES",
+        Office::TradeAndServices,
+    ),
+    industry(
+        7370,
+        "SERVICES-COMPUTER PROGRAMMING, DATA PROCESSING, ETC.",
+        Office::Technology,
+    ),
+    industry(
+        7371,
+        "SERVICES-COMPUTER PROGRAMMING SERVICES",
+        Office::Technology,
+    ),
+    industry(7372, "SERVICES-PREPACKAGED SOFTWARE", Office::Technology),
+    industry(
+        7373,
+        "SERVICES-COMPUTER INTEGRATED SYSTEMS DESIGN",
+        Office::Technology,
+    ),
+    industry(
+        7374,
+        "SERVICES-COMPUTER PROCESSING & DATA PREPARATION",
+        Office::Technology,
+    ),
+    industry(
+        7377,
+        "SERVICES-COMPUTER RENTAL & LEASING",
+        Office::Technology,
+    ),
+    industry(
+        7380,
+        "SERVICES-MISCELLANEOUS BUSINESS SERVICES",
+        Office::TradeAndServices,
+    ),
+    industry(
+        7381,
+        "SERVICES-DETECTIVE, GUARD & ARMORED CAR SERVICES",
+        Office::TradeAndServices,
+    ),
+    industry(
+        7384,
+        "SERVICES-PHOTOFINISHING LABORATORIES",
+        Office::TradeAndServices,
+    ),
+    industry(
+        7385,
+        "SERVICES-TELEPHONE INTERCONNECT SYSTEMS",
+        Office::Technology,
+    ),
+    industry(
+        7389,
+        "SERVICES-BUSINESS SERVICES, NEC",
+        Office::TradeAndServices,
+    ),
+    industry(
+        7500,
+        "SERVICES-AUTOMOTIVE REPAIR, SERVICES & PARKING",
+        Office::TradeAndServices,
+    ),
+    industry(
+        7510,
+        "SERVICES-AUTO RENTAL & LEASING (NO DRIVERS)",
+        Office::TradeAndServices,
+    ),
+    industry(
+        7600,
+        "SERVICES-MISCELLANEOUS REPAIR SERVICES",
+        Office::TradeAndServices,
+    ),
+    industry(
+        7812,
+        "SERVICES-MOTION PICTURE & VIDEO TAPE PRODUCTION",
+        Office::TradeAndServices,
+    ),
+    industry(
+        7819,
+        "SERVICES-ALLIED TO MOTION PICTURE PRODUCTION",
+        Office::TradeAndServices,
+    ),
+    industry(
+        7822,
+        "SERVICES-MOTION PICTURE & VIDEO TAPE DISTRIBUTION",
+        Office::TradeAndServices,
+    ),
+    industry(
+        7829,
+        "SERVICES-ALLIED TO MOTION PICTURE DISTRIBUTION",
+        Office::TradeAndServices,
+    ),
+    industry(
+        7830,
+        "SERVICES-MOTION PICTURE THEATERS",
+        Office::TradeAndServices,
+    ),
+    industry(7841, "SERVICES-VIDEO TAPE RENTAL", Office::TradeAndServices),
+    industry(
+        7900,
+        "SERVICES-AMUSEMENT & RECREATION SERVICES",
+        Office::TradeAndServices,
+    ),
+    industry(
+        7948,
+        "SERVICES-RACING, INCLUDING TRACK OPERATION",
+        Office::TradeAndServices,
+    ),
+    industry(
+        7990,
+        "SERVICES-MISCELLANEOUS AMUSEMENT & RECREATION",
+        Office::TradeAndServices,
+    ),
+    industry(
+        7997,
+        "SERVICES-MEMBERSHIP SPORTS & RECREATION CLUBS",
+        Office::TradeAndServices,
+    ),
+    industry(8000, "SERVICES-HEALTH SERVICES", Office::LifeSciences),
+    industry(
+        8011,
+        "SERVICES-OFFICES & CLINICS OF DOCTORS OF MEDICINE",
+        Office::LifeSciences,
+    ),
+    industry(
+        8050,
+        "SERVICES-NURSING & PERSONAL CARE FACILITIES",
+        Office::LifeSciences,
+    ),
+    industry(
+        8051,
+        "SERVICES-SKILLED NURSING CARE FACILITIES",
+        Office::LifeSciences,
+    ),
+    industry(8060, "SERVICES-HOSPITALS", Office::LifeSciences),
+    industry(
+        8062,
+        "SERVICES-GENERAL MEDICAL & SURGICAL HOSPITALS, NEC",
+        Office::LifeSciences,
+    ),
+    industry(8071, "SERVICES-MEDICAL LABORATORIES", Office::LifeSciences),
+    industry(
+        8082,
+        "SERVICES-HOME HEALTH CARE SERVICES",
+        Office::LifeSciences,
+    ),
+    industry(
+        8090,
+        "SERVICES-MISC HEALTH & ALLIED SERVICES, NEC",
+        Office::LifeSciences,
+    ),
+    industry(
+        8093,
+        "SERVICES-SPECIALTY OUTPATIENT FACILITIES, NEC",
+        Office::LifeSciences,
+    ),
+    industry(8111, "SERVICES-LEGAL SERVICES", Office::TradeAndServices),
+    industry(
+        8200,
+        "SERVICES-EDUCATIONAL SERVICES",
+        Office::TradeAndServices,
+    ),
+    industry(8300, "SERVICES-SOCIAL SERVICES", Office::TradeAndServices),
+    industry(
+        8351,
+        "SERVICES-CHILD DAY CARE SERVICES",
+        Office::TradeAndServices,
+    ),
+    industry(
+        8600,
+        "SERVICES-MEMBERSHIP ORGANIZATIONS",
+        Office::TradeAndServices,
+    ),
+    industry(
+        8700,
+        "SERVICES-ENGINEERING, ACCOUNTING, RESEARCH, MANAGEMENT",
+        Office::TradeAndServices,
+    ),
+    industry(
+        8711,
+        "SERVICES-ENGINEERING SERVICES",
+        Office::TradeAndServices,
+    ),
+    industry(
+        8731,
+        "SERVICES-COMMERCIAL PHYSICAL & BIOLOGICAL RESEARCH",
+        Office::LifeSciences,
+    ),
+    industry(
+        8734,
+        "SERVICES-TESTING LABORATORIES",
+        Office::TradeAndServices,
+    ),
+    industry(
+        8741,
+        "SERVICES-MANAGEMENT SERVICES",
+        Office::TradeAndServices,
+    ),
+    industry(
+        8742,
+        "SERVICES-MANAGEMENT CONSULTING SERVICES",
+        Office::TradeAndServices,
+    ),
+    industry(
+        8744,
+        "SERVICES-FACILITIES SUPPORT MANAGEMENT SERVICES",
+        Office::TradeAndServices,
+    ),
+    industry(8880, "AMERICAN DEPOSITARY RECEIPTS", Office::International),
+    industry(8888, "FOREIGN GOVERNMENTS", Office::International),
+    industry(8900, "SERVICES-SERVICES, NEC", Office::TradeAndServices),
+    industry(9721, "INTERNATIONAL AFFAIRS", Office::International),
+    industry(
+        9995,
+        "NON-OPERATING ESTABLISHMENTS",
+        Office::RealEstateAndConstruction,
+    ),
+];
@@ -0,0 +1,80 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// A position in the source. Lines and columns count from one; columns count bytes. Nodes which
+/// were built rather than parsed have a line of zero.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Span {
+    pub offset: usize,
+    pub line: u32,
+    pub column: u32,
+}
+
+impl Span {
+    pub fn start() -> Span {
+        Span {
+            offset: 0,
+            line: 1,
+            column: 1,
+        }
+    }
+
+    // Moves the position past `bytes`, which must be the source starting at this position.
+    pub fn advance(&mut self, bytes: &[u8]) {
+        for &b in bytes {
+            if b == b'\n' {
+                self.line += 1;
+                self.column = 1;
+            } else {
+                self.column += 1;
+            }
+        }
+        self.offset += bytes.len();
+    }
+}
+
+impl Display for Span {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}, column {}", self.line, self.column)
+    }
+}
+
+/// Renders the line of `source` containing `span` with a caret under the tag that starts there,
+/// in the style of a compiler diagnostic:
+///
+/// ```text
+///   --> line 12, column 1
+///    |
+/// 12 | <FISCAL-YEAR-END>1399
+///    | ^^^^^^^^^^^^^^^^^
+/// ```
+pub fn snippet(source: &[u8], span: Span) -> String {
+    let offset = span.offset.min(source.len());
+    let line_start = source[..offset]
+        .iter()
+        .rposition(|&b| b == b'\n')
+        .map_or(0, |idx| idx + 1);
+    let line_end = source[offset..]
+        .iter()
+        .position(|&b| b == b'\n')
+        .map_or(source.len(), |idx| offset + idx);
+    let line = String::from_utf8_lossy(&source[line_start..line_end]);
+    let line = line.trim_end();
+
+    let underline = match source[offset..line_end].iter().position(|&b| b == b'>') {
+        Some(idx) if source.get(offset) == Some(&b'<') => idx + 1,
+        _ => 1,
+    };
+
+    let number = span.line.to_string();
+    let gutter = " ".repeat(number.len());
+    format!(
+        "{gutter}--> {span}\n{gutter} |\n{number} | {line}\n{gutter} | {pad}{carets}\n",
+        gutter = gutter,
+        span = span,
+        number = number,
+        line = line,
+        pad = " ".repeat(offset - line_start),
+        carets = "^".repeat(underline),
+    )
+}
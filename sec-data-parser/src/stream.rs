@@ -0,0 +1,213 @@
+use crate::context::{declared_document_count, Context};
+use crate::document_tree::{close_unknown, parse_node, DocumentTree};
+use crate::encoding::DecodingPolicy;
+use crate::error::{ErrorKind, ParseError, Result};
+use crate::schema::{Document, Submission};
+use crate::span::Span;
+use crate::tag::{ContainerTag, ValueTag};
+use crate::tokens::{Token, Tokenizer};
+use crate::warning::Warning;
+use crate::ParseOptions;
+use std::io::BufRead;
+
+// Yields the documents of a submission one at a time, so that only a single document is held in
+// memory. The header values usually precede the first `<DOCUMENT>`, as they do in the
+// dissemination feed; any which follow it are applied by `merge_trailing`.
+pub struct DocumentStream<R> {
+    tokens: Tokenizer<R>,
+    span: Span,
+    ctx: Context,
+    decoding: DecodingPolicy,
+    pending: Option<DocumentTree<'static>>,
+    finished: bool,
+    declared_documents: Option<(usize, Span)>,
+    documents_read: usize,
+    header: Vec<DocumentTree<'static>>,
+    // Parts other than documents which follow the first document. Once the documents are read,
+    // the header is parsed again with these added, as if they had preceded the documents.
+    trailing: Vec<DocumentTree<'static>>,
+    merged: Option<Submission>,
+}
+
+// Reads the next child of the submission, which opened at `span`. `siblings` are the children
+// read so far, which an unknown closing tag may gather into a container.
+fn next_child<R: BufRead>(
+    tokens: &mut Tokenizer<R>,
+    span: Span,
+    decoding: DecodingPolicy,
+    siblings: &mut Vec<DocumentTree<'static>>,
+) -> Result<Option<DocumentTree<'static>>> {
+    loop {
+        match tokens.next().transpose()? {
+            Some((Token::ContainerTagClose(ContainerTag::Submission), _)) => return Ok(None),
+            Some((Token::ContainerTagClose(ContainerTag::Unknown(name)), _))
+                if close_unknown(siblings, &name) => {}
+            Some((token, span)) => {
+                return Ok(Some(parse_node(token, span, tokens, decoding)?));
+            }
+            None => {
+                return Err(ParseError::new(ErrorKind::UnexpectedEndOfInput(
+                    ContainerTag::Submission,
+                ))
+                .at(span))
+            }
+        }
+    }
+}
+
+impl<R: BufRead> DocumentStream<R> {
+    pub fn open(reader: R, options: &ParseOptions) -> Result<(Submission, DocumentStream<R>)> {
+        let decoding = options.decoding;
+        let mut tokens = Tokenizer::new(reader, options.bodies, options.lenient);
+
+        let span = match tokens.next().transpose()? {
+            Some((Token::ContainerTagOpen(ContainerTag::Submission), span)) => span,
+            Some((_, span)) => {
+                return Err(
+                    ParseError::new(ErrorKind::MissingContainer(ContainerTag::Submission)).at(span),
+                )
+            }
+            None => {
+                return Err(ParseError::new(ErrorKind::MissingContainer(
+                    ContainerTag::Submission,
+                )))
+            }
+        };
+
+        let mut header = Vec::new();
+        let mut pending = None;
+        let mut finished = true;
+        while let Some(part) = next_child(&mut tokens, span, decoding, &mut header)
+            .map_err(|e| e.within(ContainerTag::Submission))?
+        {
+            if let DocumentTree::ContainerNode(ContainerTag::Document, _, _) = part {
+                pending = Some(part);
+                finished = false;
+                break;
+            }
+            header.push(part);
+        }
+
+        let accession_number = header.iter().find_map(|part| match part {
+            DocumentTree::ValueNode(ValueTag::AccessionNumber, value, _) => Some(value.as_ref()),
+            _ => None,
+        });
+        let mut ctx = Context::new(accession_number, options);
+        ctx.enter(ContainerTag::Submission, span);
+        let submission = Submission::from_parts(&header, &mut ctx)?;
+        let declared_documents = declared_document_count(&header);
+        // When the header is the whole submission the stream yields nothing, so the count is
+        // checked here instead of at the end of the documents.
+        if finished {
+            ctx.check_document_count(declared_documents, 0);
+        }
+
+        Ok((
+            submission,
+            DocumentStream {
+                tokens,
+                span,
+                ctx,
+                decoding,
+                pending,
+                finished,
+                declared_documents,
+                documents_read: 0,
+                header,
+                trailing: Vec::new(),
+                merged: None,
+            },
+        ))
+    }
+
+    /// Warnings raised since the last call, starting with those for the header.
+    pub fn take_warnings(&mut self) -> Vec<Warning> {
+        self.ctx.take_warnings()
+    }
+
+    /// Applies any header values found after the first document, such as a trailing `<ITEMS>`,
+    /// to `submission`, the one returned with this stream. Its documents are kept. Only complete
+    /// once the stream is exhausted.
+    pub fn merge_trailing(&mut self, submission: &mut Submission) {
+        if let Some(mut merged) = self.merged.take() {
+            merged.documents = std::mem::take(&mut submission.documents);
+            *submission = merged;
+        }
+    }
+
+    // Parses the header again with the trailing parts added. Only the warnings for the trailing
+    // parts are kept, since those for the header were raised when the stream was opened.
+    fn parse_trailing(&mut self) -> Result<()> {
+        let start = match self.trailing.first().and_then(DocumentTree::span) {
+            Some(span) => span.offset,
+            None => return Ok(()),
+        };
+        let mut parts = std::mem::take(&mut self.header);
+        parts.append(&mut self.trailing);
+
+        let mut warnings = self.ctx.take_warnings();
+        let result = Submission::from_parts(&parts, &mut self.ctx);
+        warnings.extend(
+            self.ctx
+                .take_warnings()
+                .into_iter()
+                .filter(|warning| warning.span.is_none_or(|span| span.offset >= start)),
+        );
+        self.ctx.restore_warnings(warnings);
+
+        self.merged = Some(result?);
+        Ok(())
+    }
+
+    fn next_document(&mut self) -> Result<Option<Document>> {
+        let part = loop {
+            let part = match self.pending.take() {
+                Some(part) => part,
+                None => match next_child(
+                    &mut self.tokens,
+                    self.span,
+                    self.decoding,
+                    &mut self.trailing,
+                )
+                .map_err(|e| e.within(ContainerTag::Submission))?
+                {
+                    Some(part) => part,
+                    None => {
+                        self.parse_trailing()?;
+                        self.ctx
+                            .check_document_count(self.declared_documents, self.documents_read);
+                        return Ok(None);
+                    }
+                },
+            };
+
+            match &part {
+                DocumentTree::ContainerNode(ContainerTag::Document, _, _) => break part,
+                _ => self.trailing.push(part),
+            }
+        };
+
+        match &part {
+            DocumentTree::ContainerNode(tag, parts, span) => {
+                let document = self.ctx.nested(tag, parts, *span, Document::from_parts)?;
+                self.documents_read += 1;
+                Ok(Some(document))
+            }
+            _ => Err(self.ctx.unexpected(&part)),
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for DocumentStream<R> {
+    type Item = Result<Document>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+
+        let result = self.next_document().transpose();
+        self.finished = !matches!(result, Some(Ok(_)));
+        result
+    }
+}
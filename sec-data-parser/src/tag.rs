@@ -1,7 +1,7 @@
-use crate::error::{ParseError, Result};
-use std::fmt::Debug;
+use crate::error::{ErrorKind, ParseError, Result};
+use std::fmt::{Debug, Display, Formatter};
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum ContainerTag {
     AcquiringData,
     BusinessAddress,
@@ -31,6 +31,8 @@ pub enum ContainerTag {
     SubjectCompany,
     Submission,
     TargetData,
+    /// A tag this crate does not know, kept when parsing leniently.
+    Unknown(String),
 }
 
 impl ContainerTag {
@@ -67,12 +69,52 @@ impl ContainerTag {
             "SUBMISSION" => ContainerTag::Submission,
             "TARGET-DATA" => ContainerTag::TargetData,
 
-            _ => return Err(ParseError::InvalidContainerTag(tag.to_string())),
+            _ => {
+                return Err(ParseError::new(ErrorKind::InvalidContainerTag(
+                    tag.to_string(),
+                )))
+            }
         })
     }
+
+    pub fn name(&self) -> &str {
+        match self {
+            ContainerTag::AcquiringData => "ACQUIRING-DATA",
+            ContainerTag::BusinessAddress => "BUSINESS-ADDRESS",
+            ContainerTag::ClassContract => "CLASS-CONTRACT",
+            ContainerTag::CompanyData => "COMPANY-DATA",
+            ContainerTag::Depositor => "DEPOSITOR",
+            ContainerTag::Document => "DOCUMENT",
+            ContainerTag::ExistingSeriesAndClassesContracts => {
+                "EXISTING-SERIES-AND-CLASSES-CONTRACTS"
+            }
+            ContainerTag::FiledBy => "FILED-BY",
+            ContainerTag::FiledFor => "FILED-FOR",
+            ContainerTag::Filer => "FILER",
+            ContainerTag::FilingValues => "FILING-VALUES",
+            ContainerTag::FormerCompany => "FORMER-COMPANY",
+            ContainerTag::FormerName => "FORMER-NAME",
+            ContainerTag::Issuer => "ISSUER",
+            ContainerTag::MailAddress => "MAIL-ADDRESS",
+            ContainerTag::Merger => "MERGER",
+            ContainerTag::MergerSeriesAndClassesContracts => "MERGER-SERIES-AND-CLASSES-CONTRACTS",
+            ContainerTag::NewClassesContracts => "NEW-CLASSES-CONTRACTS",
+            ContainerTag::NewSeries => "NEW-SERIES",
+            ContainerTag::NewSeriesAndClassesContracts => "NEW-SERIES-AND-CLASSES-CONTRACTS",
+            ContainerTag::OwnerData => "OWNER-DATA",
+            ContainerTag::ReportingOwner => "REPORTING-OWNER",
+            ContainerTag::Securitizer => "SECURITIZER",
+            ContainerTag::Series => "SERIES",
+            ContainerTag::SeriesAndClassesContractsData => "SERIES-AND-CLASSES-CONTRACTS-DATA",
+            ContainerTag::SubjectCompany => "SUBJECT-COMPANY",
+            ContainerTag::Submission => "SUBMISSION",
+            ContainerTag::TargetData => "TARGET-DATA",
+            ContainerTag::Unknown(name) => name,
+        }
+    }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum ValueTag {
     AbsAssetClass,
     AbsRule,
@@ -145,6 +187,8 @@ pub enum ValueTag {
     Timestamp,
     Type,
     Zip,
+    /// A tag this crate does not know, kept when parsing leniently.
+    Unknown(String),
 }
 
 impl ValueTag {
@@ -223,7 +267,98 @@ impl ValueTag {
             "TIMESTAMP" => ValueTag::Timestamp,
             "TYPE" => ValueTag::Type,
             "ZIP" => ValueTag::Zip,
-            _ => return Err(ParseError::InvalidValueTag(tag.to_string())),
+            _ => return Err(ParseError::new(ErrorKind::InvalidValueTag(tag.to_string()))),
         })
     }
+
+    pub fn name(&self) -> &str {
+        match self {
+            ValueTag::AbsAssetClass => "ABS-ASSET-CLASS",
+            ValueTag::AbsRule => "ABS-RULE",
+            ValueTag::AccessionNumber => "ACCESSION-NUMBER",
+            ValueTag::Act => "ACT",
+            ValueTag::ActionDate => "ACTION-DATE",
+            ValueTag::AssignedSic => "ASSIGNED-SIC",
+            ValueTag::Category => "CATEGORY",
+            ValueTag::Cik => "CIK",
+            ValueTag::City => "CITY",
+            ValueTag::ClassContractId => "CLASS-CONTRACT-ID",
+            ValueTag::ClassContractName => "CLASS-CONTRACT-NAME",
+            ValueTag::ClassContractTickerSymbol => "CLASS-CONTRACT-TICKER-SYMBOL",
+            ValueTag::ConformedName => "CONFORMED-NAME",
+            ValueTag::ConfirmingCopy => "CONFIRMING-COPY",
+            ValueTag::Correction => "CORRECTION",
+            ValueTag::DateChanged => "DATE-CHANGED",
+            ValueTag::DateOfFilingDateChange => "DATE-OF-FILING-DATE-CHANGE",
+            ValueTag::Deletion => "DELETION",
+            ValueTag::DepositorCik => "DEPOSITOR-CIK",
+            ValueTag::DepositorFileNumber => "DEPOSITOR-FILE-NUMBER",
+            ValueTag::Description => "DESCRIPTION",
+            ValueTag::EffectivenessDate => "EFFECTIVENESS-DATE",
+            ValueTag::FileNumber => "FILE-NUMBER",
+            ValueTag::FiledPursuantToGeneralInstructionA2 => {
+                "FILED-PURSUANT-TO-GENERAL-INSTRUCTION-A2"
+            }
+            ValueTag::Filename => "FILENAME",
+            ValueTag::FilingDate => "FILING-DATE",
+            ValueTag::FilmNumber => "FILM-NUMBER",
+            ValueTag::FiscalYearEnd => "FISCAL-YEAR-END",
+            ValueTag::Flawed => "FLAWED",
+            ValueTag::FormType => "FORM-TYPE",
+            ValueTag::FormerConformedName => "FORMER-CONFORMED-NAME",
+            ValueTag::GroupMembers => "GROUP-MEMBERS",
+            ValueTag::IrsNumber => "IRS-NUMBER",
+            ValueTag::IsFilerANewRegistrant => "IS-FILER-A-NEW-REGISTRANT",
+            ValueTag::IsFilerAWellKnownSeasonedIssuer => "IS-FILER-A-WELL-KNOWN-SEASONED-ISSUER",
+            ValueTag::IsFund24F2Eligible => "IS-FUND-24F2-ELIGIBLE",
+            ValueTag::IssuingEntityCik => "ISSUING-ENTITY-CIK",
+            ValueTag::IssuingEntityName => "ISSUING-ENTITY-NAME",
+            ValueTag::Items => "ITEMS",
+            ValueTag::MaIIndividual => "MA-I_INDIVIDUAL",
+            ValueTag::NoAnnualActivity => "NO-ANNUAL-ACTIVITY",
+            ValueTag::NoQuarterlyActivity => "NO-QUARTERLY-ACTIVITY",
+            ValueTag::OwnerCik => "OWNER-CIK",
+            ValueTag::Paper => "PAPER",
+            ValueTag::Period => "PERIOD",
+            ValueTag::PeriodStart => "PERIOD-START",
+            ValueTag::Phone => "PHONE",
+            ValueTag::PublicDocumentCount => "PUBLIC-DOCUMENT-COUNT",
+            ValueTag::PublicReferenceAcc => "PUBLIC-REFERENCE-ACC",
+            ValueTag::PublicRelDate => "PUBLIC-REL-DATE",
+            ValueTag::PreviousAccessionNumber => "PREVIOUS-ACCESSION-NUMBER",
+            ValueTag::PrivateToPublic => "PRIVATE-TO-PUBLIC",
+            ValueTag::ReceivedDate => "RECEIVED-DATE",
+            ValueTag::Reference462B => "REFERENCE-462B",
+            ValueTag::References429 => "REFERENCES-429",
+            ValueTag::RegisteredEntity => "REGISTERED-ENTITY",
+            ValueTag::Relationship => "RELATIONSHIP",
+            ValueTag::SecuritizerCik => "SECURITIZER-CIK",
+            ValueTag::SecuritizerFileNumber => "SECURITIZER-FILE-NUMBER",
+            ValueTag::Sequence => "SEQUENCE",
+            ValueTag::SeriesId => "SERIES-ID",
+            ValueTag::SeriesName => "SERIES-NAME",
+            ValueTag::SponsorCik => "SPONSOR-CIK",
+            ValueTag::Sros => "SROS",
+            ValueTag::State => "STATE",
+            ValueTag::StateOfInforporation => "STATE-OF-INCORPORATION",
+            ValueTag::Street1 => "STREET1",
+            ValueTag::Street2 => "STREET2",
+            ValueTag::Timestamp => "TIMESTAMP",
+            ValueTag::Type => "TYPE",
+            ValueTag::Zip => "ZIP",
+            ValueTag::Unknown(name) => name,
+        }
+    }
+}
+
+impl Display for ContainerTag {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl Display for ValueTag {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
 }
@@ -0,0 +1,322 @@
+//! Form 13F, on which institutional investment managers report their holdings each quarter. The
+//! holdings are listed in an information table, an XML document since 2013 and a text table
+//! before that.
+
+use crate::error::{ErrorKind, ParseError, Result};
+use crate::form_type::Form;
+use crate::schema::{Document, Submission};
+use crate::xml::{
+    self, child, child_string, child_text, child_value, children, parse_integer, required_text,
+    required_value,
+};
+use roxmltree::Node;
+use serde::{Deserialize, Serialize};
+use std::ops::Range;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Holding {
+    pub issuer_name: String,
+    pub class: String,
+    pub cusip: String,
+    /// The market value, in thousands of dollars in tables filed before 2023 and in dollars
+    /// since.
+    pub value: u64,
+    /// The number of shares, or the principal amount for debt.
+    pub shares: u64,
+    pub shares_type: Option<SharesType>,
+    pub put_call: Option<PutCall>,
+    pub discretion: Option<InvestmentDiscretion>,
+    /// The sequence numbers of the other managers the holding is reported for, as written.
+    pub other_managers: Option<String>,
+    pub voting: Option<VotingAuthority>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SharesType {
+    Shares,
+    Principal,
+}
+
+impl SharesType {
+    pub fn parse(st: &str) -> Option<SharesType> {
+        match st.to_ascii_uppercase().as_str() {
+            "SH" => Some(SharesType::Shares),
+            "PRN" => Some(SharesType::Principal),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PutCall {
+    Put,
+    Call,
+}
+
+impl PutCall {
+    pub fn parse(st: &str) -> Option<PutCall> {
+        match st.to_ascii_uppercase().as_str() {
+            "PUT" => Some(PutCall::Put),
+            "CALL" => Some(PutCall::Call),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum InvestmentDiscretion {
+    Sole,
+    SharedDefined,
+    SharedOther,
+}
+
+impl InvestmentDiscretion {
+    /// Reads both the XML codes, such as `DFND`, and the words used in text tables.
+    pub fn parse(st: &str) -> Option<InvestmentDiscretion> {
+        match st.to_ascii_uppercase().as_str() {
+            "SOLE" => Some(InvestmentDiscretion::Sole),
+            "DFND" | "DEFINED" | "SHARED" | "SHARED-DEFINED" | "SHR" => {
+                Some(InvestmentDiscretion::SharedDefined)
+            }
+            "OTR" | "OTHER" | "SHARED-OTHER" => Some(InvestmentDiscretion::SharedOther),
+            _ => None,
+        }
+    }
+}
+
+/// The number of shares over which the manager has each kind of voting authority.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VotingAuthority {
+    pub sole: u64,
+    pub shared: u64,
+    pub none: u64,
+}
+
+/// Parses an XML information table, without the `<XML>` wrapper.
+pub fn parse_xml(st: &str) -> Result<Vec<Holding>> {
+    let document = xml::parse(st)?;
+    let root = xml::root(&document, "informationTable")?;
+    children(root, "infoTable").map(holding_from_node).collect()
+}
+
+fn holding_from_node(node: Node<'_, '_>) -> Result<Holding> {
+    let amount = child(node, "shrsOrPrnAmt")
+        .ok_or_else(|| xml::malformed("missing <shrsOrPrnAmt>".into()))?;
+    let voting = match child(node, "votingAuthority") {
+        Some(voting) => Some(VotingAuthority {
+            sole: child_value(voting, "Sole", parse_integer)?.unwrap_or(0),
+            shared: child_value(voting, "Shared", parse_integer)?.unwrap_or(0),
+            none: child_value(voting, "None", parse_integer)?.unwrap_or(0),
+        }),
+        None => None,
+    };
+
+    Ok(Holding {
+        issuer_name: required_text(node, "nameOfIssuer")?.to_string(),
+        class: child_string(node, "titleOfClass").unwrap_or_default(),
+        cusip: required_text(node, "cusip")?.to_ascii_uppercase(),
+        value: required_value(node, "value", parse_integer)?,
+        shares: required_value(amount, "sshPrnamt", parse_integer)?,
+        shares_type: child_value(amount, "sshPrnamtType", SharesType::parse)?,
+        put_call: child_value(node, "putCall", PutCall::parse)?,
+        discretion: child_value(node, "investmentDiscretion", InvestmentDiscretion::parse)?,
+        other_managers: child_text(node, "otherManager").map(|st| st.to_string()),
+        voting,
+    })
+}
+
+/// The rows of a text information table.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TextTable {
+    pub holdings: Vec<Holding>,
+    /// Rows with a CUSIP whose columns could not be read, such as a wrapped issuer name or a row
+    /// with a missing column, as written.
+    pub unread_rows: Vec<String>,
+}
+
+/// Reads the holdings from a text information table, as filed before 2013. These tables have no
+/// fixed layout, so rows are found by their CUSIP: the text before it is the issuer's name and
+/// the class, separated by at least two spaces, and the columns after it are read in the order
+/// of the form. Lines without a CUSIP, such as headings and totals, are skipped, and rows whose
+/// columns cannot be read are kept in `unread_rows`. A table without any holdings is an error.
+pub fn parse_text(st: &str) -> Result<TextTable> {
+    let mut table = TextTable {
+        holdings: Vec::new(),
+        unread_rows: Vec::new(),
+    };
+    for line in st.lines() {
+        if let Some((range, cusip)) = find_cusip(line) {
+            match holding_from_line(line, range, cusip) {
+                Some(holding) => table.holdings.push(holding),
+                None => table.unread_rows.push(line.trim().to_string()),
+            }
+        }
+    }
+    if table.holdings.is_empty() {
+        return Err(malformed("information table has no holdings".into()));
+    }
+    Ok(table)
+}
+
+// The part of a primary document after the information table's heading, leaving out the cover
+// page. The summary page's `Information Table Entry Total` and `Value Total` are not headings.
+fn after_table_heading(st: &str) -> &str {
+    let mut offset = 0;
+    for line in st.split_inclusive('\n') {
+        offset += line.len();
+        let upper = line.to_ascii_uppercase();
+        if upper.contains("INFORMATION TABLE") && !upper.contains("TOTAL") {
+            return &st[offset..];
+        }
+    }
+    ""
+}
+
+fn malformed(reason: String) -> ParseError {
+    ParseError::new(ErrorKind::MalformedBody(reason))
+}
+
+// A CUSIP has nine characters, the last a check digit. Tables often carry mistyped CUSIPs, so the
+// check digit is only required of all-digit tokens, which might otherwise be, say, a ZIP code.
+fn is_cusip(token: &str) -> bool {
+    token.len() == 9
+        && token
+            .bytes()
+            .all(|b| b.is_ascii_digit() || b.is_ascii_uppercase())
+        && token.bytes().last().is_some_and(|b| b.is_ascii_digit())
+        && token.bytes().filter(|b| b.is_ascii_digit()).count() >= 4
+        && (token.bytes().any(|b| b.is_ascii_uppercase()) || has_check_digit(token))
+}
+
+// The modulus 10 double-add-double check: every second character's value is doubled, and the
+// digits of the values are summed.
+fn has_check_digit(cusip: &str) -> bool {
+    let bytes = cusip.as_bytes();
+    let sum: u32 = bytes[..8]
+        .iter()
+        .enumerate()
+        .map(|(i, b)| {
+            let value = match b {
+                b'0'..=b'9' => u32::from(b - b'0'),
+                _ => u32::from(b - b'A') + 10,
+            };
+            let value = if i % 2 == 1 { value * 2 } else { value };
+            value / 10 + value % 10
+        })
+        .sum();
+    u32::from(bytes[8] - b'0') == (10 - sum % 10) % 10
+}
+
+// Finds the CUSIP in a row, returning where it is in the line. Some tables write the issuer,
+// issue and check digit apart, as in `037833 10 0`.
+fn find_cusip(line: &str) -> Option<(Range<usize>, String)> {
+    let offset = |token: &str| token.as_ptr() as usize - line.as_ptr() as usize;
+    let tokens: Vec<&str> = line.split_whitespace().collect();
+    (0..tokens.len()).find_map(|i| {
+        let parts = if is_cusip(tokens[i]) {
+            &tokens[i..=i]
+        } else {
+            tokens
+                .get(i..i + 3)
+                .filter(|parts| parts.iter().map(|part| part.len()).eq([6, 2, 1]))?
+        };
+        let cusip = parts.concat();
+        let last = parts[parts.len() - 1];
+        is_cusip(&cusip).then(|| (offset(parts[0])..offset(last) + last.len(), cusip))
+    })
+}
+
+fn holding_from_line(line: &str, range: Range<usize>, cusip: String) -> Option<Holding> {
+    let before = &line[..range.start];
+    let mut after = line[range.end..]
+        .split_whitespace()
+        .map(|token| token.trim_start_matches('$'))
+        .filter(|token| !token.is_empty())
+        .peekable();
+
+    let mut columns = before
+        .split("  ")
+        .map(str::trim)
+        .filter(|st| !st.is_empty());
+    let issuer_name = columns.next()?.to_string();
+    let class = columns.collect::<Vec<_>>().join(" ");
+
+    let value = parse_integer(after.next()?)?;
+    let shares = parse_integer(after.next()?)?;
+    let shares_type = after.peek().and_then(|token| SharesType::parse(token));
+    if shares_type.is_some() {
+        after.next();
+    }
+    let put_call = after.peek().and_then(|token| PutCall::parse(token));
+    if put_call.is_some() {
+        after.next();
+    }
+    let discretion = after
+        .peek()
+        .and_then(|token| InvestmentDiscretion::parse(token));
+    if discretion.is_some() {
+        after.next();
+    }
+
+    let rest: Vec<&str> = after.collect();
+    let (managers, voting) = match rest.len().checked_sub(3) {
+        Some(split) => match (
+            parse_integer(rest[split]),
+            parse_integer(rest[split + 1]),
+            parse_integer(rest[split + 2]),
+        ) {
+            (Some(sole), Some(shared), Some(none)) => {
+                (&rest[..split], Some(VotingAuthority { sole, shared, none }))
+            }
+            _ => (&rest[..], None),
+        },
+        None => (&rest[..], None),
+    };
+
+    Some(Holding {
+        issuer_name,
+        class,
+        cusip,
+        value,
+        shares,
+        shares_type,
+        put_call,
+        discretion,
+        other_managers: match managers {
+            [] => None,
+            managers => Some(managers.join(" ")),
+        },
+        voting,
+    })
+}
+
+impl Submission {
+    /// The holdings listed in a 13F-HR's information table. Returns `None` for other forms and
+    /// when no information table was found among the loaded bodies. Before 2013 the table is
+    /// often part of the primary document rather than a document of its own, in which case it
+    /// is read from the table's heading on. Text rows which cannot be read are left out; use
+    /// `parse_text` to see them.
+    pub fn information_table(&self) -> Option<Result<Vec<Holding>>> {
+        if self.form().form != Form::ThirteenFHr {
+            return None;
+        }
+
+        let table = self
+            .documents
+            .iter()
+            .find(|document| document.doc_type.eq_ignore_ascii_case("INFORMATION TABLE"));
+        if let Some(xml) = table.and_then(Document::xml) {
+            return Some(parse_xml(xml));
+        }
+        let text = match table.and_then(Document::plain_text) {
+            Some(text) => text,
+            None => after_table_heading(
+                self.documents
+                    .iter()
+                    .filter(|document| document.form().form == Form::ThirteenFHr)
+                    .find_map(Document::plain_text)?,
+            ),
+        };
+        Some(parse_text(text).map(|table| table.holdings))
+    }
+}
@@ -1,60 +1,343 @@
-use crate::error::Result;
+use crate::document_body::{BodyMode, BodyRange};
+use crate::error::{ErrorKind, ParseError, Result};
+use crate::span::Span;
 use crate::tag::{ContainerTag, ValueTag};
+use std::borrow::Cow;
+use std::io::BufRead;
 
+const TEXT_CLOSE: &[u8] = b"</TEXT>";
+
+// Tokens borrow from the source when it is held in memory, and own their bytes when they are
+// read from a stream.
 #[derive(Debug, PartialEq)]
-pub enum Token {
+pub enum Token<'a> {
     ContainerTagOpen(ContainerTag),
     ContainerTagClose(ContainerTag),
-    ValueTag(ValueTag),
-    RawText(String),
-    TextBlock(String),
-}
-
-pub fn next_token(st: &str) -> Result<(Token, &str)> {
-    Ok(if st.starts_with('<') {
-        let closing = st.starts_with("</");
-        let end_idx = st.find('>').unwrap();
-        let start_idx = if closing { 2 } else { 1 };
-        let tag = st[start_idx..end_idx].to_string();
-        if tag == "TEXT" {
-            let start_idx = "<TEXT>".len();
-            let end_idx = st.find("</TEXT>").unwrap();
-            let content = st[start_idx..end_idx].to_string();
-            let st = &st[end_idx + "</TEXT>".len()..];
-
-            (Token::TextBlock(content), st)
-        } else if let Ok(container_tag) = ContainerTag::parse(&tag) {
-            if closing {
-                (Token::ContainerTagClose(container_tag), &st[end_idx + 1..])
-            } else {
-                (Token::ContainerTagOpen(container_tag), &st[end_idx + 1..])
-            }
+    ValueTag(ValueTag, Cow<'a, [u8]>),
+    RawText(Cow<'a, [u8]>),
+    TextBlock(Cow<'a, [u8]>),
+    TextRange(BodyRange),
+}
+
+enum Tag {
+    Text,
+    ContainerOpen(ContainerTag),
+    ContainerClose(ContainerTag),
+    Value(ValueTag),
+}
+
+// Classifies a complete tag, including its angle brackets. When lenient, an unknown opening tag is
+// treated as a value, since whether it is really a container is only known once its closing tag
+// turns up.
+fn parse_tag(tag: &[u8], lenient: bool) -> Result<Tag> {
+    let closing = tag.starts_with(b"</");
+    let start_idx = if closing { 2 } else { 1 };
+    let name = String::from_utf8_lossy(&tag[start_idx..tag.len() - 1]);
+
+    Ok(if name == "TEXT" && !closing {
+        Tag::Text
+    } else if let Ok(container_tag) = ContainerTag::parse(&name) {
+        if closing {
+            Tag::ContainerClose(container_tag)
         } else {
-            (Token::ValueTag(ValueTag::parse(&tag)?), &st[end_idx + 1..])
+            Tag::ContainerOpen(container_tag)
         }
     } else {
-        let end_idx = st.find('<').unwrap();
-        (
-            Token::RawText(st[..end_idx].trim().to_string()),
-            &st[end_idx..],
-        )
+        match ValueTag::parse(&name) {
+            Ok(value_tag) => Tag::Value(value_tag),
+            Err(_) if lenient && closing => {
+                Tag::ContainerClose(ContainerTag::Unknown(name.into_owned()))
+            }
+            Err(_) if lenient => Tag::Value(ValueTag::Unknown(name.into_owned())),
+            Err(e) => return Err(e),
+        }
     })
 }
 
-pub fn tokenize_submission(submission: String) -> Result<Vec<Token>> {
-    let mut tokens: Vec<Token> = Vec::new();
-    let mut st = submission.as_str();
+fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    let mut start = 0;
+    while let Some(idx) = haystack[start..].iter().position(|&b| b == needle[0]) {
+        if haystack[start + idx..].starts_with(needle) {
+            return Some(start + idx);
+        }
+        start += idx + 1;
+    }
+    None
+}
+
+fn trim(mut bytes: Vec<u8>) -> Vec<u8> {
+    let end = bytes.len()
+        - bytes
+            .iter()
+            .rev()
+            .take_while(|b| b.is_ascii_whitespace())
+            .count();
+    bytes.truncate(end);
+    let start = bytes.iter().take_while(|b| b.is_ascii_whitespace()).count();
+    bytes.drain(..start);
+    bytes
+}
+
+// Pulls tokens from a reader one at a time, so that only the token currently being read (at most
+// one `<TEXT>` block) is held in memory. Each token is paired with its position in the source.
+pub struct Tokenizer<R> {
+    reader: R,
+    bodies: BodyMode,
+    lenient: bool,
+    span: Span,
+    failed: bool,
+}
+
+impl<R: BufRead> Tokenizer<R> {
+    pub fn new(reader: R, bodies: BodyMode, lenient: bool) -> Tokenizer<R> {
+        Tokenizer {
+            reader,
+            bodies,
+            lenient,
+            span: Span::start(),
+            failed: false,
+        }
+    }
+
+    // Skips whitespace between tokens and returns the first byte after it, if any.
+    fn skip_whitespace(&mut self) -> Result<Option<u8>> {
+        loop {
+            let available = self.reader.fill_buf()?;
+            match available.iter().position(|b| !b.is_ascii_whitespace()) {
+                Some(idx) => {
+                    let next = available[idx];
+                    self.span.advance(&available[..idx]);
+                    self.reader.consume(idx);
+                    return Ok(Some(next));
+                }
+                None if available.is_empty() => return Ok(None),
+                None => {
+                    let amount = available.len();
+                    self.span.advance(available);
+                    self.reader.consume(amount);
+                }
+            }
+        }
+    }
+
+    // Reads up to and including `delimiter`, returning false if the input ended first.
+    fn read_until(&mut self, delimiter: u8, buf: &mut Vec<u8>) -> Result<bool> {
+        let start = buf.len();
+        self.reader.read_until(delimiter, buf)?;
+        self.span.advance(&buf[start..]);
+        Ok(buf.last() == Some(&delimiter))
+    }
+
+    // Reads up to, but not including, the start of the next tag.
+    fn read_text(&mut self, buf: &mut Vec<u8>) -> Result<()> {
+        loop {
+            let available = self.reader.fill_buf()?;
+            match available.iter().position(|&b| b == b'<') {
+                Some(idx) => {
+                    buf.extend_from_slice(&available[..idx]);
+                    self.span.advance(&available[..idx]);
+                    self.reader.consume(idx);
+                    return Ok(());
+                }
+                None if available.is_empty() => return Ok(()),
+                None => {
+                    buf.extend_from_slice(available);
+                    let amount = available.len();
+                    self.span.advance(available);
+                    self.reader.consume(amount);
+                }
+            }
+        }
+    }
+
+    // Reads past the end of a `<TEXT>` block, keeping only the range it occupied.
+    fn skip_text(&mut self) -> Result<BodyRange> {
+        let offset = self.span.offset;
+        let mut chunk = Vec::new();
+        loop {
+            chunk.clear();
+            if !self.read_until(b'>', &mut chunk)? {
+                return Err(ParseError::new(ErrorKind::UnterminatedTextBlock));
+            }
+            if chunk.ends_with(TEXT_CLOSE) {
+                return Ok(BodyRange {
+                    offset,
+                    length: self.span.offset - TEXT_CLOSE.len() - offset,
+                });
+            }
+        }
+    }
+
+    fn read_token(&mut self, first: u8) -> Result<Option<Token<'static>>> {
+        let mut buf = Vec::new();
+        if first != b'<' {
+            self.read_text(&mut buf)?;
+            return Ok(Some(Token::RawText(Cow::Owned(trim(buf)))));
+        }
+
+        if !self.read_until(b'>', &mut buf)? {
+            return Err(ParseError::new(ErrorKind::UnterminatedTag));
+        }
+
+        Ok(Some(match parse_tag(&buf, self.lenient)? {
+            Tag::Text if self.bodies == BodyMode::Skip => {
+                self.skip_text()?;
+                return Ok(None);
+            }
+            Tag::Text if self.bodies == BodyMode::Lazy => Token::TextRange(self.skip_text()?),
+            Tag::Text => {
+                let mut content = Vec::new();
+                loop {
+                    if !self.read_until(b'>', &mut content)? {
+                        return Err(ParseError::new(ErrorKind::UnterminatedTextBlock));
+                    }
+                    if content.ends_with(TEXT_CLOSE) {
+                        content.truncate(content.len() - TEXT_CLOSE.len());
+                        break Token::TextBlock(Cow::Owned(content));
+                    }
+                }
+            }
+            Tag::ContainerOpen(tag) => Token::ContainerTagOpen(tag),
+            Tag::ContainerClose(tag) => Token::ContainerTagClose(tag),
+            Tag::Value(tag) => {
+                let mut value = Vec::new();
+                self.read_text(&mut value)?;
+                Token::ValueTag(tag, Cow::Owned(trim(value)))
+            }
+        }))
+    }
+
+    pub fn next_token(&mut self) -> Result<Option<(Token<'static>, Span)>> {
+        loop {
+            let first = match self.skip_whitespace()? {
+                Some(first) => first,
+                None => return Ok(None),
+            };
+
+            let span = self.span;
+            if let Some(token) = self.read_token(first).map_err(|e| e.at(span))? {
+                return Ok(Some((token, span)));
+            }
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for Tokenizer<R> {
+    type Item = Result<(Token<'static>, Span)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+
+        let result = self.next_token().transpose();
+        self.failed = matches!(result, Some(Err(_)));
+        result
+    }
+}
+
+// Tokenizes a submission which is already in memory without copying any of it.
+pub struct SliceTokenizer<'a> {
+    source: &'a [u8],
+    bodies: BodyMode,
+    lenient: bool,
+    span: Span,
+    failed: bool,
+}
+
+impl<'a> SliceTokenizer<'a> {
+    pub fn new(source: &'a [u8], bodies: BodyMode, lenient: bool) -> SliceTokenizer<'a> {
+        SliceTokenizer {
+            source,
+            bodies,
+            lenient,
+            span: Span::start(),
+            failed: false,
+        }
+    }
+
+    fn rest(&self) -> &'a [u8] {
+        &self.source[self.span.offset..]
+    }
+
+    fn advance(&mut self, amount: usize) {
+        let start = self.span.offset;
+        self.span.advance(&self.source[start..start + amount]);
+    }
+
+    // Returns the text up to, but not including, the start of the next tag.
+    fn read_text(&mut self) -> &'a [u8] {
+        let rest = self.rest();
+        let end_idx = rest.iter().position(|&b| b == b'<').unwrap_or(rest.len());
+        self.advance(end_idx);
+        rest[..end_idx].trim_ascii()
+    }
 
-    while st.len() > 0 {
-        if st.starts_with('\n') || st.starts_with(' ') {
-            st = &st[1..];
-            continue;
+    fn read_token(&mut self) -> Result<Option<Token<'a>>> {
+        let rest = self.rest();
+        if !rest.starts_with(b"<") {
+            return Ok(Some(Token::RawText(Cow::Borrowed(self.read_text()))));
         }
 
-        let (tok, new_st) = next_token(st)?;
-        tokens.push(tok);
-        st = new_st;
+        let end_idx = rest
+            .iter()
+            .position(|&b| b == b'>')
+            .ok_or_else(|| ParseError::new(ErrorKind::UnterminatedTag))?;
+        self.advance(end_idx + 1);
+
+        Ok(Some(match parse_tag(&rest[..=end_idx], self.lenient)? {
+            Tag::Text => {
+                let rest = self.rest();
+                let end_idx = find(rest, TEXT_CLOSE)
+                    .ok_or_else(|| ParseError::new(ErrorKind::UnterminatedTextBlock))?;
+                let range = BodyRange {
+                    offset: self.span.offset,
+                    length: end_idx,
+                };
+                self.advance(end_idx + TEXT_CLOSE.len());
+
+                match self.bodies {
+                    BodyMode::Skip => return Ok(None),
+                    BodyMode::Lazy => Token::TextRange(range),
+                    BodyMode::Full => Token::TextBlock(Cow::Borrowed(&rest[..end_idx])),
+                }
+            }
+            Tag::ContainerOpen(tag) => Token::ContainerTagOpen(tag),
+            Tag::ContainerClose(tag) => Token::ContainerTagClose(tag),
+            Tag::Value(tag) => Token::ValueTag(tag, Cow::Borrowed(self.read_text())),
+        }))
+    }
+
+    pub fn next_token(&mut self) -> Result<Option<(Token<'a>, Span)>> {
+        loop {
+            let whitespace = self
+                .rest()
+                .iter()
+                .take_while(|b| b.is_ascii_whitespace())
+                .count();
+            self.advance(whitespace);
+            if self.rest().is_empty() {
+                return Ok(None);
+            }
+
+            let span = self.span;
+            if let Some(token) = self.read_token().map_err(|e| e.at(span))? {
+                return Ok(Some((token, span)));
+            }
+        }
     }
+}
+
+impl<'a> Iterator for SliceTokenizer<'a> {
+    type Item = Result<(Token<'a>, Span)>;
 
-    Ok(tokens)
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+
+        let result = self.next_token().transpose();
+        self.failed = matches!(result, Some(Err(_)));
+        result
+    }
 }
@@ -1,6 +1,7 @@
 use chrono::{Month, NaiveDate, NaiveDateTime};
 use num_traits::FromPrimitive;
-use serde::{Serialize, Deserialize};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
 
 const DATE_FORMAT: &str = "%Y%m%d";
 const DATE_TIME_FORMAT: &str = "%Y%m%d:%H%M%S";
@@ -9,27 +10,160 @@ const DATE_TIME_FORMAT: &str = "%Y%m%d:%H%M%S";
 pub struct MonthDayPair(chrono::Month, u32);
 
 impl MonthDayPair {
-    pub fn parse(st: &str) -> MonthDayPair {
-        let month_n: u32 = st[..2].parse().unwrap();
-        let day: u32 = st[2..].parse().unwrap();
+    pub fn parse(st: &str) -> Option<MonthDayPair> {
+        let month_n: u32 = st.get(..2)?.parse().ok()?;
+        let day: u32 = st.get(2..)?.parse().ok()?;
 
-        let month = Month::from_u32(month_n).unwrap();
-        MonthDayPair(month, day)
+        let month = Month::from_u32(month_n)?;
+        Some(MonthDayPair(month, day))
     }
 }
 
-pub fn parse_bool(v: &str) -> bool {
+impl Display for MonthDayPair {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:02}{:02}", self.0.number_from_month(), self.1)
+    }
+}
+
+// True if `st` is a non-empty run of ASCII digits.
+fn is_digits(st: &str) -> bool {
+    !st.is_empty() && st.bytes().all(|b| b.is_ascii_digit())
+}
+
+/// A Central Index Key, which EDGAR assigns to every filer. Parses with or without zero padding
+/// and displays padded to ten digits.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Serialize, Deserialize)]
+pub struct Cik(pub u64);
+
+impl Cik {
+    pub fn parse(st: &str) -> Option<Cik> {
+        if !is_digits(st) || st.len() > 10 {
+            return None;
+        }
+        Some(Cik(st.parse().ok()?))
+    }
+}
+
+impl Display for Cik {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:010}", self.0)
+    }
+}
+
+/// An accession number such as `0000950123-21-001234`: the CIK of the entity that submitted the
+/// filing, the two-digit year, and a sequence number within that year.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Serialize, Deserialize)]
+pub struct AccessionNumber {
+    pub filer_id: u64,
+    pub year: u8,
+    pub seq: u32,
+}
+
+impl AccessionNumber {
+    pub fn parse(st: &str) -> Option<AccessionNumber> {
+        let pieces: Vec<&str> = st.split('-').collect();
+        let valid = match pieces.as_slice() {
+            [filer_id, year, seq] => filer_id.len() == 10 && year.len() == 2 && seq.len() == 6,
+            _ => false,
+        };
+        if !valid || !pieces.iter().all(|piece| is_digits(piece)) {
+            return None;
+        }
+
+        Some(AccessionNumber {
+            filer_id: pieces[0].parse().ok()?,
+            year: pieces[1].parse().ok()?,
+            seq: pieces[2].parse().ok()?,
+        })
+    }
+}
+
+impl Display for AccessionNumber {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:010}-{:02}-{:06}", self.filer_id, self.year, self.seq)
+    }
+}
+
+/// An SEC file number such as `333-123456`. The prefix identifies the kind of registration and
+/// is kept as written, since `1-` and `001-` are both in use. The number may carry further
+/// suffixes, as in `333-123456-01`, and older numbers from the regional offices end in letters,
+/// as in `33-12345-NY` or `2-1234-D`.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Serialize, Deserialize)]
+pub struct FileNumber {
+    pub prefix: String,
+    pub number: String,
+}
+
+impl FileNumber {
+    pub fn parse(st: &str) -> Option<FileNumber> {
+        let (prefix, number) = st.split_once('-')?;
+        let mut groups = number.split('-');
+        let is_suffix =
+            |group: &str| !group.is_empty() && group.bytes().all(|b| b.is_ascii_alphanumeric());
+        if !is_digits(prefix) || !groups.next().is_some_and(is_digits) || !groups.all(is_suffix) {
+            return None;
+        }
+
+        Some(FileNumber {
+            prefix: prefix.to_string(),
+            number: number.to_string(),
+        })
+    }
+}
+
+impl Display for FileNumber {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-{}", self.prefix, self.number)
+    }
+}
+
+/// A Standard Industrial Classification code, displayed as four digits.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Serialize, Deserialize)]
+pub struct Sic(pub u16);
+
+impl Sic {
+    pub fn parse(st: &str) -> Option<Sic> {
+        if !is_digits(st) || st.len() > 4 {
+            return None;
+        }
+        Some(Sic(st.parse().ok()?))
+    }
+}
+
+impl Display for Sic {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:04}", self.0)
+    }
+}
+
+pub fn parse_bool(v: &str) -> Option<bool> {
     match v {
-        "N" => false,
-        "Y" => true,
-        _ => panic!("h1"),
+        "N" => Some(false),
+        "Y" => Some(true),
+        _ => None,
     }
 }
 
-pub fn parse_date(value: &str) -> NaiveDate {
-    NaiveDate::parse_from_str(value, DATE_FORMAT).unwrap()
+pub fn format_bool(value: bool) -> &'static str {
+    if value {
+        "Y"
+    } else {
+        "N"
+    }
+}
+
+pub fn parse_date(value: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(value, DATE_FORMAT).ok()
+}
+
+pub fn parse_date_time(value: &str) -> Option<NaiveDateTime> {
+    NaiveDateTime::parse_from_str(value, DATE_TIME_FORMAT).ok()
+}
+
+pub fn format_date(value: &NaiveDate) -> String {
+    value.format(DATE_FORMAT).to_string()
 }
 
-pub fn parse_date_time(value: &str) -> NaiveDateTime {
-    NaiveDateTime::parse_from_str(value, DATE_TIME_FORMAT).unwrap()
+pub fn format_date_time(value: &NaiveDateTime) -> String {
+    value.format(DATE_TIME_FORMAT).to_string()
 }
@@ -0,0 +1,94 @@
+use crate::error::{ErrorKind, ParseError, Result};
+
+// Binary documents are embedded as uuencoded text. Each line starts with a character giving the
+// number of bytes it encodes, followed by four characters for every three bytes.
+
+const LINE_LENGTH: usize = 45;
+
+fn malformed(reason: &str) -> ParseError {
+    ParseError::new(ErrorKind::MalformedBody(reason.to_string()))
+}
+
+// A zero is written as a backtick rather than a space, so that trailing whitespace on a line is
+// never significant.
+fn encode_char(value: u8) -> char {
+    if value == 0 {
+        '`'
+    } else {
+        (value + 32) as char
+    }
+}
+
+fn decode_char(ch: u8) -> Result<u8> {
+    match ch {
+        b' '..=b'`' => Ok((ch - 32) & 0x3f),
+        _ => Err(malformed("invalid character in uuencoded data")),
+    }
+}
+
+pub fn encode(filename: &str, data: &[u8]) -> String {
+    let mut output = format!("begin 644 {}\n", filename);
+    for line in data.chunks(LINE_LENGTH) {
+        output.push(encode_char(line.len() as u8));
+        for group in line.chunks(3) {
+            let b = [
+                group[0],
+                group.get(1).copied().unwrap_or(0),
+                group.get(2).copied().unwrap_or(0),
+            ];
+            output.push(encode_char(b[0] >> 2));
+            output.push(encode_char((b[0] << 4 | b[1] >> 4) & 0x3f));
+            output.push(encode_char((b[1] << 2 | b[2] >> 6) & 0x3f));
+            output.push(encode_char(b[2] & 0x3f));
+        }
+        output.push('\n');
+    }
+    output.push_str("`\nend");
+    output
+}
+
+// Returns the filename from the `begin` line and the decoded data. Lines which were truncated
+// (for example by stripping trailing spaces) are padded with zeros, and only as many bytes as the
+// length character declares are kept from each line.
+pub fn decode(st: &str) -> Result<(String, Vec<u8>)> {
+    let mut lines = st.lines();
+    let header = lines.next().unwrap_or_default();
+    let filename = header
+        .splitn(3, ' ')
+        .nth(2)
+        .filter(|filename| !filename.is_empty())
+        .ok_or_else(|| malformed("uuencoded data has no filename"))?;
+
+    let mut data = Vec::new();
+    for line in lines {
+        let line = line.as_bytes();
+        if line == b"end" {
+            break;
+        }
+        let length = match line.first() {
+            Some(&ch) => decode_char(ch)? as usize,
+            None => continue,
+        };
+        if length == 0 {
+            continue;
+        }
+
+        let mut decoded = Vec::with_capacity(length + 2);
+        for group in line[1..].chunks(4) {
+            let mut c = [0; 4];
+            for (slot, &ch) in c.iter_mut().zip(group) {
+                *slot = decode_char(ch)?;
+            }
+            decoded.push(c[0] << 2 | c[1] >> 4);
+            decoded.push(c[1] << 4 | c[2] >> 2);
+            decoded.push(c[2] << 6 | c[3]);
+            if decoded.len() >= length {
+                break;
+            }
+        }
+        decoded.resize(length, 0);
+        data.extend_from_slice(&decoded);
+    }
+
+    Ok((filename.to_string(), data))
+}
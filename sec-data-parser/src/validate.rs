@@ -0,0 +1,110 @@
+use crate::schema::Submission;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::{Display, Formatter};
+
+/// An inconsistency between a submission's header and its documents, found by
+/// `Submission::validate`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Finding {
+    /// `<PUBLIC-DOCUMENT-COUNT>` does not match the number of documents.
+    DocumentCount { declared: usize, found: usize },
+    /// More than one document has this `<SEQUENCE>`.
+    DuplicateSequence(u32),
+    /// No document has a `<SEQUENCE>` from `first` to `last` inclusive, although one with a
+    /// higher sequence does.
+    MissingSequences { first: u32, last: u32 },
+    /// More than one document has this `<FILENAME>`.
+    DuplicateFilename(String),
+    /// The primary document, the one with the lowest sequence, is not of the submission's type.
+    PrimaryDocumentType {
+        filing_type: String,
+        document_type: String,
+    },
+}
+
+impl Display for Finding {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Finding::DocumentCount { declared, found } => write!(
+                f,
+                "<PUBLIC-DOCUMENT-COUNT> is {} but there are {} documents",
+                declared, found
+            ),
+            Finding::DuplicateSequence(sequence) => {
+                write!(f, "more than one document has sequence {}", sequence)
+            }
+            Finding::MissingSequences { first, last } if first == last => {
+                write!(f, "no document has sequence {}", first)
+            }
+            Finding::MissingSequences { first, last } => {
+                write!(f, "no document has a sequence from {} to {}", first, last)
+            }
+            Finding::DuplicateFilename(filename) => {
+                write!(f, "more than one document is named {:?}", filename)
+            }
+            Finding::PrimaryDocumentType {
+                filing_type,
+                document_type,
+            } => write!(
+                f,
+                "primary document is a {:?} but the submission is a {:?}",
+                document_type, filing_type
+            ),
+        }
+    }
+}
+
+impl Submission {
+    /// Checks the header against the documents, returning every inconsistency found. An empty
+    /// list means the submission is consistent, not that it is complete.
+    pub fn validate(&self) -> Vec<Finding> {
+        let mut findings = Vec::new();
+
+        if let Some(declared) = self.public_document_count {
+            if declared != self.documents.len() {
+                findings.push(Finding::DocumentCount {
+                    declared,
+                    found: self.documents.len(),
+                });
+            }
+        }
+
+        let mut sequences: BTreeMap<u32, usize> = BTreeMap::new();
+        for document in &self.documents {
+            *sequences.entry(document.sequence).or_default() += 1;
+        }
+        let mut expected = 1;
+        for (&sequence, &count) in &sequences {
+            if sequence > expected {
+                findings.push(Finding::MissingSequences {
+                    first: expected,
+                    last: sequence - 1,
+                });
+            }
+            if count > 1 {
+                findings.push(Finding::DuplicateSequence(sequence));
+            }
+            expected = sequence.saturating_add(1);
+        }
+
+        let mut filenames: HashMap<&str, usize> = HashMap::new();
+        for filename in self.documents.iter().filter_map(|d| d.filename.as_deref()) {
+            let count = filenames.entry(filename).or_default();
+            *count += 1;
+            if *count == 2 {
+                findings.push(Finding::DuplicateFilename(filename.to_string()));
+            }
+        }
+
+        if let Some(primary) = self.documents.iter().min_by_key(|d| d.sequence) {
+            if primary.doc_type != self.filing_type {
+                findings.push(Finding::PrimaryDocumentType {
+                    filing_type: self.filing_type.clone(),
+                    document_type: primary.doc_type.clone(),
+                });
+            }
+        }
+
+        findings
+    }
+}
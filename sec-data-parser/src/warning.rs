@@ -0,0 +1,77 @@
+use crate::error::path_string;
+use crate::schema::Submission;
+use crate::span::Span;
+use crate::tag::{ContainerTag, ValueTag};
+use std::fmt::{Display, Formatter};
+
+/// Something suspicious that was tolerated rather than treated as an error.
+#[derive(Clone, Debug, PartialEq)]
+pub enum WarningKind {
+    /// A value tag this crate does not know. Its value is kept in the enclosing struct's `extra`.
+    UnknownValue(String),
+    /// A container tag this crate does not know. It is kept in the enclosing struct's
+    /// `extra_containers`.
+    UnknownContainer(String),
+    /// A known value tag in a container which does not expect it, kept like an unknown one.
+    UnexpectedValue(ValueTag),
+    /// A known container tag inside a container which does not expect it, kept like an unknown
+    /// one.
+    UnexpectedContainer(ContainerTag),
+    /// A value which could not be read, such as a malformed CIK. It is kept in the enclosing
+    /// struct's `extra`.
+    BadValue(ValueTag, String),
+    /// A container which should appear once appeared again. The last one is kept.
+    DuplicateContainer(ContainerTag),
+    /// A document marked `<FLAWED>`.
+    FlawedDocument,
+    /// `<PUBLIC-DOCUMENT-COUNT>` does not match the number of `<DOCUMENT>`s in the submission.
+    DocumentCountMismatch { declared: usize, found: usize },
+}
+
+impl Display for WarningKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WarningKind::UnknownValue(tag) => write!(f, "unknown value tag <{}>", tag),
+            WarningKind::UnknownContainer(tag) => write!(f, "unknown container tag <{}>", tag),
+            WarningKind::UnexpectedValue(tag) => write!(f, "unexpected <{}>", tag),
+            WarningKind::UnexpectedContainer(tag) => write!(f, "unexpected <{}>", tag),
+            WarningKind::BadValue(tag, value) => write!(f, "bad value {:?} for <{}>", value, tag),
+            WarningKind::DuplicateContainer(tag) => write!(f, "duplicate <{}>", tag),
+            WarningKind::FlawedDocument => write!(f, "document is marked flawed"),
+            WarningKind::DocumentCountMismatch { declared, found } => write!(
+                f,
+                "<PUBLIC-DOCUMENT-COUNT> is {} but there are {} documents",
+                declared, found
+            ),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Warning {
+    pub kind: WarningKind,
+    /// The containers enclosing the input that caused the warning, outermost first.
+    pub path: Vec<ContainerTag>,
+    /// The position in the source of the tag that caused the warning.
+    pub span: Option<Span>,
+}
+
+impl Display for Warning {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.kind)?;
+        if !self.path.is_empty() {
+            write!(f, " in {}", path_string(&self.path))?;
+        }
+        if let Some(span) = self.span {
+            write!(f, " at {}", span)?;
+        }
+        Ok(())
+    }
+}
+
+/// A parsed submission along with everything that was tolerated while parsing it.
+#[derive(Debug)]
+pub struct ParseReport {
+    pub submission: Submission,
+    pub warnings: Vec<Warning>,
+}
@@ -0,0 +1,49 @@
+use crate::document_tree::DocumentTree;
+use crate::schema::Submission;
+use crate::tag::ContainerTag;
+use std::io::{self, Write};
+
+/// Writes a tree in the tag format it was parsed from. Text blocks are written verbatim, so a
+/// tree parsed with `BodyMode::Full` is reproduced byte for byte apart from whitespace between
+/// tags.
+pub fn write_tree<W: Write>(tree: &DocumentTree<'_>, out: &mut W) -> io::Result<()> {
+    match tree {
+        DocumentTree::ContainerNode(tag, parts, _) => {
+            writeln!(out, "<{}>", tag)?;
+            for part in parts {
+                write_tree(part, out)?;
+            }
+            writeln!(out, "</{}>", tag)
+        }
+        DocumentTree::ValueNode(tag, value, _) => writeln!(out, "<{}>{}", tag, value),
+        DocumentTree::TextNode(text, _) => {
+            out.write_all(b"<TEXT>")?;
+            out.write_all(text)?;
+            out.write_all(b"</TEXT>\n")
+        }
+        DocumentTree::TextRangeNode(_, _) => Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "document body has not been loaded",
+        )),
+        DocumentTree::Empty => Ok(()),
+    }
+}
+
+/// Writes a submission as an `.nc` file. Lazy document bodies must be loaded first.
+///
+/// Typed header values are written in their canonical form rather than as they appeared in the
+/// source: CIKs and the parts of accession numbers are zero padded, SIC codes are four digits and
+/// dates use EDGAR's `YYYYMMDD`. The output parses back to an equal `Submission`, but is not
+/// byte for byte the same as a source which wrote, say, an unpadded CIK.
+pub fn write_submission<W: Write>(submission: &Submission, out: &mut W) -> io::Result<()> {
+    write_tree(
+        &DocumentTree::container(ContainerTag::Submission, submission.to_parts()),
+        out,
+    )
+}
+
+pub fn submission_to_bytes(submission: &Submission) -> io::Result<Vec<u8>> {
+    let mut bytes = Vec::new();
+    write_submission(submission, &mut bytes)?;
+    Ok(bytes)
+}
@@ -0,0 +1,138 @@
+// Helpers shared by the parsers for the XML documents embedded in submissions. Elements are
+// matched by local name, since some filers qualify them with a namespace prefix and others do not.
+
+use crate::error::{ErrorKind, ParseError, Result};
+use chrono::NaiveDate;
+use roxmltree::{Document, Node};
+
+pub fn parse(st: &str) -> Result<Document<'_>> {
+    Document::parse(st.trim()).map_err(|e| malformed(e.to_string()))
+}
+
+pub fn malformed(reason: String) -> ParseError {
+    ParseError::new(ErrorKind::MalformedXml(reason))
+}
+
+// Checks that the document is of the expected kind, returning its root element.
+pub fn root<'a, 'input>(document: &'a Document<'input>, name: &str) -> Result<Node<'a, 'input>> {
+    let root = document.root_element();
+    if root.tag_name().name() != name {
+        return Err(malformed(format!(
+            "expected <{}>, found <{}>",
+            name,
+            root.tag_name().name()
+        )));
+    }
+    Ok(root)
+}
+
+pub fn children<'a, 'input>(
+    node: Node<'a, 'input>,
+    name: &'static str,
+) -> impl Iterator<Item = Node<'a, 'input>> {
+    node.children()
+        .filter(move |child| child.is_element() && child.tag_name().name() == name)
+}
+
+pub fn child<'a, 'input>(node: Node<'a, 'input>, name: &'static str) -> Option<Node<'a, 'input>> {
+    children(node, name).next()
+}
+
+// The trimmed text of an element, or `None` if it is empty.
+pub fn text<'a>(node: Node<'a, '_>) -> Option<&'a str> {
+    node.text().map(str::trim).filter(|st| !st.is_empty())
+}
+
+pub fn child_text<'a>(node: Node<'a, '_>, name: &'static str) -> Option<&'a str> {
+    text(child(node, name)?)
+}
+
+pub fn child_string(node: Node<'_, '_>, name: &'static str) -> Option<String> {
+    child_text(node, name).map(|st| st.to_string())
+}
+
+pub fn required_text<'a>(node: Node<'a, '_>, name: &'static str) -> Result<&'a str> {
+    child_text(node, name).ok_or_else(|| malformed(format!("missing <{}>", name)))
+}
+
+// Reads an optional element with `parse`, failing if it is present but cannot be parsed.
+pub fn child_value<T>(
+    node: Node<'_, '_>,
+    name: &'static str,
+    parse: fn(&str) -> Option<T>,
+) -> Result<Option<T>> {
+    child_text(node, name)
+        .map(|st| parse(st).ok_or_else(|| bad_value(name, st)))
+        .transpose()
+}
+
+// Reads an optional attribute with `parse`, as `child_value` does for elements.
+pub fn attribute_value<T>(
+    node: Node<'_, '_>,
+    name: &'static str,
+    parse: fn(&str) -> Option<T>,
+) -> Result<Option<T>> {
+    node.attribute(name)
+        .map(str::trim)
+        .filter(|st| !st.is_empty())
+        .map(|st| {
+            parse(st).ok_or_else(|| {
+                malformed(format!(
+                    "bad value {:?} for attribute {} of <{}>",
+                    st,
+                    name,
+                    node.tag_name().name()
+                ))
+            })
+        })
+        .transpose()
+}
+
+// Reads a value from an element which may itself be missing.
+pub fn optional_value<T>(
+    node: Option<Node<'_, '_>>,
+    name: &'static str,
+    parse: fn(&str) -> Option<T>,
+) -> Result<Option<T>> {
+    match node {
+        Some(node) => child_value(node, name, parse),
+        None => Ok(None),
+    }
+}
+
+pub fn required_value<T>(
+    node: Node<'_, '_>,
+    name: &'static str,
+    parse: fn(&str) -> Option<T>,
+) -> Result<T> {
+    let st = required_text(node, name)?;
+    parse(st).ok_or_else(|| bad_value(name, st))
+}
+
+pub fn bad_value(name: &str, value: &str) -> ParseError {
+    malformed(format!("bad value {:?} for <{}>", value, name))
+}
+
+// Dates are usually `YYYY-MM-DD`, sometimes with a UTC offset appended, but 13F cover pages use
+// `MM-DD-YYYY`, whose month and day are not always padded.
+pub fn parse_date(st: &str) -> Option<NaiveDate> {
+    st.get(..10)
+        .and_then(|date| NaiveDate::parse_from_str(date, "%Y-%m-%d").ok())
+        .or_else(|| NaiveDate::parse_from_str(st, "%m-%d-%Y").ok())
+}
+
+pub fn parse_bool(st: &str) -> Option<bool> {
+    match st {
+        "1" | "true" | "Y" | "y" => Some(true),
+        "0" | "false" | "N" | "n" => Some(false),
+        _ => None,
+    }
+}
+
+pub fn parse_number(st: &str) -> Option<f64> {
+    st.replace(',', "").parse().ok()
+}
+
+pub fn parse_integer(st: &str) -> Option<u64> {
+    st.replace(',', "").parse().ok()
+}
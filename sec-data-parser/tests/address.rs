@@ -0,0 +1,91 @@
+use sec_data_parser::*;
+
+fn address(state: &str, zip: &str, phone: &str) -> Address {
+    Address {
+        street1: Some("1 MAIN ST".to_string()),
+        street2: None,
+        city: Some("SPRINGFIELD".to_string()),
+        state: Some(state.to_string()),
+        zip: Some(zip.to_string()),
+        phone: Some(phone.to_string()),
+        extra: Vec::new(),
+        extra_containers: Vec::new(),
+    }
+}
+
+#[test]
+fn us_address() {
+    let normalized = address("NY", "10001-2345", "(212) 555-0100").normalized();
+
+    assert_eq!(normalized.state, Some(State::NewYork));
+    assert_eq!(
+        normalized.zip,
+        Some(Zip {
+            base: "10001".to_string(),
+            plus_four: Some("2345".to_string())
+        })
+    );
+    assert_eq!(normalized.phone.as_deref(), Some("+12125550100"));
+    assert!(!normalized.foreign);
+}
+
+#[test]
+fn foreign_address() {
+    let normalized = address("X0", "EC2V 7HN", "44 20 7946 0000").normalized();
+
+    assert_eq!(normalized.state, Some(State::Foreign("X0".to_string())));
+    assert_eq!(normalized.zip, None);
+    assert_eq!(normalized.phone, None);
+    assert!(normalized.foreign);
+
+    let normalized = address("E9", "KY1-1104", "+1 345 949 0000").normalized();
+    assert_eq!(normalized.phone.as_deref(), Some("+13459490000"));
+}
+
+#[test]
+fn zip_forms() {
+    assert_eq!(Zip::parse("123456789").unwrap().to_string(), "12345-6789");
+    assert_eq!(Zip::parse("02134").unwrap().plus_four, None);
+    assert_eq!(Zip::parse("1234"), None);
+    assert_eq!(Zip::parse("12345-67"), None);
+}
+
+#[test]
+fn phone_extensions_and_prefixes() {
+    let phone = |raw: &str| address("CA", "94105", raw).normalized().phone;
+
+    assert_eq!(
+        phone("415-555-0100 EXT 12").as_deref(),
+        Some("+14155550100")
+    );
+    assert_eq!(phone("1-415-555-0100").as_deref(), Some("+14155550100"));
+    assert_eq!(
+        phone("011 44 20 7946 0000").as_deref(),
+        Some("+442079460000")
+    );
+    assert_eq!(phone("555-0100"), None);
+}
+
+#[test]
+fn unrecognized_state_is_none() {
+    let normalized = address("ON", "M5H 2N2", "416-555-0100").normalized();
+
+    assert_eq!(normalized.state, None);
+    assert!(!normalized.foreign);
+    assert_eq!(State::parse("A6"), Some(State::Foreign("A6".to_string())));
+    assert_eq!(State::parse("Z9"), None);
+    assert_eq!(State::PuertoRico.code(), "PR");
+}
+
+#[test]
+fn us_territory_codes_are_not_foreign() {
+    let normalized = address("2J", "96898", "808-555-0100").normalized();
+
+    assert_eq!(
+        normalized.state,
+        Some(State::UnitedStatesMinorOutlyingIslands)
+    );
+    assert!(!normalized.foreign);
+    assert_eq!(normalized.zip.unwrap().base, "96898");
+    assert_eq!(normalized.phone.as_deref(), Some("+18085550100"));
+}
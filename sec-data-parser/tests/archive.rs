@@ -0,0 +1,53 @@
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use sec_data_parser::*;
+use tar::{Builder, EntryType, Header};
+
+const GOOD: &str = "<SUBMISSION>
+<ACCESSION-NUMBER>0000950123-21-001234
+<TYPE>8-K
+<FILING-DATE>20210115
+</SUBMISSION>
+";
+
+fn append(builder: &mut Builder<GzEncoder<Vec<u8>>>, path: &str, kind: EntryType, data: &[u8]) {
+    let mut header = Header::new_gnu();
+    header.set_entry_type(kind);
+    header.set_size(data.len() as u64);
+    header.set_mode(0o644);
+    builder.append_data(&mut header, path, data).unwrap();
+}
+
+fn feed() -> Vec<u8> {
+    let mut builder = Builder::new(GzEncoder::new(Vec::new(), Compression::default()));
+    append(&mut builder, "20210115", EntryType::Directory, b"");
+    append(
+        &mut builder,
+        "20210115/bad.nc",
+        EntryType::Regular,
+        b"<SUBMISSION>\n<TYPE>8-K\n",
+    );
+    append(
+        &mut builder,
+        "20210115/good.nc",
+        EntryType::Regular,
+        GOOD.as_bytes(),
+    );
+    builder.into_inner().unwrap().finish().unwrap()
+}
+
+#[test]
+fn entries_are_parsed_independently() {
+    let bytes = feed();
+    let mut archive = FeedArchive::new(bytes.as_slice());
+    let entries: Vec<(String, Result<Submission>)> = archive.submissions().unwrap().collect();
+
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[0].0, "20210115/bad.nc");
+    assert!(entries[0].1.is_err());
+    assert_eq!(entries[1].0, "20210115/good.nc");
+    assert_eq!(
+        entries[1].1.as_ref().unwrap(),
+        &parse_submission_from_str(GOOD).unwrap()
+    );
+}
@@ -0,0 +1,305 @@
+use sec_data_parser::beneficial_ownership::*;
+use sec_data_parser::*;
+
+fn submission(form: &str, filename: &str, body: &str) -> String {
+    format!(
+        "<SUBMISSION>
+<ACCESSION-NUMBER>0000950123-21-001234
+<TYPE>{form}
+<PUBLIC-DOCUMENT-COUNT>1
+<FILING-DATE>20210212
+<SUBJECT-COMPANY>
+<COMPANY-DATA>
+<CONFORMED-NAME>EXAMPLE THERAPEUTICS INC
+<CIK>0001600001
+</COMPANY-DATA>
+</SUBJECT-COMPANY>
+<FILED-BY>
+<COMPANY-DATA>
+<CONFORMED-NAME>EXAMPLE CAPITAL PARTNERS LP
+<CIK>0001500001
+</COMPANY-DATA>
+</FILED-BY>
+<DOCUMENT>
+<TYPE>{form}
+<SEQUENCE>1
+<FILENAME>{filename}
+<TEXT>
+{body}
+</TEXT>
+</DOCUMENT>
+</SUBMISSION>
+"
+    )
+}
+
+const TEXT_13G: &str = "                               SCHEDULE 13G
+
+                    Under the Securities Exchange Act of 1934
+                              (Amendment No. 2)*
+
+                            Example Therapeutics, Inc.
+                                (Name of Issuer)
+
+                     Common Stock, par value $0.001 per share
+                         (Title of Class of Securities)
+
+                                   30161Q104
+                                 (CUSIP Number)
+
+CUSIP No. 30161Q104                   13G                    Page 2 of 6 Pages
+-------------------------------------------------------------------------------
+  1.  NAMES OF REPORTING PERSONS
+      I.R.S. IDENTIFICATION NOS. OF ABOVE PERSONS (ENTITIES ONLY)
+
+      Example Capital Partners LP
+-------------------------------------------------------------------------------
+  2.  CHECK THE APPROPRIATE BOX IF A MEMBER OF A GROUP (SEE INSTRUCTIONS)
+      (a) [ ]
+      (b) [X]
+-------------------------------------------------------------------------------
+  3.  SEC USE ONLY
+-------------------------------------------------------------------------------
+  4.  CITIZENSHIP OR PLACE OF ORGANIZATION
+
+      Delaware
+-------------------------------------------------------------------------------
+               5.  SOLE VOTING POWER
+  NUMBER OF
+   SHARES          -0-
+BENEFICIALLY  -----------------------------------------------------------------
+  OWNED BY     6.  SHARED VOTING POWER
+    EACH
+  REPORTING        2,500,000 (1)
+   PERSON     -----------------------------------------------------------------
+    WITH       7.  SOLE DISPOSITIVE POWER
+
+                   -0-
+              -----------------------------------------------------------------
+               8.  SHARED DISPOSITIVE POWER
+
+                   2,500,000 (1)
+-------------------------------------------------------------------------------
+  9.  AGGREGATE AMOUNT BENEFICIALLY OWNED BY EACH REPORTING PERSON
+
+      2,500,000 (1)
+-------------------------------------------------------------------------------
+ 10.  CHECK BOX IF THE AGGREGATE AMOUNT IN ROW (9) EXCLUDES CERTAIN SHARES
+      (SEE INSTRUCTIONS)                                                  [ ]
+-------------------------------------------------------------------------------
+ 11.  PERCENT OF CLASS REPRESENTED BY AMOUNT IN ROW (9)
+
+      7.4% (2)
+-------------------------------------------------------------------------------
+ 12.  TYPE OF REPORTING PERSON (SEE INSTRUCTIONS)
+
+      PN
+-------------------------------------------------------------------------------
+
+CUSIP No. 30161Q104                   13G                    Page 3 of 6 Pages
+-------------------------------------------------------------------------------
+  1.  NAMES OF REPORTING PERSONS
+      I.R.S. IDENTIFICATION NOS. OF ABOVE PERSONS (ENTITIES ONLY)
+
+      John Q. Example
+-------------------------------------------------------------------------------
+  2.  CHECK THE APPROPRIATE BOX IF A MEMBER OF A GROUP (SEE INSTRUCTIONS)
+-------------------------------------------------------------------------------
+  3.  SEC USE ONLY
+-------------------------------------------------------------------------------
+  4.  CITIZENSHIP OR PLACE OF ORGANIZATION
+
+      United States
+-------------------------------------------------------------------------------
+               5.  SOLE VOTING POWER
+                   15,000
+               6.  SHARED VOTING POWER
+                   2,500,000
+               7.  SOLE DISPOSITIVE POWER
+                   15,000
+               8.  SHARED DISPOSITIVE POWER
+                   2,500,000
+  9.  AGGREGATE AMOUNT BENEFICIALLY OWNED BY EACH REPORTING PERSON
+      2,515,000
+ 10.  CHECK BOX IF THE AGGREGATE AMOUNT IN ROW (9) EXCLUDES CERTAIN SHARES [ ]
+ 11.  PERCENT OF CLASS REPRESENTED BY AMOUNT IN ROW (9)
+      7.5%
+ 12.  TYPE OF REPORTING PERSON (SEE INSTRUCTIONS)
+      IN
+
+Item 1(a).  Name of Issuer:
+            Example Therapeutics, Inc.";
+
+#[test]
+fn text_cover_pages() {
+    let submission =
+        parse_submission_from_str(&submission("SC 13G/A", "d123.txt", TEXT_13G)).unwrap();
+    let cover_pages = submission.cover_pages().unwrap().unwrap();
+
+    assert_eq!(cover_pages.cusip.as_deref(), Some("30161Q104"));
+    assert_eq!(cover_pages.reporting_persons.len(), 2);
+    assert_eq!(
+        cover_pages.reporting_persons[0],
+        CoverPage {
+            name: Some("Example Capital Partners LP".to_string()),
+            cik: None,
+            citizenship: Some("Delaware".to_string()),
+            sole_voting_power: Some(0.0),
+            shared_voting_power: Some(2_500_000.0),
+            sole_dispositive_power: Some(0.0),
+            shared_dispositive_power: Some(2_500_000.0),
+            aggregate_amount_owned: Some(2_500_000.0),
+            percent_of_class: Some(7.4),
+            type_of_reporting_person: Some("PN".to_string()),
+        }
+    );
+
+    let person = &cover_pages.reporting_persons[1];
+    assert_eq!(person.name.as_deref(), Some("John Q. Example"));
+    assert_eq!(person.citizenship.as_deref(), Some("United States"));
+    assert_eq!(person.sole_voting_power, Some(15_000.0));
+    assert_eq!(person.aggregate_amount_owned, Some(2_515_000.0));
+    assert_eq!(person.percent_of_class, Some(7.5));
+    assert_eq!(person.type_of_reporting_person.as_deref(), Some("IN"));
+}
+
+#[test]
+fn html_cover_page() {
+    let html = "<html><body>
+<p style=\"text-align:center\"><b>SCHEDULE 13D</b></p>
+<p>CUSIP No.&nbsp;30161Q104</p>
+<table>
+<tr><td>1</td><td>NAME OF REPORTING PERSON<br>Example Activist Fund&nbsp;L.P.</td></tr>
+<tr><td>2</td><td>CHECK THE APPROPRIATE BOX IF A MEMBER OF A GROUP<br>(a)&nbsp;&#9744; (b)&nbsp;&#9746;</td></tr>
+<tr><td>3</td><td>SEC USE ONLY</td></tr>
+<tr><td>4</td><td>SOURCE OF FUNDS<br>WC</td></tr>
+<tr><td>5</td><td>CHECK BOX IF DISCLOSURE OF LEGAL PROCEEDINGS IS REQUIRED PURSUANT TO ITEMS 2(d) or 2(e)</td></tr>
+<tr><td>6</td><td>CITIZENSHIP OR PLACE OF ORGANIZATION<br>Cayman Islands</td></tr>
+<tr><td rowspan=\"4\">NUMBER OF SHARES BENEFICIALLY OWNED BY EACH REPORTING PERSON WITH</td>
+<td>7</td><td>SOLE VOTING POWER<br>1,250,000</td></tr>
+<tr><td>8</td><td>SHARED VOTING POWER<br>0</td></tr>
+<tr><td>9</td><td>SOLE DISPOSITIVE POWER<br>1,250,000</td></tr>
+<tr><td>10</td><td>SHARED DISPOSITIVE POWER<br>0</td></tr>
+<tr><td>11</td><td>AGGREGATE AMOUNT BENEFICIALLY OWNED BY EACH REPORTING PERSON<br>1,250,000</td></tr>
+<tr><td>12</td><td>CHECK BOX IF THE AGGREGATE AMOUNT IN ROW (11) EXCLUDES CERTAIN SHARES</td></tr>
+<tr><td>13</td><td>PERCENT OF CLASS REPRESENTED BY AMOUNT IN ROW (11)<br>5.2%*</td></tr>
+<tr><td>14</td><td>TYPE OF REPORTING PERSON<br>PN</td></tr>
+</table>
+</body></html>";
+    let cover_pages = parse_text(html);
+
+    assert_eq!(cover_pages.cusip.as_deref(), Some("30161Q104"));
+    assert_eq!(cover_pages.reporting_persons.len(), 1);
+    let person = &cover_pages.reporting_persons[0];
+    assert_eq!(person.name.as_deref(), Some("Example Activist Fund L.P."));
+    assert_eq!(person.citizenship.as_deref(), Some("Cayman Islands"));
+    assert_eq!(person.sole_voting_power, Some(1_250_000.0));
+    assert_eq!(person.shared_voting_power, Some(0.0));
+    assert_eq!(person.sole_dispositive_power, Some(1_250_000.0));
+    assert_eq!(person.shared_dispositive_power, Some(0.0));
+    assert_eq!(person.aggregate_amount_owned, Some(1_250_000.0));
+    assert_eq!(person.percent_of_class, Some(5.2));
+    assert_eq!(person.type_of_reporting_person.as_deref(), Some("PN"));
+}
+
+#[test]
+fn xml_schedule_13d() {
+    let xml = "<XML>
+<?xml version=\"1.0\" encoding=\"UTF-8\"?>
+<edgarSubmission xmlns=\"http://www.sec.gov/edgar/schedule13D\">
+  <headerData>
+    <submissionType>SCHEDULE 13D</submissionType>
+  </headerData>
+  <formData>
+    <coverPageHeader>
+      <securitiesClassTitle>Common Stock</securitiesClassTitle>
+      <issuerInfo>
+        <issuerCIK>0001600001</issuerCIK>
+        <issuerCUSIP>30161Q104</issuerCUSIP>
+        <issuerName>Example Therapeutics, Inc.</issuerName>
+      </issuerInfo>
+    </coverPageHeader>
+    <reportingPersons>
+      <reportingPersonInfo>
+        <reportingPersonCIK>0001500001</reportingPersonCIK>
+        <reportingPersonName>Example Activist Fund L.P.</reportingPersonName>
+        <memberOfGroup>b</memberOfGroup>
+        <fundType>WC</fundType>
+        <citizenshipOrOrganization>E9</citizenshipOrOrganization>
+        <soleVotingPower>1250000.00</soleVotingPower>
+        <sharedVotingPower>0.00</sharedVotingPower>
+        <soleDispositivePower>1250000.00</soleDispositivePower>
+        <sharedDispositivePower>0.00</sharedDispositivePower>
+        <aggregateAmountOwned>1250000.00</aggregateAmountOwned>
+        <isAggregateExcludeShares>N</isAggregateExcludeShares>
+        <percentOfClass>5.2</percentOfClass>
+        <typeOfReportingPerson>PN</typeOfReportingPerson>
+      </reportingPersonInfo>
+    </reportingPersons>
+  </formData>
+</edgarSubmission>
+</XML>";
+    let submission =
+        parse_submission_from_str(&submission("SCHEDULE 13D", "primary_doc.xml", xml)).unwrap();
+    let cover_pages = submission.cover_pages().unwrap().unwrap();
+
+    assert_eq!(cover_pages.cusip.as_deref(), Some("30161Q104"));
+    assert_eq!(
+        cover_pages.reporting_persons,
+        vec![CoverPage {
+            name: Some("Example Activist Fund L.P.".to_string()),
+            cik: Some(Cik(1500001)),
+            citizenship: Some("E9".to_string()),
+            sole_voting_power: Some(1_250_000.0),
+            shared_voting_power: Some(0.0),
+            sole_dispositive_power: Some(1_250_000.0),
+            shared_dispositive_power: Some(0.0),
+            aggregate_amount_owned: Some(1_250_000.0),
+            percent_of_class: Some(5.2),
+            type_of_reporting_person: Some("PN".to_string()),
+        }]
+    );
+}
+
+#[test]
+fn xml_schedule_13g() {
+    let cover_pages = parse_xml(
+        "<edgarSubmission xmlns=\"http://www.sec.gov/edgar/schedule13g\">
+  <formData>
+    <coverPageHeader>
+      <issuerInfo>
+        <issuerCusip>30161Q104</issuerCusip>
+      </issuerInfo>
+    </coverPageHeader>
+    <coverPageHeaderReportingPersonDetails>
+      <reportingPersonName>Example Capital Partners LP</reportingPersonName>
+      <citizenshipOrOrganization>DE</citizenshipOrOrganization>
+      <reportingPersonBeneficiallyOwnedNumberOfShares>
+        <soleVotingPower>0</soleVotingPower>
+        <sharedVotingPower>2500000</sharedVotingPower>
+        <soleDispositivePower>0</soleDispositivePower>
+        <sharedDispositivePower>2500000</sharedDispositivePower>
+      </reportingPersonBeneficiallyOwnedNumberOfShares>
+      <reportingPersonBeneficiallyOwnedAggregateNumberOfShares>2500000</reportingPersonBeneficiallyOwnedAggregateNumberOfShares>
+      <classPercent>7.4</classPercent>
+      <typeOfReportingPerson>PN</typeOfReportingPerson>
+    </coverPageHeaderReportingPersonDetails>
+  </formData>
+</edgarSubmission>",
+    )
+    .unwrap();
+
+    assert_eq!(cover_pages.cusip.as_deref(), Some("30161Q104"));
+    let person = &cover_pages.reporting_persons[0];
+    assert_eq!(person.shared_voting_power, Some(2_500_000.0));
+    assert_eq!(person.aggregate_amount_owned, Some(2_500_000.0));
+    assert_eq!(person.percent_of_class, Some(7.4));
+}
+
+#[test]
+fn other_forms_have_no_cover_pages() {
+    let submission =
+        parse_submission_from_str(&submission("8-K", "d123.txt", "Item 8.01")).unwrap();
+
+    assert!(submission.cover_pages().is_none());
+}
@@ -0,0 +1,92 @@
+use sec_data_parser::*;
+use std::path::{Path, PathBuf};
+
+fn fixture(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures")
+        .join(name)
+}
+
+fn with_bodies(bodies: BodyMode) -> ParseOptions {
+    ParseOptions {
+        bodies,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn skipped_bodies_are_none() {
+    for name in ["8-k.nc", "uuencoded.nc"] {
+        let path = fixture(name);
+        let full = with_bodies(BodyMode::Full).parse_path(&path).unwrap();
+        let skipped = with_bodies(BodyMode::Skip).parse_path(&path).unwrap();
+
+        assert_eq!(skipped.documents.len(), full.documents.len());
+        for (skipped, full) in skipped.documents.iter().zip(&full.documents) {
+            assert_eq!(skipped.body, None);
+            assert_eq!(skipped.doc_type, full.doc_type);
+            assert_eq!(skipped.filename, full.filename);
+        }
+    }
+}
+
+#[test]
+fn lazy_bodies_are_ranges_of_the_source() {
+    let path = fixture("uuencoded.nc");
+    let source = std::fs::read(&path).unwrap();
+    let lazy = with_bodies(BodyMode::Lazy).parse_path(&path).unwrap();
+    let borrowed = with_bodies(BodyMode::Lazy).parse_bytes(&source).unwrap();
+
+    assert_eq!(lazy, borrowed);
+    let range = match &lazy.documents[1].body {
+        Some(Body::Lazy(range)) => *range,
+        body => panic!("expected a lazy body, found {:?}", body),
+    };
+    let text = range.slice(&source).unwrap();
+    assert!(String::from_utf8_lossy(text)
+        .trim()
+        .starts_with("begin 644 logo.gif"));
+}
+
+#[test]
+fn loaded_lazy_bodies_match_full() {
+    for name in ["8-k.nc", "uuencoded.nc"] {
+        let path = fixture(name);
+        let source = std::fs::read(&path).unwrap();
+        let full = with_bodies(BodyMode::Full).parse_path(&path).unwrap();
+        let lazy = with_bodies(BodyMode::Lazy).parse_path(&path).unwrap();
+
+        for (lazy, full) in lazy.documents.iter().zip(&full.documents) {
+            let expected = match &full.body {
+                Some(Body::Loaded(typed_data)) => typed_data,
+                body => panic!("expected a loaded body, found {:?}", body),
+            };
+            let body = lazy.body.as_ref().unwrap();
+
+            let from_slice = body
+                .load_from_slice(&source, DecodingPolicy::default())
+                .unwrap();
+            assert_eq!(from_slice.as_ref(), expected);
+
+            let mut file = std::fs::File::open(&path).unwrap();
+            let from_reader = body
+                .load_from_reader(&mut file, DecodingPolicy::default())
+                .unwrap();
+            assert_eq!(from_reader.as_ref(), expected);
+        }
+    }
+
+    let full = with_bodies(BodyMode::Full)
+        .parse_path(&fixture("uuencoded.nc"))
+        .unwrap();
+    match &full.documents[1].body {
+        Some(Body::Loaded(TypedData {
+            body: DocumentBody::BinaryData(filename, data),
+            ..
+        })) => {
+            assert_eq!(filename, "logo.gif");
+            assert!(data.ends_with(b"GIF89a trailing bytes"));
+        }
+        body => panic!("expected binary data, found {:?}", body),
+    }
+}
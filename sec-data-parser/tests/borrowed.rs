@@ -0,0 +1,62 @@
+use chrono::NaiveDate;
+use sec_data_parser::*;
+use std::fs::File;
+use std::path::{Path, PathBuf};
+
+fn fixtures() -> Vec<PathBuf> {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures");
+    let mut paths: Vec<PathBuf> = std::fs::read_dir(dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .collect();
+    paths.sort();
+    paths
+}
+
+#[test]
+fn borrowed_matches_streamed() {
+    for path in fixtures() {
+        let bytes = std::fs::read(&path).unwrap();
+        let options = ParseOptions::default();
+
+        let borrowed = options.parse_borrowed(&bytes).unwrap().to_owned().unwrap();
+        let streamed = options.parse_reader(File::open(&path).unwrap()).unwrap();
+
+        assert_eq!(borrowed, streamed, "{}", path.display());
+        assert_eq!(options.parse_bytes(&bytes).unwrap(), streamed);
+    }
+}
+
+#[test]
+fn header_accessors() {
+    let bytes =
+        std::fs::read(Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/8-k.nc")).unwrap();
+    let submission = parse_submission_borrowed(&bytes).unwrap();
+
+    assert_eq!(submission.accession_number(), Some("0000950123-21-001234"));
+    assert_eq!(submission.filing_type(), Some("8-K"));
+    assert_eq!(
+        submission.filing_date(),
+        NaiveDate::from_ymd_opt(2021, 1, 15)
+    );
+    assert_eq!(
+        submission
+            .root()
+            .values(ValueTag::Items)
+            .collect::<Vec<_>>(),
+        vec!["2.02", "9.01"]
+    );
+
+    let filer = submission.filers().next().unwrap();
+    let company_data = filer.container(ContainerTag::CompanyData).unwrap();
+    assert_eq!(
+        company_data.value(ValueTag::ConformedName),
+        Some("ACME CORP")
+    );
+    assert_eq!(
+        filer
+            .container(ContainerTag::BusinessAddress)
+            .and_then(|address| address.value(ValueTag::City)),
+        Some("SPRINGFIELD")
+    );
+}
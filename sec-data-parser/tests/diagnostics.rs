@@ -0,0 +1,105 @@
+use sec_data_parser::*;
+
+const SUBMISSION: &str = "<SUBMISSION>
+<ACCESSION-NUMBER>0000950123-21-001234
+<TYPE>8-K
+<FILING-DATE>20210115
+<FILER>
+<COMPANY-DATA>
+<CONFORMED-NAME>ACME CORP
+<CIK>0000012345
+  <FISCAL-YEAR-END>1399
+</COMPANY-DATA>
+</FILER>
+</SUBMISSION>
+";
+
+#[test]
+fn errors_carry_span_and_path() {
+    let error = parse_submission_from_str(SUBMISSION).unwrap_err();
+
+    assert_eq!(
+        error.span,
+        Some(Span {
+            offset: 151,
+            line: 9,
+            column: 3
+        })
+    );
+    assert_eq!(
+        error.path,
+        vec![
+            ContainerTag::Submission,
+            ContainerTag::Filer,
+            ContainerTag::CompanyData
+        ]
+    );
+    assert_eq!(&SUBMISSION[151..168], "<FISCAL-YEAR-END>");
+}
+
+#[test]
+fn streaming_reports_the_same_span() {
+    let from_str = parse_submission_from_str(SUBMISSION).unwrap_err();
+    let from_reader = ParseOptions::default()
+        .parse_reader(SUBMISSION.as_bytes())
+        .unwrap_err();
+
+    assert_eq!(from_reader.span, from_str.span);
+    assert_eq!(from_reader.path, from_str.path);
+}
+
+#[test]
+fn render_points_at_the_tag() {
+    let error = parse_submission_from_str(SUBMISSION).unwrap_err();
+
+    assert_eq!(
+        error.render(SUBMISSION.as_bytes()),
+        "error: bad value \"1399\" for <FISCAL-YEAR-END>
+ --> line 9, column 3
+  |
+9 |   <FISCAL-YEAR-END>1399
+  |   ^^^^^^^^^^^^^^^^^
+  = in SUBMISSION > FILER > COMPANY-DATA
+  = accession number 0000950123-21-001234
+"
+    );
+}
+
+#[test]
+fn empty_input_has_no_span() {
+    for error in [
+        parse_submission_from_str("").unwrap_err(),
+        ParseOptions::default().parse_reader(&b""[..]).unwrap_err(),
+    ] {
+        assert!(matches!(
+            error.kind,
+            ErrorKind::MissingContainer(ContainerTag::Submission)
+        ));
+        assert_eq!(error.span, None);
+    }
+}
+
+#[test]
+fn unclosed_submission_has_span_and_path() {
+    let source = "\n<SUBMISSION>\n";
+    let from_str = parse_submission_from_str(source).unwrap_err();
+    let from_reader = ParseOptions::default()
+        .parse_reader(source.as_bytes())
+        .unwrap_err();
+
+    for error in [from_str, from_reader] {
+        assert!(matches!(
+            error.kind,
+            ErrorKind::UnexpectedEndOfInput(ContainerTag::Submission)
+        ));
+        assert_eq!(
+            error.span,
+            Some(Span {
+                offset: 1,
+                line: 2,
+                column: 1
+            })
+        );
+        assert_eq!(error.path, vec![ContainerTag::Submission]);
+    }
+}
@@ -0,0 +1,52 @@
+<SUBMISSION>
+<ACCESSION-NUMBER>0000950123-21-001234
+<TYPE>8-K
+<PUBLIC-DOCUMENT-COUNT>2
+<ITEMS>2.02
+<ITEMS>9.01
+<PERIOD>20210115
+<FILING-DATE>20210115
+<DATE-OF-FILING-DATE-CHANGE>20210115
+<FILER>
+<COMPANY-DATA>
+<CONFORMED-NAME>ACME CORP
+<CIK>0000012345
+<ASSIGNED-SIC>3571
+<IRS-NUMBER>123456789
+<STATE-OF-INCORPORATION>DE
+<FISCAL-YEAR-END>1231
+</COMPANY-DATA>
+<FILING-VALUES>
+<FORM-TYPE>8-K
+<ACT>34
+<FILE-NUMBER>001-12345
+<FILM-NUMBER>21530000
+</FILING-VALUES>
+<BUSINESS-ADDRESS>
+<STREET1>1 MAIN ST
+<CITY>SPRINGFIELD
+<STATE>IL
+<ZIP>62701
+<PHONE>217-555-0100
+</BUSINESS-ADDRESS>
+</FILER>
+<DOCUMENT>
+<TYPE>8-K
+<SEQUENCE>1
+<FILENAME>d8k.htm
+<DESCRIPTION>FORM 8-K
+<TEXT>
+<html>Hello</html>
+</TEXT>
+</DOCUMENT>
+<DOCUMENT>
+<TYPE>EX-99.1
+<SEQUENCE>2
+<FILENAME>ex99.xml
+<TEXT>
+<XML>
+<a>b</a>
+</XML>
+</TEXT>
+</DOCUMENT>
+</SUBMISSION>
@@ -0,0 +1,43 @@
+<SUBMISSION>
+<ACCESSION-NUMBER>0000950123-21-005678
+<TYPE>10-K
+<PUBLIC-DOCUMENT-COUNT>2
+<PERIOD>20201231
+<FILING-DATE>20210301
+<FILER>
+<COMPANY-DATA>
+<CONFORMED-NAME>ACME CORP
+<CIK>0000012345
+</COMPANY-DATA>
+</FILER>
+<DOCUMENT>
+<TYPE>10-K
+<SEQUENCE>1
+<FILENAME>d10k.txt
+<TEXT>
+ANNUAL REPORT
+</TEXT>
+</DOCUMENT>
+<DOCUMENT>
+<TYPE>GRAPHIC
+<SEQUENCE>2
+<FILENAME>logo.gif
+<TEXT>
+begin 644 logo.gif
+M``$"`P0%!@<("0H+#`T.#Q`1$A,4%187&!D:&QP='A\@(2(C)"4F)R@I*BLL
+M+2XO,#$R,S0U-C<X.3H[/#T^/T!!0D-$149'2$E*2TQ-3D]045)35%565UA9
+M6EM<75Y?8&%B8V1E9F=H:6IK;&UN;W!Q<G-T=79W>'EZ>WQ]?G^`@8*#A(6&
+MAXB)BHN,C8Z/D)&2DY25EI>8F9J;G)V>GZ"AHJ.DI::GJ*FJJZRMKJ^PL;*S
+MM+6VM[BYNKN\O;Z_P,'"P\3%QL?(R<K+S,W.S]#1TM/4U=;7V-G:V]S=WM_@
+MX>+CY.7FY^CIZNOL[>[O\/'R\_3U]O?X^?K[_/W^_P`!`@,$!08'"`D*"PP-
+M#@\0$1(3%!46%Q@9&AL<'1X?("$B(R0E)B<H*2HK+"TN+S`Q,C,T-38W.#DZ
+M.SP]/C]`04)#1$5&1TA)2DM,34Y/4%%24U155E=865I;7%U>7V!A8F-D969G
+M:&EJ:VQM;F]P<7)S='5V=WAY>GM\?7Y_@(&"@X2%AH>(B8J+C(V.CY"1DI.4
+ME9:7F)F:FYR=GI^@H:*CI*6FIZBIJJNLK:ZOL+&RL[2UMK>XN;J[O+V^O\#!
+MPL/$Q<;'R,G*R\S-SL_0T=+3U-76U]C9VMO<W=[?X.'BX^3EYN?HZ>KK[.WN
+F[_#Q\O/T]?;W^/GZ^_S]_O]'248X.6$@=')A:6QI;F<@8GET97,`
+`
+end
+</TEXT>
+</DOCUMENT>
+</SUBMISSION>
@@ -0,0 +1,220 @@
+use chrono::NaiveDate;
+use sec_data_parser::form_d::*;
+use sec_data_parser::*;
+
+const FORM_D: &str = "<SUBMISSION>
+<ACCESSION-NUMBER>0001765432-21-000001
+<TYPE>D
+<PUBLIC-DOCUMENT-COUNT>1
+<FILING-DATE>20210201
+<FILER>
+<COMPANY-DATA>
+<CONFORMED-NAME>Example Opportunity Fund LP
+<CIK>0001765432
+</COMPANY-DATA>
+</FILER>
+<DOCUMENT>
+<TYPE>D
+<SEQUENCE>1
+<FILENAME>primary_doc.xml
+<TEXT>
+<XML>
+<?xml version=\"1.0\"?>
+<edgarSubmission>
+  <schemaVersion>X0708</schemaVersion>
+  <submissionType>D</submissionType>
+  <testOrLive>LIVE</testOrLive>
+  <primaryIssuer>
+    <cik>0001765432</cik>
+    <entityName>Example Opportunity Fund LP</entityName>
+    <issuerAddress>
+      <street1>100 Park Ave</street1>
+      <city>New York</city>
+      <stateOrCountry>NY</stateOrCountry>
+      <stateOrCountryDescription>NEW YORK</stateOrCountryDescription>
+      <zipCode>10017</zipCode>
+    </issuerAddress>
+    <issuerPhoneNumber>212-555-0100</issuerPhoneNumber>
+    <jurisdictionOfInc>DELAWARE</jurisdictionOfInc>
+    <entityType>Limited Partnership</entityType>
+    <yearOfInc>
+      <withinFiveYears>true</withinFiveYears>
+      <value>2019</value>
+    </yearOfInc>
+  </primaryIssuer>
+  <relatedPersonsList>
+    <relatedPersonInfo>
+      <relatedPersonName>
+        <firstName>Jane</firstName>
+        <lastName>Doe</lastName>
+      </relatedPersonName>
+      <relatedPersonRelationshipList>
+        <relationship>Executive Officer</relationship>
+        <relationship>Promoter</relationship>
+      </relatedPersonRelationshipList>
+      <relationshipClarification>Managing member of the general partner</relationshipClarification>
+    </relatedPersonInfo>
+  </relatedPersonsList>
+  <offeringData>
+    <industryGroup>
+      <industryGroupType>Pooled Investment Fund</industryGroupType>
+      <investmentFundInfo>
+        <investmentFundType>Hedge Fund</investmentFundType>
+        <is40Act>false</is40Act>
+      </investmentFundInfo>
+    </industryGroup>
+    <issuerSize>
+      <aggregateNetAssetValueRange>Decline to Disclose</aggregateNetAssetValueRange>
+    </issuerSize>
+    <federalExemptionsExclusions>
+      <item>06b</item>
+      <item>3C</item>
+      <item>3C.7</item>
+    </federalExemptionsExclusions>
+    <typeOfFiling>
+      <newOrAmendment>
+        <isAmendment>false</isAmendment>
+      </newOrAmendment>
+      <dateOfFirstSale>
+        <value>2021-01-04</value>
+      </dateOfFirstSale>
+    </typeOfFiling>
+    <durationOfOffering>
+      <moreThanOneYear>true</moreThanOneYear>
+    </durationOfOffering>
+    <typesOfSecuritiesOffered>
+      <isPooledInvestmentFundType>true</isPooledInvestmentFundType>
+      <isEquityType>false</isEquityType>
+    </typesOfSecuritiesOffered>
+    <businessCombinationTransaction>
+      <isBusinessCombinationTransaction>false</isBusinessCombinationTransaction>
+    </businessCombinationTransaction>
+    <minimumInvestmentAccepted>1000000</minimumInvestmentAccepted>
+    <salesCompensationList>
+      <recipient>
+        <recipientName>Example Securities LLC</recipientName>
+        <recipientCRDNumber>123456</recipientCRDNumber>
+        <associatedBDName>None</associatedBDName>
+        <associatedBDCRDNumber>None</associatedBDCRDNumber>
+        <statesOfSolicitationList>
+          <state>NY</state>
+          <state>CA</state>
+        </statesOfSolicitationList>
+        <foreignSolicitation>false</foreignSolicitation>
+      </recipient>
+    </salesCompensationList>
+    <offeringSalesAmounts>
+      <totalOfferingAmount>Indefinite</totalOfferingAmount>
+      <totalAmountSold>25000000</totalAmountSold>
+      <totalRemaining>Indefinite</totalRemaining>
+    </offeringSalesAmounts>
+    <investors>
+      <hasNonAccreditedInvestors>false</hasNonAccreditedInvestors>
+      <totalNumberAlreadyInvested>12</totalNumberAlreadyInvested>
+    </investors>
+    <salesCommissionsFindersFees>
+      <salesCommissions>
+        <dollarAmount>50000</dollarAmount>
+      </salesCommissions>
+      <findersFees>
+        <dollarAmount>0</dollarAmount>
+      </findersFees>
+    </salesCommissionsFindersFees>
+    <useOfProceeds>
+      <grossProceedsUsed>
+        <dollarAmount>0</dollarAmount>
+      </grossProceedsUsed>
+    </useOfProceeds>
+    <signatureBlock>
+      <authorizedRepresentative>false</authorizedRepresentative>
+      <signature>
+        <issuerName>Example Opportunity Fund LP</issuerName>
+        <signatureName>/s/ Jane Doe</signatureName>
+        <nameOfSigner>Jane Doe</nameOfSigner>
+        <signatureTitle>Managing Member</signatureTitle>
+        <signatureDate>2021-02-01</signatureDate>
+      </signature>
+    </signatureBlock>
+  </offeringData>
+</edgarSubmission>
+</XML>
+</TEXT>
+</DOCUMENT>
+</SUBMISSION>
+";
+
+#[test]
+fn issuer_and_related_persons() {
+    let submission = parse_submission_from_str(FORM_D).unwrap();
+    let form_d = submission.form_d().unwrap().unwrap();
+
+    assert_eq!(form_d.submission_type, "D");
+    let issuer = &form_d.primary_issuer;
+    assert_eq!(issuer.cik, Cik(1765432));
+    assert_eq!(issuer.name, "Example Opportunity Fund LP");
+    assert_eq!(issuer.entity_type.as_deref(), Some("Limited Partnership"));
+    assert_eq!(issuer.year_of_incorporation, Some(2019));
+    assert_eq!(issuer.within_five_years, Some(true));
+    assert_eq!(
+        issuer.address.as_ref().unwrap().state_or_country.as_deref(),
+        Some("NY")
+    );
+    assert_eq!(form_d.issuers().count(), 1);
+
+    let person = &form_d.related_persons[0];
+    assert_eq!(person.last_name.as_deref(), Some("Doe"));
+    assert_eq!(person.relationships, vec!["Executive Officer", "Promoter"]);
+
+    assert_eq!(
+        form_d.signatures[0].title.as_deref(),
+        Some("Managing Member")
+    );
+}
+
+#[test]
+fn offering() {
+    let submission = parse_submission_from_str(FORM_D).unwrap();
+    let offering = submission.form_d().unwrap().unwrap().offering;
+
+    assert_eq!(
+        offering.industry_group.as_deref(),
+        Some("Pooled Investment Fund")
+    );
+    assert_eq!(offering.investment_fund_type.as_deref(), Some("Hedge Fund"));
+    assert_eq!(offering.federal_exemptions, vec!["06b", "3C", "3C.7"]);
+    assert_eq!(offering.is_amendment, Some(false));
+    assert_eq!(
+        offering.date_of_first_sale,
+        Some(NaiveDate::from_ymd_opt(2021, 1, 4).unwrap())
+    );
+    assert_eq!(offering.types_of_securities, vec!["PooledInvestmentFund"]);
+    assert_eq!(offering.minimum_investment, Some(1_000_000));
+    assert_eq!(
+        offering.total_offering_amount,
+        Some(OfferingAmount::Indefinite)
+    );
+    assert_eq!(offering.total_amount_sold, Some(25_000_000));
+    assert_eq!(offering.total_number_already_invested, Some(12));
+    assert_eq!(offering.sales_commissions, Some(50_000));
+    assert_eq!(offering.finders_fees, Some(0));
+
+    let recipient = &offering.sales_compensation[0];
+    assert_eq!(recipient.crd_number.as_deref(), Some("123456"));
+    assert_eq!(recipient.states_of_solicitation, vec!["NY", "CA"]);
+}
+
+#[test]
+fn joined_to_filers() {
+    let submission = parse_submission_from_str(FORM_D).unwrap();
+    let form_d = submission.form_d().unwrap().unwrap();
+
+    let filer = form_d.primary_issuer.company(&submission).unwrap();
+    assert_eq!(filer.cik(), Some(Cik(1765432)));
+}
+
+#[test]
+fn other_forms_have_no_notice() {
+    let submission = parse_submission_from_str(&FORM_D.replace("<TYPE>D", "<TYPE>8-K")).unwrap();
+
+    assert!(submission.form_d().is_none());
+}
@@ -0,0 +1,108 @@
+use sec_data_parser::*;
+
+#[test]
+fn amendments_are_split_out() {
+    let form_type = FormType::parse("10-K/A");
+
+    assert_eq!(form_type.form, Form::TenK);
+    assert!(form_type.amendment);
+    assert_eq!(form_type.family(), Some(FormFamily::AnnualReport));
+    assert_eq!(form_type.to_string(), "10-K/A");
+}
+
+#[test]
+fn forms_display_as_parsed() {
+    for name in &[
+        "SC 13D",
+        "SC 13G/A",
+        "SCHEDULE 13G/A",
+        "4",
+        "424B3",
+        "424B12",
+        "D/A",
+        "S-1/A",
+        "CERTNYS",
+    ] {
+        assert_eq!(FormType::parse(name).to_string(), *name);
+    }
+}
+
+#[test]
+fn unknown_forms_are_kept() {
+    let form_type = FormType::parse("CERTNYS");
+
+    assert_eq!(form_type.form, Form::Other("CERTNYS".to_string()));
+    assert_eq!(form_type.family(), None);
+    assert_eq!(
+        FormType::parse("424B12").form,
+        Form::Other("424B12".to_string())
+    );
+}
+
+#[test]
+fn families_group_forms() {
+    let families: Vec<_> = ["4", "SC 13G", "13F-HR/A", "NPORT-P", "424B5"]
+        .iter()
+        .map(|name| FormType::parse(name).family())
+        .collect();
+
+    assert_eq!(
+        families,
+        vec![
+            Some(FormFamily::InsiderOwnership),
+            Some(FormFamily::BeneficialOwnership),
+            Some(FormFamily::InstitutionalHoldings),
+            Some(FormFamily::FundReport),
+            Some(FormFamily::Prospectus),
+        ]
+    );
+}
+
+#[test]
+fn exhibit_types() {
+    let exhibit = ExhibitType::parse("EX-99.1").unwrap();
+    assert_eq!(exhibit.number, 99);
+    assert_eq!(exhibit.sub_number(), Some(1));
+    assert_eq!(exhibit.to_string(), "EX-99.1");
+
+    let exhibit = ExhibitType::parse("EX-101.INS").unwrap();
+    assert_eq!(exhibit.suffix.as_deref(), Some("INS"));
+    assert_eq!(exhibit.sub_number(), None);
+
+    assert_eq!(
+        ExhibitType::parse("EX-27"),
+        Some(ExhibitType {
+            number: 27,
+            suffix: None
+        })
+    );
+    assert_eq!(ExhibitType::parse("EX-"), None);
+    assert_eq!(ExhibitType::parse("EX-99."), None);
+    assert_eq!(ExhibitType::parse("10-K"), None);
+}
+
+#[test]
+fn accessors_read_the_raw_fields() {
+    let submission = parse_submission_from_str(
+        "<SUBMISSION>
+<ACCESSION-NUMBER>0000950123-21-001234
+<TYPE>8-K/A
+<FILING-DATE>20210115
+<DOCUMENT>
+<TYPE>8-K/A
+<SEQUENCE>1
+</DOCUMENT>
+<DOCUMENT>
+<TYPE>EX-99.1
+<SEQUENCE>2
+</DOCUMENT>
+</SUBMISSION>
+",
+    )
+    .unwrap();
+
+    assert_eq!(submission.form().form, Form::EightK);
+    assert_eq!(submission.documents[0].form(), submission.form());
+    assert_eq!(submission.documents[0].exhibit(), None);
+    assert_eq!(submission.documents[1].exhibit().unwrap().number, 99);
+}
@@ -0,0 +1,238 @@
+use sec_data_parser::*;
+
+#[test]
+fn cik_ignores_padding() {
+    assert_eq!(Cik::parse("0000012345"), Cik::parse("12345"));
+    assert_eq!(Cik::parse("12345").unwrap().to_string(), "0000012345");
+    assert_eq!(Cik::parse("12345678901"), None);
+    assert_eq!(Cik::parse("12A45"), None);
+    assert_eq!(Cik::parse(""), None);
+}
+
+#[test]
+fn accession_number_round_trips() {
+    let accession_number = AccessionNumber::parse("0000950123-21-001234").unwrap();
+
+    assert_eq!(
+        accession_number,
+        AccessionNumber {
+            filer_id: 950123,
+            year: 21,
+            seq: 1234
+        }
+    );
+    assert_eq!(accession_number.to_string(), "0000950123-21-001234");
+    assert_eq!(AccessionNumber::parse("950123-21-001234"), None);
+    assert_eq!(AccessionNumber::parse("0000950123-21-001234-1"), None);
+}
+
+#[test]
+fn file_number_keeps_prefix_as_written() {
+    let file_number = FileNumber::parse("001-03480").unwrap();
+
+    assert_eq!(file_number.prefix, "001");
+    assert_eq!(file_number.number, "03480");
+    assert_eq!(file_number.to_string(), "001-03480");
+    assert_eq!(
+        FileNumber::parse("333-228375-01").unwrap().number,
+        "228375-01"
+    );
+    assert_eq!(FileNumber::parse("33312345"), None);
+    assert_eq!(FileNumber::parse("333-"), None);
+    assert_eq!(FileNumber::parse("333-123456-"), None);
+}
+
+#[test]
+fn legacy_file_numbers_keep_letter_suffixes() {
+    let file_number = FileNumber::parse("33-12345-NY").unwrap();
+    assert_eq!(file_number.prefix, "33");
+    assert_eq!(file_number.number, "12345-NY");
+    assert_eq!(FileNumber::parse("2-1234-D").unwrap().number, "1234-D");
+
+    let submission = parse_submission_from_str(
+        "<SUBMISSION>
+<ACCESSION-NUMBER>0000950123-96-001234
+<TYPE>10-K
+<FILING-DATE>19960115
+<FILER>
+<COMPANY-DATA>
+<CONFORMED-NAME>ACME CORP
+<CIK>0000012345
+</COMPANY-DATA>
+<FILING-VALUES>
+<FORM-TYPE>10-K
+<FILE-NUMBER>33-12345-NY
+</FILING-VALUES>
+</FILER>
+</SUBMISSION>
+",
+    )
+    .unwrap();
+    let file_number = submission.filers[0].filing_values[0].file_number.as_ref();
+    assert_eq!(file_number.unwrap().to_string(), "33-12345-NY");
+}
+
+#[test]
+fn sic_is_four_digits() {
+    assert_eq!(Sic::parse("0100"), Some(Sic(100)));
+    assert_eq!(Sic(100).to_string(), "0100");
+    assert_eq!(Sic::parse("12345"), None);
+}
+
+#[test]
+fn header_identifiers_are_typed() {
+    let submission = parse_submission_from_str(
+        "<SUBMISSION>
+<ACCESSION-NUMBER>0000950123-21-001234
+<TYPE>8-K
+<FILING-DATE>20210115
+<FILER>
+<COMPANY-DATA>
+<CONFORMED-NAME>ACME CORP
+<CIK>12345
+<ASSIGNED-SIC>3670
+</COMPANY-DATA>
+<FILING-VALUES>
+<FORM-TYPE>8-K
+<FILE-NUMBER>001-03480
+</FILING-VALUES>
+</FILER>
+</SUBMISSION>
+",
+    )
+    .unwrap();
+
+    assert_eq!(submission.accession_number.filer_id, 950123);
+    let filer = &submission.filers[0];
+    let company_data = filer.company_data.as_ref().unwrap();
+    assert_eq!(company_data.cik, Cik(12345));
+    assert_eq!(company_data.assigned_sic, Some(Sic(3670)));
+    assert_eq!(
+        filer.filing_values[0].file_number,
+        FileNumber::parse("001-03480")
+    );
+}
+
+#[test]
+fn malformed_cik_is_an_error() {
+    let error = parse_submission_from_str(
+        "<SUBMISSION>
+<ACCESSION-NUMBER>0000950123-21-001234
+<TYPE>8-K
+<FILING-DATE>20210115
+<FILER>
+<COMPANY-DATA>
+<CONFORMED-NAME>ACME CORP
+<CIK>ACME
+</COMPANY-DATA>
+</FILER>
+</SUBMISSION>
+",
+    )
+    .unwrap_err();
+
+    assert!(matches!(error.kind, ErrorKind::BadValue(ValueTag::Cik, _)));
+}
+
+#[test]
+fn malformed_identifiers_are_kept_when_lenient() {
+    let source = "<SUBMISSION>
+<ACCESSION-NUMBER>0000950123-21-001234
+<TYPE>8-K
+<FILING-DATE>20210115
+<FILER>
+<COMPANY-DATA>
+<CONFORMED-NAME>ACME CORP
+<CIK>12345
+<ASSIGNED-SIC>36X0
+</COMPANY-DATA>
+<FILING-VALUES>
+<FORM-TYPE>8-K
+<FILE-NUMBER>001 03480
+</FILING-VALUES>
+</FILER>
+</SUBMISSION>
+";
+    assert!(parse_submission_from_str(source).is_err());
+
+    let options = ParseOptions {
+        lenient: true,
+        ..Default::default()
+    };
+    let report = options.report_str(source).unwrap();
+    let filer = &report.submission.filers[0];
+    let company_data = filer.company_data.as_ref().unwrap();
+
+    assert_eq!(company_data.assigned_sic, None);
+    assert_eq!(
+        company_data.extra,
+        vec![("ASSIGNED-SIC".to_string(), "36X0".to_string())]
+    );
+    assert_eq!(filer.filing_values[0].file_number, None);
+    assert_eq!(
+        report.warnings.iter().map(|w| &w.kind).collect::<Vec<_>>(),
+        vec![
+            &WarningKind::BadValue(ValueTag::AssignedSic, "36X0".to_string()),
+            &WarningKind::BadValue(ValueTag::FileNumber, "001 03480".to_string()),
+        ]
+    );
+
+    let written = submission_to_bytes(&report.submission).unwrap();
+    assert_eq!(options.parse_bytes(&written).unwrap(), report.submission);
+}
+
+#[test]
+fn repeats_of_malformed_identifiers_are_duplicates() {
+    let options = ParseOptions {
+        lenient: true,
+        ..Default::default()
+    };
+    let error = options
+        .parse_str(
+            "<SUBMISSION>
+<ACCESSION-NUMBER>0000950123-21-001234
+<TYPE>8-K
+<FILING-DATE>20210115
+<FILER>
+<COMPANY-DATA>
+<CONFORMED-NAME>ACME CORP
+<CIK>12345
+<ASSIGNED-SIC>36X0
+<ASSIGNED-SIC>3600
+</COMPANY-DATA>
+</FILER>
+</SUBMISSION>
+",
+        )
+        .unwrap_err();
+
+    assert!(matches!(
+        error.kind,
+        ErrorKind::DuplicateValue(ValueTag::AssignedSic)
+    ));
+}
+
+#[test]
+fn writing_pads_identifiers() {
+    let submission = parse_submission_from_str(
+        "<SUBMISSION>
+<ACCESSION-NUMBER>0000950123-21-001234
+<TYPE>8-K
+<FILING-DATE>20210115
+<FILER>
+<COMPANY-DATA>
+<CONFORMED-NAME>ACME CORP
+<CIK>12345
+<ASSIGNED-SIC>100
+</COMPANY-DATA>
+</FILER>
+</SUBMISSION>
+",
+    )
+    .unwrap();
+
+    let written = String::from_utf8(submission_to_bytes(&submission).unwrap()).unwrap();
+    assert!(written.contains("<CIK>0000012345\n"));
+    assert!(written.contains("<ASSIGNED-SIC>0100\n"));
+    assert_eq!(parse_submission_from_str(&written).unwrap(), submission);
+}
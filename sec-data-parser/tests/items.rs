@@ -0,0 +1,59 @@
+use sec_data_parser::*;
+
+#[test]
+fn current_items() {
+    let item = Item8K::parse("2.02");
+
+    assert_eq!(item, Item8K::ResultsOfOperations);
+    assert_eq!(
+        item.title(),
+        Some("Results of Operations and Financial Condition")
+    );
+    assert_eq!(item.to_string(), "2.02");
+    assert!(!item.is_legacy());
+}
+
+#[test]
+fn legacy_items_map_to_current() {
+    let item = Item8K::parse("12");
+
+    assert!(item.is_legacy());
+    assert_eq!(item.to_current(), Item8K::ResultsOfOperations);
+    assert_eq!(
+        Item8K::parse("6").to_current(),
+        Item8K::DepartureOfDirectorsOrOfficers
+    );
+    assert_eq!(Item8K::parse("9.01").to_current(), Item8K::parse("9.01"));
+}
+
+#[test]
+fn unknown_items_are_kept() {
+    let item = Item8K::parse("10.01");
+
+    assert_eq!(item, Item8K::Other("10.01".to_string()));
+    assert_eq!(item.title(), None);
+    assert_eq!(item.code(), "10.01");
+}
+
+#[test]
+fn submission_items() {
+    let submission = parse_submission_from_str(
+        "<SUBMISSION>
+<ACCESSION-NUMBER>0000950123-21-001234
+<TYPE>8-K
+<ITEMS>5.02
+<ITEMS>9.01
+<FILING-DATE>20210115
+</SUBMISSION>
+",
+    )
+    .unwrap();
+
+    assert_eq!(
+        submission.items_8k().collect::<Vec<_>>(),
+        vec![
+            Item8K::DepartureOfDirectorsOrOfficers,
+            Item8K::FinancialStatementsAndExhibits
+        ]
+    );
+}
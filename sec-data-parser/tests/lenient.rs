@@ -0,0 +1,110 @@
+use sec_data_parser::*;
+
+const SUBMISSION: &str = "<SUBMISSION>
+<ACCESSION-NUMBER>0000950123-21-001234
+<TYPE>8-K
+<FILING-DATE>20210115
+<NEW-HEADER-VALUE>Y
+<FILER>
+<COMPANY-DATA>
+<CONFORMED-NAME>ACME CORP
+<CIK>0000012345
+<LEI>5493001KJTIIGC8Y1R12
+</COMPANY-DATA>
+<NEW-CONTAINER>
+<NAME>SOMETHING
+<NESTED>
+<INNER>VALUE
+</NESTED>
+</NEW-CONTAINER>
+</FILER>
+<DOCUMENT>
+<TYPE>8-K
+<SEQUENCE>1
+<TEXT>
+Hello
+</TEXT>
+</DOCUMENT>
+<TRAILING-VALUE>X
+</SUBMISSION>
+";
+
+fn lenient() -> ParseOptions {
+    ParseOptions {
+        lenient: true,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn unknown_tags_fail_by_default() {
+    let error = parse_submission_from_str(SUBMISSION).unwrap_err();
+    assert!(matches!(error.kind, ErrorKind::InvalidValueTag(ref tag) if tag == "NEW-HEADER-VALUE"));
+}
+
+#[test]
+fn unknown_tags_are_kept_when_lenient() {
+    let report = lenient().report_str(SUBMISSION).unwrap();
+    let submission = &report.submission;
+
+    assert_eq!(
+        submission.extra,
+        vec![
+            ("NEW-HEADER-VALUE".to_string(), "Y".to_string()),
+            ("TRAILING-VALUE".to_string(), "X".to_string()),
+        ]
+    );
+    let filer = &submission.filers[0];
+    assert_eq!(
+        filer.company_data.as_ref().unwrap().extra,
+        vec![("LEI".to_string(), "5493001KJTIIGC8Y1R12".to_string())]
+    );
+    assert_eq!(
+        filer.extra_containers,
+        vec![ExtraContainer {
+            tag: "NEW-CONTAINER".to_string(),
+            values: vec![("NAME".to_string(), "SOMETHING".to_string())],
+            containers: vec![ExtraContainer {
+                tag: "NESTED".to_string(),
+                values: vec![("INNER".to_string(), "VALUE".to_string())],
+                containers: Vec::new(),
+            }],
+        }]
+    );
+
+    let kinds: Vec<&WarningKind> = report.warnings.iter().map(|w| &w.kind).collect();
+    assert_eq!(
+        kinds,
+        vec![
+            &WarningKind::UnknownValue("NEW-HEADER-VALUE".to_string()),
+            &WarningKind::UnknownValue("LEI".to_string()),
+            &WarningKind::UnknownContainer("NEW-CONTAINER".to_string()),
+            &WarningKind::UnknownValue("TRAILING-VALUE".to_string()),
+        ]
+    );
+    assert_eq!(
+        report.warnings[1].path,
+        vec![
+            ContainerTag::Submission,
+            ContainerTag::Filer,
+            ContainerTag::CompanyData
+        ]
+    );
+}
+
+#[test]
+fn streaming_keeps_the_same_tags() {
+    let from_bytes = lenient().report_str(SUBMISSION).unwrap();
+    let from_reader = lenient().report_reader(SUBMISSION.as_bytes()).unwrap();
+
+    assert_eq!(from_reader.submission, from_bytes.submission);
+    assert_eq!(from_reader.warnings.len(), from_bytes.warnings.len());
+}
+
+#[test]
+fn unknown_tags_are_written_back() {
+    let submission = lenient().parse_str(SUBMISSION).unwrap();
+    let written = submission_to_bytes(&submission).unwrap();
+
+    assert_eq!(lenient().parse_bytes(&written).unwrap(), submission);
+}
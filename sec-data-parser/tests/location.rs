@@ -0,0 +1,104 @@
+use sec_data_parser::*;
+
+#[test]
+fn codes_are_decoded() {
+    let cayman = Location::from_code("E9").unwrap();
+    assert_eq!(cayman.name, "Cayman Islands");
+    assert_eq!(cayman.country, "KY");
+    assert_eq!(cayman.subdivision, None);
+
+    let uk = Location::from_code("X0").unwrap();
+    assert_eq!(uk.country, "GB");
+
+    let delaware = Location::from_code("DE").unwrap();
+    assert_eq!(delaware.name, "Delaware");
+    assert_eq!(delaware.subdivision, Some("US-DE"));
+    assert!(delaware.is_us());
+
+    let ontario = State::Foreign("A6".to_string()).location().unwrap();
+    assert_eq!(ontario.subdivision, Some("CA-ON"));
+    assert!(!ontario.is_us());
+
+    assert_eq!(Location::from_code("??"), None);
+}
+
+#[test]
+fn every_state_variant_has_a_location() {
+    for code in &["AL", "DC", "B5", "GU", "1V", "PR", "VI", "WY", "X1"] {
+        let state = State::parse(code).unwrap();
+        assert!(state.location().unwrap().is_us(), "{}", code);
+    }
+}
+
+#[test]
+fn states_agree_with_locations() {
+    for location in Location::all() {
+        let state = State::parse(location.code).unwrap();
+        assert_eq!(state.is_foreign(), !location.is_us(), "{}", location.code);
+        assert_eq!(state.location(), Some(location));
+    }
+}
+
+#[test]
+fn territories_use_edgar_codes() {
+    let samoa = Location::from_code("B5").unwrap();
+    assert_eq!(samoa.name, "American Samoa");
+    assert_eq!(samoa.subdivision, Some("US-AS"));
+    assert_eq!(State::parse("B5"), Some(State::AmericanSamoa));
+
+    let marianas = Location::from_code("1V").unwrap();
+    assert_eq!(marianas.name, "Northern Mariana Islands");
+    assert_eq!(marianas.subdivision, Some("US-MP"));
+    assert_eq!(State::parse("1V"), Some(State::NorthernMarianaIslands));
+
+    assert_eq!(Location::from_code("AS"), None);
+    assert_eq!(Location::from_code("MP"), None);
+}
+
+#[test]
+fn less_common_countries_are_decoded() {
+    for (code, country) in &[
+        ("B2", "AF"),
+        ("Y6", "AX"),
+        ("E1", "MM"),
+        ("Q1", "VN"),
+        ("Z2", "RS"),
+        ("Y5", "ZW"),
+    ] {
+        assert_eq!(Location::from_code(code).unwrap().country, *country);
+    }
+}
+
+#[test]
+fn header_locations() {
+    let submission = parse_submission_from_str(
+        "<SUBMISSION>
+<ACCESSION-NUMBER>0000950123-21-001234
+<TYPE>20-F
+<FILING-DATE>20210115
+<FILER>
+<COMPANY-DATA>
+<CONFORMED-NAME>ACME HOLDINGS LTD
+<CIK>0000012345
+<STATE-OF-INCORPORATION>E9
+</COMPANY-DATA>
+<BUSINESS-ADDRESS>
+<CITY>LONDON
+<STATE>X0
+</BUSINESS-ADDRESS>
+</FILER>
+</SUBMISSION>
+",
+    )
+    .unwrap();
+    let filer = &submission.filers[0];
+
+    let incorporated = filer
+        .company_data
+        .as_ref()
+        .unwrap()
+        .incorporation_location();
+    assert_eq!(incorporated.unwrap().name, "Cayman Islands");
+    let based = filer.business_address.as_ref().unwrap().location();
+    assert_eq!(based.unwrap().name, "United Kingdom");
+}
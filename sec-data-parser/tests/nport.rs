@@ -0,0 +1,272 @@
+use chrono::NaiveDate;
+use sec_data_parser::nport::*;
+use sec_data_parser::*;
+
+const NPORT: &str = "<SUBMISSION>
+<ACCESSION-NUMBER>0001752724-21-045678
+<TYPE>NPORT-P
+<PUBLIC-DOCUMENT-COUNT>1
+<PERIOD>20210131
+<FILING-DATE>20210325
+<FILER>
+<COMPANY-DATA>
+<CONFORMED-NAME>EXAMPLE FUNDS TRUST
+<CIK>0000812345
+</COMPANY-DATA>
+</FILER>
+<SERIES-AND-CLASSES-CONTRACTS-DATA>
+<EXISTING-SERIES-AND-CLASSES-CONTRACTS>
+<SERIES>
+<OWNER-CIK>0000812345
+<SERIES-ID>S000012345
+<SERIES-NAME>Example Balanced Fund
+<CLASS-CONTRACT>
+<CLASS-CONTRACT-ID>C000033333
+<CLASS-CONTRACT-NAME>Investor Class
+<CLASS-CONTRACT-TICKER-SYMBOL>EXBLX
+</CLASS-CONTRACT>
+<CLASS-CONTRACT>
+<CLASS-CONTRACT-ID>C000044444
+<CLASS-CONTRACT-NAME>Institutional Class
+</CLASS-CONTRACT>
+</SERIES>
+</EXISTING-SERIES-AND-CLASSES-CONTRACTS>
+</SERIES-AND-CLASSES-CONTRACTS-DATA>
+<DOCUMENT>
+<TYPE>NPORT-P
+<SEQUENCE>1
+<FILENAME>primary_doc.xml
+<TEXT>
+<XML>
+<?xml version=\"1.0\" encoding=\"UTF-8\"?>
+<edgarSubmission xmlns=\"http://www.sec.gov/edgar/nport\" xmlns:com=\"http://www.sec.gov/edgar/common\">
+  <headerData>
+    <submissionType>NPORT-P</submissionType>
+    <isConfidential>false</isConfidential>
+    <filerInfo>
+      <filer>
+        <issuerCredentials>
+          <cik>0000812345</cik>
+        </issuerCredentials>
+      </filer>
+      <seriesClassInfo>
+        <seriesId>S000012345</seriesId>
+        <classId>C000033333</classId>
+        <classId>C000044444</classId>
+      </seriesClassInfo>
+    </filerInfo>
+  </headerData>
+  <formData>
+    <genInfo>
+      <regName>Example Funds Trust</regName>
+      <regFileNumber>811-05678</regFileNumber>
+      <regCik>0000812345</regCik>
+      <regLei>549300EXAMPLE0000001</regLei>
+      <seriesName>Example Balanced Fund</seriesName>
+      <seriesId>S000012345</seriesId>
+      <seriesLei>549300EXAMPLE0000002</seriesLei>
+      <repPdEnd>2021-10-31</repPdEnd>
+      <repPdDate>2021-01-31</repPdDate>
+      <isFinalFiling>N</isFinalFiling>
+    </genInfo>
+    <fundInfo>
+      <totAssets>1250000000.00</totAssets>
+      <totLiabs>5000000.00</totLiabs>
+      <netAssets>1245000000.00</netAssets>
+      <returnInfo>
+        <monthlyTotReturns>
+          <monthlyTotReturn classId=\"C000033333\" rtn1=\"1.25\" rtn2=\"-0.40\" rtn3=\"0.85\"/>
+          <monthlyTotReturn classId=\"C000044444\" rtn1=\"1.27\" rtn2=\"-0.38\" rtn3=\"N/A\"/>
+        </monthlyTotReturns>
+        <othMon1 netRealizedGain=\"1500000.00\" netUnrealizedAppr=\"-250000.00\"/>
+        <othMon2 netRealizedGain=\"0.00\" netUnrealizedAppr=\"300000.00\"/>
+        <othMon3 netRealizedGain=\"125000.00\" netUnrealizedAppr=\"75000.00\"/>
+      </returnInfo>
+      <mon1Flow sales=\"10000000.00\" reinvestment=\"0.00\" redemption=\"8000000.00\"/>
+      <mon2Flow sales=\"9000000.00\" reinvestment=\"250000.00\" redemption=\"7500000.00\"/>
+      <mon3Flow sales=\"11000000.00\" reinvestment=\"0.00\" redemption=\"9000000.00\"/>
+    </fundInfo>
+    <invstOrSecs>
+      <invstOrSec>
+        <name>Apple Inc</name>
+        <lei>HWUPKR0MPOU8FGXBT394</lei>
+        <title>Apple Inc</title>
+        <cusip>037833100</cusip>
+        <identifiers>
+          <isin value=\"US0378331005\"/>
+        </identifiers>
+        <balance>100000.00</balance>
+        <units>NS</units>
+        <curCd>USD</curCd>
+        <valUSD>13269000.00</valUSD>
+        <pctVal>1.065783</pctVal>
+        <payoffProfile>Long</payoffProfile>
+        <assetCat>EC</assetCat>
+        <issuerCat>CORP</issuerCat>
+        <invCountry>US</invCountry>
+        <isRestrictedSec>N</isRestrictedSec>
+        <fairValLevel>1</fairValLevel>
+        <securityLending>
+          <isCashCollateral>N</isCashCollateral>
+          <isNonCashCollateral>N</isNonCashCollateral>
+          <isLoanByFund>N</isLoanByFund>
+        </securityLending>
+      </invstOrSec>
+      <invstOrSec>
+        <name>Chicago Mercantile Exchange</name>
+        <lei>SNZ2OJLFK8MNNCLQOF39</lei>
+        <title>US 10YR NOTE (CBT) Mar21</title>
+        <cusip>000000000</cusip>
+        <identifiers>
+          <ticker value=\"TYH1\"/>
+        </identifiers>
+        <balance>-50.00</balance>
+        <units>NC</units>
+        <curCd>USD</curCd>
+        <valUSD>12500.00</valUSD>
+        <pctVal>0.001004</pctVal>
+        <payoffProfile>N/A</payoffProfile>
+        <assetConditional assetCat=\"OTHER\" desc=\"Interest rate future\"/>
+        <issuerCat>OTHER</issuerCat>
+        <invCountry>US</invCountry>
+        <isRestrictedSec>N</isRestrictedSec>
+        <fairValLevel>N/A</fairValLevel>
+        <derivativeInfo>
+          <futrDeriv derivCat=\"FUT\">
+            <counterparties>
+              <counterpartyName>Chicago Board of Trade</counterpartyName>
+              <counterpartyLei>549300EX04Q2QBFQTQ27</counterpartyLei>
+            </counterparties>
+            <payOffProf>Short</payOffProf>
+            <expDate>2021-03-22</expDate>
+            <notionalAmt>-6900000.00</notionalAmt>
+            <curCd>USD</curCd>
+            <unrealizedAppr>12500.00</unrealizedAppr>
+          </futrDeriv>
+        </derivativeInfo>
+      </invstOrSec>
+    </invstOrSecs>
+  </formData>
+</edgarSubmission>
+</XML>
+</TEXT>
+</DOCUMENT>
+</SUBMISSION>
+";
+
+#[test]
+fn fund_and_return_info() {
+    let submission = parse_submission_from_str(NPORT).unwrap();
+    let nport = submission.nport().unwrap().unwrap();
+
+    assert_eq!(nport.submission_type.as_deref(), Some("NPORT-P"));
+    assert_eq!(nport.class_ids, vec!["C000033333", "C000044444"]);
+    assert_eq!(nport.general.cik, Some(Cik(812345)));
+    assert_eq!(nport.general.series_id.as_deref(), Some("S000012345"));
+    assert_eq!(
+        nport.general.period_date,
+        Some(NaiveDate::from_ymd_opt(2021, 1, 31).unwrap())
+    );
+    assert_eq!(nport.general.is_final_filing, Some(false));
+
+    let fund = &nport.fund;
+    assert_eq!(fund.net_assets, Some(1_245_000_000.0));
+    assert_eq!(
+        fund.class_returns[0],
+        ClassReturns {
+            class_id: "C000033333".to_string(),
+            returns: [Some(1.25), Some(-0.4), Some(0.85)],
+        }
+    );
+    assert_eq!(fund.class_returns[1].returns[2], None);
+    assert_eq!(
+        fund.other_gains[0].net_unrealized_appreciation,
+        Some(-250_000.0)
+    );
+    assert_eq!(fund.flows.len(), 3);
+    assert_eq!(fund.flows[1].reinvestment, Some(250_000.0));
+}
+
+#[test]
+fn holdings_and_derivatives() {
+    let submission = parse_submission_from_str(NPORT).unwrap();
+    let holdings = submission.nport().unwrap().unwrap().holdings;
+
+    assert_eq!(holdings.len(), 2);
+    let apple = &holdings[0];
+    assert_eq!(apple.cusip.as_deref(), Some("037833100"));
+    assert_eq!(apple.isin.as_deref(), Some("US0378331005"));
+    assert_eq!(apple.balance, Some(100_000.0));
+    assert_eq!(apple.value_usd, Some(13_269_000.0));
+    assert_eq!(apple.asset_category.as_deref(), Some("EC"));
+    assert_eq!(apple.fair_value_level, Some(1));
+    assert_eq!(apple.derivative, None);
+
+    let future = &holdings[1];
+    assert_eq!(future.cusip, None);
+    assert_eq!(future.ticker.as_deref(), Some("TYH1"));
+    assert_eq!(future.payoff_profile, None);
+    assert_eq!(future.asset_category.as_deref(), Some("OTHER"));
+    assert_eq!(future.fair_value_level, None);
+    assert_eq!(
+        future.derivative,
+        Some(Derivative {
+            category: Some("FUT".to_string()),
+            counterparties: vec![Counterparty {
+                name: Some("Chicago Board of Trade".to_string()),
+                lei: Some("549300EX04Q2QBFQTQ27".to_string()),
+            }],
+            notional_amount: Some(-6_900_000.0),
+            expiration_date: Some(NaiveDate::from_ymd_opt(2021, 3, 22).unwrap()),
+            unrealized_appreciation: Some(12_500.0),
+        })
+    );
+}
+
+#[test]
+fn joined_to_series_and_classes() {
+    let submission = parse_submission_from_str(NPORT).unwrap();
+    let nport = submission.nport().unwrap().unwrap();
+
+    let series = nport.series(&submission).unwrap();
+    assert_eq!(series.series_name, "Example Balanced Fund");
+
+    let investor = nport.fund.class_returns[0]
+        .class_contract(&submission)
+        .unwrap();
+    assert_eq!(investor.class_contract_name, "Investor Class");
+    assert_eq!(
+        investor.class_contract_ticker_symbol.as_deref(),
+        Some("EXBLX")
+    );
+    let institutional = nport.fund.class_returns[1]
+        .class_contract(&submission)
+        .unwrap();
+    assert_eq!(institutional.class_contract_name, "Institutional Class");
+}
+
+#[test]
+fn bad_values_are_errors() {
+    let error = NPort::parse(
+        "<edgarSubmission><formData>
+<genInfo><seriesId>S000012345</seriesId></genInfo>
+<fundInfo><returnInfo><monthlyTotReturns>
+<monthlyTotReturn classId=\"C000033333\" rtn1=\"1.2.3\"/>
+</monthlyTotReturns></returnInfo></fundInfo>
+</formData></edgarSubmission>",
+    )
+    .unwrap_err();
+
+    assert_eq!(
+        error.to_string(),
+        "malformed XML document: bad value \"1.2.3\" for attribute rtn1 of <monthlyTotReturn>"
+    );
+}
+
+#[test]
+fn other_forms_have_no_report() {
+    let submission =
+        parse_submission_from_str(&NPORT.replace("<TYPE>NPORT-P", "<TYPE>N-CSR")).unwrap();
+
+    assert!(submission.nport().is_none());
+}
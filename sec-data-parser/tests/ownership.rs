@@ -0,0 +1,266 @@
+use chrono::NaiveDate;
+use sec_data_parser::ownership::*;
+use sec_data_parser::*;
+
+const FORM_4: &str = "<SUBMISSION>
+<ACCESSION-NUMBER>0001209191-21-004567
+<TYPE>4
+<PUBLIC-DOCUMENT-COUNT>1
+<PERIOD>20210115
+<FILING-DATE>20210119
+<REPORTING-OWNER>
+<OWNER-DATA>
+<CONFORMED-NAME>DOE JANE
+<CIK>0001234567
+</OWNER-DATA>
+</REPORTING-OWNER>
+<ISSUER>
+<COMPANY-DATA>
+<CONFORMED-NAME>ACME CORP
+<CIK>0000012345
+</COMPANY-DATA>
+</ISSUER>
+<DOCUMENT>
+<TYPE>4
+<SEQUENCE>1
+<FILENAME>doc4.xml
+<TEXT>
+<XML>
+<?xml version=\"1.0\"?>
+<ownershipDocument>
+    <schemaVersion>X0306</schemaVersion>
+    <documentType>4</documentType>
+    <periodOfReport>2021-01-15</periodOfReport>
+    <notSubjectToSection16>0</notSubjectToSection16>
+    <issuer>
+        <issuerCik>0000012345</issuerCik>
+        <issuerName>Acme Corp</issuerName>
+        <issuerTradingSymbol>ACME</issuerTradingSymbol>
+    </issuer>
+    <reportingOwner>
+        <reportingOwnerId>
+            <rptOwnerCik>0001234567</rptOwnerCik>
+            <rptOwnerName>Doe Jane</rptOwnerName>
+        </reportingOwnerId>
+        <reportingOwnerAddress>
+            <rptOwnerStreet1>1 MAIN ST</rptOwnerStreet1>
+            <rptOwnerCity>SPRINGFIELD</rptOwnerCity>
+            <rptOwnerState>IL</rptOwnerState>
+            <rptOwnerZipCode>62701</rptOwnerZipCode>
+        </reportingOwnerAddress>
+        <reportingOwnerRelationship>
+            <isDirector>0</isDirector>
+            <isOfficer>1</isOfficer>
+            <officerTitle>Chief Financial Officer</officerTitle>
+        </reportingOwnerRelationship>
+    </reportingOwner>
+    <nonDerivativeTable>
+        <nonDerivativeTransaction>
+            <securityTitle><value>Common Stock</value></securityTitle>
+            <transactionDate><value>2021-01-15</value></transactionDate>
+            <transactionCoding>
+                <transactionFormType>4</transactionFormType>
+                <transactionCode>S</transactionCode>
+                <equitySwapInvolved>0</equitySwapInvolved>
+                <footnoteId id=\"F1\"/>
+            </transactionCoding>
+            <transactionAmounts>
+                <transactionShares><value>1,500</value></transactionShares>
+                <transactionPricePerShare>
+                    <value>42.17</value>
+                    <footnoteId id=\"F2\"/>
+                </transactionPricePerShare>
+                <transactionAcquiredDisposedCode><value>D</value></transactionAcquiredDisposedCode>
+            </transactionAmounts>
+            <postTransactionAmounts>
+                <sharesOwnedFollowingTransaction><value>20000</value></sharesOwnedFollowingTransaction>
+            </postTransactionAmounts>
+            <ownershipNature>
+                <directOrIndirectOwnership><value>D</value></directOrIndirectOwnership>
+            </ownershipNature>
+        </nonDerivativeTransaction>
+        <nonDerivativeHolding>
+            <securityTitle><value>Common Stock</value></securityTitle>
+            <postTransactionAmounts>
+                <sharesOwnedFollowingTransaction><value>5000</value></sharesOwnedFollowingTransaction>
+            </postTransactionAmounts>
+            <ownershipNature>
+                <directOrIndirectOwnership><value>I</value></directOrIndirectOwnership>
+                <natureOfOwnership><value>By Trust</value></natureOfOwnership>
+            </ownershipNature>
+        </nonDerivativeHolding>
+    </nonDerivativeTable>
+    <derivativeTable>
+        <derivativeTransaction>
+            <securityTitle><value>Stock Option (Right to Buy)</value></securityTitle>
+            <conversionOrExercisePrice><value>30.00</value></conversionOrExercisePrice>
+            <transactionDate><value>2021-01-15</value></transactionDate>
+            <transactionCoding>
+                <transactionFormType>4</transactionFormType>
+                <transactionCode>M</transactionCode>
+                <equitySwapInvolved>0</equitySwapInvolved>
+            </transactionCoding>
+            <transactionAmounts>
+                <transactionShares><value>1500</value></transactionShares>
+                <transactionPricePerShare><value>0</value></transactionPricePerShare>
+                <transactionAcquiredDisposedCode><value>D</value></transactionAcquiredDisposedCode>
+            </transactionAmounts>
+            <exerciseDate><footnoteId id=\"F3\"/></exerciseDate>
+            <expirationDate><value>2028-02-01</value></expirationDate>
+            <underlyingSecurity>
+                <underlyingSecurityTitle><value>Common Stock</value></underlyingSecurityTitle>
+                <underlyingSecurityShares><value>1500</value></underlyingSecurityShares>
+            </underlyingSecurity>
+            <postTransactionAmounts>
+                <sharesOwnedFollowingTransaction><value>6000</value></sharesOwnedFollowingTransaction>
+            </postTransactionAmounts>
+            <ownershipNature>
+                <directOrIndirectOwnership><value>D</value></directOrIndirectOwnership>
+            </ownershipNature>
+        </derivativeTransaction>
+    </derivativeTable>
+    <footnotes>
+        <footnote id=\"F1\">Sale made pursuant to a Rule 10b5-1 trading plan.</footnote>
+        <footnote id=\"F2\">Weighted average price.</footnote>
+        <footnote id=\"F3\">The option vests in four equal annual installments.</footnote>
+    </footnotes>
+    <ownerSignature>
+        <signatureName>/s/ Jane Doe</signatureName>
+        <signatureDate>2021-01-19</signatureDate>
+    </ownerSignature>
+</ownershipDocument>
+</XML>
+</TEXT>
+</DOCUMENT>
+</SUBMISSION>
+";
+
+#[test]
+fn form_4() {
+    let submission = parse_submission_from_str(FORM_4).unwrap();
+    let ownership = submission.ownership().unwrap().unwrap();
+
+    assert_eq!(ownership.document_type, "4");
+    assert_eq!(
+        ownership.period_of_report,
+        Some(NaiveDate::from_ymd_opt(2021, 1, 15).unwrap())
+    );
+    assert_eq!(ownership.not_subject_to_section_16, Some(false));
+    assert_eq!(ownership.issuer.cik, Cik(12345));
+    assert_eq!(ownership.issuer.trading_symbol.as_deref(), Some("ACME"));
+
+    let owner = &ownership.reporting_owners[0];
+    assert_eq!(owner.cik, Cik(1234567));
+    assert!(owner.relationship.is_officer);
+    assert!(!owner.relationship.is_ten_percent_owner);
+    assert_eq!(
+        owner.relationship.officer_title.as_deref(),
+        Some("Chief Financial Officer")
+    );
+    assert_eq!(
+        owner.address.as_ref().unwrap().city.as_deref(),
+        Some("SPRINGFIELD")
+    );
+
+    assert_eq!(ownership.signatures[0].name, "/s/ Jane Doe");
+}
+
+#[test]
+fn transactions_and_holdings() {
+    let submission = parse_submission_from_str(FORM_4).unwrap();
+    let ownership = submission.ownership().unwrap().unwrap();
+
+    let sale = &ownership.non_derivative_transactions[0];
+    assert_eq!(sale.security_title.as_deref(), Some("Common Stock"));
+    assert_eq!(sale.coding.as_ref().unwrap().code, "S");
+    assert_eq!(sale.amounts.shares, Some(1500.0));
+    assert_eq!(sale.amounts.price_per_share, Some(42.17));
+    assert_eq!(
+        sale.amounts.acquired_disposed,
+        Some(AcquiredDisposed::Disposed)
+    );
+    assert_eq!(sale.post_transaction.shares_owned, Some(20000.0));
+    assert_eq!(
+        sale.ownership.direct_or_indirect,
+        Some(DirectOrIndirect::Direct)
+    );
+    assert_eq!(sale.footnote_ids, vec!["F1", "F2"]);
+    assert_eq!(
+        ownership.footnote("F2").unwrap().text,
+        "Weighted average price."
+    );
+
+    let holding = &ownership.non_derivative_holdings[0];
+    assert_eq!(holding.ownership.nature.as_deref(), Some("By Trust"));
+
+    let exercise = &ownership.derivative_transactions[0];
+    assert_eq!(exercise.conversion_or_exercise_price, Some(30.0));
+    assert_eq!(exercise.exercise_date, None);
+    assert_eq!(
+        exercise.expiration_date,
+        Some(NaiveDate::from_ymd_opt(2028, 2, 1).unwrap())
+    );
+    assert_eq!(exercise.underlying.shares, Some(1500.0));
+    assert_eq!(exercise.footnote_ids, vec!["F3"]);
+    assert!(ownership.derivative_holdings.is_empty());
+}
+
+#[test]
+fn linked_to_header() {
+    let submission = parse_submission_from_str(FORM_4).unwrap();
+    let ownership = submission.ownership().unwrap().unwrap();
+
+    let owner = ownership.reporting_owners[0].company(&submission).unwrap();
+    assert_eq!(
+        owner.owner_data.as_ref().unwrap().conformed_name,
+        "DOE JANE"
+    );
+    let issuer = ownership.issuer.company(&submission).unwrap();
+    assert_eq!(
+        issuer.company_data.as_ref().unwrap().conformed_name,
+        "ACME CORP"
+    );
+}
+
+#[test]
+fn other_forms_have_no_ownership_document() {
+    let submission = parse_submission_from_str(&FORM_4.replace("<TYPE>4", "<TYPE>8-K")).unwrap();
+
+    assert!(submission.ownership().is_none());
+}
+
+#[test]
+fn short_month_first_dates() {
+    let ownership = OwnershipDocument::parse(
+        "<ownershipDocument>
+<documentType>4</documentType>
+<periodOfReport>1-5-2021</periodOfReport>
+<issuer><issuerCik>12345</issuerCik></issuer>
+</ownershipDocument>",
+    )
+    .unwrap();
+
+    assert_eq!(
+        ownership.period_of_report,
+        Some(NaiveDate::from_ymd_opt(2021, 1, 5).unwrap())
+    );
+}
+
+#[test]
+fn bad_values_are_errors() {
+    let error = OwnershipDocument::parse(
+        "<ownershipDocument>
+<documentType>4</documentType>
+<issuer><issuerCik>12345</issuerCik></issuer>
+<nonDerivativeTable><nonDerivativeTransaction>
+<transactionAmounts><transactionShares><value>many</value></transactionShares></transactionAmounts>
+</nonDerivativeTransaction></nonDerivativeTable>
+</ownershipDocument>",
+    )
+    .unwrap_err();
+
+    assert_eq!(
+        error.to_string(),
+        "malformed XML document: bad value \"many\" for <transactionShares>"
+    );
+}
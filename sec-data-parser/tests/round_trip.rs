@@ -0,0 +1,592 @@
+// Checks that writing a submission and parsing it again reproduces the same submission, both for
+// generated submissions and, when SEC_DATA_PARSER_CORPUS is set, for every filing in a directory.
+
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+use proptest::collection::vec;
+use proptest::option::of;
+use proptest::prelude::*;
+use sec_data_parser::*;
+use std::path::{Path, PathBuf};
+
+fn round_trip(submission: &Submission) -> Result<Submission> {
+    let bytes = submission_to_bytes(submission).expect("submission could not be written");
+    parse_submission_from_bytes(&bytes)
+}
+
+// Header values run to the next tag and are trimmed, so they cannot contain `<` or start or end
+// with whitespace.
+fn value() -> impl Strategy<Value = String> {
+    "[A-Za-z0-9.,&/'-]([A-Za-z0-9 .,&/'()-]{0,30}[A-Za-z0-9.,&/'-])?"
+}
+
+fn date() -> impl Strategy<Value = NaiveDate> {
+    (1993i32..2030, 1u32..=365).prop_map(|(year, day)| NaiveDate::from_yo(year, day))
+}
+
+fn date_time() -> impl Strategy<Value = NaiveDateTime> {
+    (date(), 0u32..86400)
+        .prop_map(|(date, secs)| date.and_time(NaiveTime::from_num_seconds_from_midnight(secs, 0)))
+}
+
+fn month_day() -> impl Strategy<Value = MonthDayPair> {
+    (1u32..=12, 1u32..=31)
+        .prop_map(|(month, day)| MonthDayPair::parse(&format!("{:02}{:02}", month, day)).unwrap())
+}
+
+fn cik() -> impl Strategy<Value = Cik> {
+    (0u64..10_000_000_000).prop_map(Cik)
+}
+
+fn sic() -> impl Strategy<Value = Sic> {
+    (0u16..10_000).prop_map(Sic)
+}
+
+fn accession_number() -> impl Strategy<Value = AccessionNumber> {
+    (0u64..10_000_000_000, 0u8..100, 0u32..1_000_000).prop_map(|(filer_id, year, seq)| {
+        AccessionNumber {
+            filer_id,
+            year,
+            seq,
+        }
+    })
+}
+
+fn file_number() -> impl Strategy<Value = FileNumber> {
+    ("[0-9]{1,3}", "[0-9]{4,6}(-[0-9]{2})?")
+        .prop_map(|(prefix, number)| FileNumber { prefix, number })
+}
+
+prop_compose! {
+    fn filing_values()(
+        form_type in value(),
+        act in of(value()),
+        file_number in of(file_number()),
+        film_number in of(value()),
+    ) -> FilingValues {
+        FilingValues {
+            form_type,
+            act,
+            file_number,
+            film_number,
+            extra: Vec::new(),
+            extra_containers: Vec::new(),
+        }
+    }
+}
+
+prop_compose! {
+    fn company_data()(
+        conformed_name in value(),
+        cik in cik(),
+        irs_number in of("[0-9]{9}"),
+        state_of_incorporation in of("[A-Z][A-Z0-9]"),
+        fiscal_year_end in of(month_day()),
+        assigned_sic in of(sic()),
+        relationship in of(value()),
+    ) -> CompanyData {
+        CompanyData {
+            conformed_name,
+            cik,
+            irs_number,
+            state_of_incorporation,
+            fiscal_year_end,
+            assigned_sic,
+            relationship,
+            extra: Vec::new(),
+            extra_containers: Vec::new(),
+        }
+    }
+}
+
+prop_compose! {
+    fn address()(
+        street1 in of(value()),
+        street2 in of(value()),
+        city in of(value()),
+        state in of("[A-Z][A-Z0-9]"),
+        zip in of("[0-9]{5}"),
+        phone in of(value()),
+    ) -> Address {
+        Address {
+            street1,
+            street2,
+            city,
+            state,
+            zip,
+            phone,
+            extra: Vec::new(),
+            extra_containers: Vec::new(),
+        }
+    }
+}
+
+prop_compose! {
+    fn former_company()(former_conformed_name in value(), date_changed in date()) -> FormerCompany {
+        FormerCompany {
+            former_conformed_name,
+            date_changed,
+            extra: Vec::new(),
+            extra_containers: Vec::new(),
+        }
+    }
+}
+
+prop_compose! {
+    fn company()(
+        company_data in of(company_data()),
+        filing_values in vec(filing_values(), 0..3),
+        business_address in of(address()),
+        mail_address in of(address()),
+        owner_data in of(company_data()),
+        former_name in vec(former_company(), 0..2),
+        former_company in vec(former_company(), 0..2),
+    ) -> Company {
+        Company {
+            company_data,
+            filing_values,
+            business_address,
+            mail_address,
+            owner_data,
+            former_name,
+            former_company,
+            extra: Vec::new(),
+            extra_containers: Vec::new(),
+        }
+    }
+}
+
+// Unwrapped text is trimmed and must not look like a wrapper or uuencoded data.
+fn plain_text() -> impl Strategy<Value = String> {
+    "[A-Za-z0-9]([ -;=-~\n\té£€]{0,200}[A-Za-z0-9])?"
+        .prop_filter("looks uuencoded", |st| !st.starts_with("begin 644"))
+}
+
+// Text inside a wrapper is kept exactly, including surrounding whitespace.
+fn wrapped_text() -> impl Strategy<Value = String> {
+    "\n?<[a-z]{1,8}>[ -;=-~\n]{0,200}</[a-z]{1,8}>\n?"
+}
+
+fn binary() -> impl Strategy<Value = DocumentBody> {
+    (
+        "[a-z0-9_-]{1,12}\\.(jpg|gif|pdf|xls|zip)",
+        vec(any::<u8>(), 0..400),
+    )
+        .prop_map(|(filename, data)| DocumentBody::BinaryData(filename, data))
+}
+
+fn typed_data() -> impl Strategy<Value = TypedData> {
+    prop_oneof![
+        plain_text().prop_map(|st| (DataType::Plaintext, DocumentBody::Text(st))),
+        binary().prop_map(|body| (DataType::Plaintext, body)),
+        wrapped_text().prop_map(|st| (DataType::Xml, DocumentBody::Text(st))),
+        wrapped_text().prop_map(|st| (DataType::Xbrl, DocumentBody::Text(st))),
+        binary().prop_map(|body| (DataType::Pdf, body)),
+    ]
+    .prop_map(|(data_type, body)| TypedData { data_type, body })
+}
+
+// Text which is only valid once encoded as Windows-1252: a high byte followed by ASCII is never
+// valid UTF-8.
+fn windows_1252_text() -> impl Strategy<Value = TypedData> {
+    (
+        "[A-Za-z0-9]{1,20}",
+        "[éñüç£]",
+        "[A-Za-z0-9][ -;=-~]{0,40}[A-Za-z0-9]",
+    )
+        .prop_map(|(head, accent, tail)| TypedData {
+            data_type: DataType::Plaintext,
+            body: DocumentBody::Text(format!("{}{}{}", head, accent, tail)),
+        })
+}
+
+fn body() -> impl Strategy<Value = (Option<Body>, Option<TextEncoding>)> {
+    prop_oneof![
+        Just((None, None)),
+        typed_data().prop_map(|t| (Some(Body::Loaded(t)), Some(TextEncoding::Utf8))),
+        windows_1252_text().prop_map(|t| (Some(Body::Loaded(t)), Some(TextEncoding::Windows1252))),
+    ]
+}
+
+prop_compose! {
+    fn document()(
+        doc_type in value(),
+        sequence in 1u32..1000,
+        filename in of("[a-z0-9_-]{1,12}\\.(htm|txt|xml|jpg)"),
+        (body, encoding) in body(),
+        description in of(value()),
+        flawed in any::<bool>(),
+    ) -> Document {
+        Document {
+            doc_type,
+            sequence,
+            filename,
+            body,
+            encoding,
+            description,
+            flawed,
+            extra: Vec::new(),
+            extra_containers: Vec::new(),
+        }
+    }
+}
+
+prop_compose! {
+    fn class_contract()(
+        class_contract_id in "C[0-9]{9}",
+        class_contract_name in value(),
+        class_contract_ticker_symbol in of("[A-Z]{4,5}"),
+    ) -> ClassContract {
+        ClassContract {
+            class_contract_id,
+            class_contract_name,
+            class_contract_ticker_symbol,
+            extra: Vec::new(),
+            extra_containers: Vec::new(),
+        }
+    }
+}
+
+prop_compose! {
+    fn series()(
+        owner_cik in of(cik()),
+        series_id in "S[0-9]{9}",
+        series_name in value(),
+        class_contracts in vec(class_contract(), 0..3),
+    ) -> Series {
+        Series {
+            owner_cik,
+            series_id,
+            series_name,
+            class_contracts,
+            extra: Vec::new(),
+            extra_containers: Vec::new(),
+        }
+    }
+}
+
+prop_compose! {
+    fn merger()(
+        acquiring_cik in cik(),
+        acquiring_series in series(),
+        target_data in vec(
+            (cik(), vec(series(), 0..2))
+                .prop_map(|(cik, series)| TargetData {
+                    cik,
+                    series,
+                    extra: Vec::new(),
+                    extra_containers: Vec::new(),
+                }),
+            0..2,
+        ),
+    ) -> Merger {
+        Merger {
+            acquiring_data: AcquiringData {
+                cik: acquiring_cik,
+                series: acquiring_series,
+                extra: Vec::new(),
+                extra_containers: Vec::new(),
+            },
+            target_data,
+            extra: Vec::new(),
+            extra_containers: Vec::new(),
+        }
+    }
+}
+
+prop_compose! {
+    fn series_and_classes_contracts_data()(
+        existing in of(vec(series(), 0..3)),
+        mergers in of(vec(merger(), 0..2)),
+        new in of((of(cik()), vec(series(), 0..2), vec(series(), 0..2))),
+    ) -> SeriesAndClassesContractsData {
+        SeriesAndClassesContractsData {
+            existing_series_and_classes_contracts: existing
+                .map(|series| SeriesAndClassesContracts {
+                    series,
+                    extra: Vec::new(),
+                    extra_containers: Vec::new(),
+                }),
+            merger_series_and_classes_contracts: mergers
+                .map(|mergers| MergerSeriesAndClassContracts {
+                    mergers,
+                    extra: Vec::new(),
+                    extra_containers: Vec::new(),
+                }),
+            new_series_and_classes_contracts: new.map(
+                |(owner_cik, new_series, new_classes_contract)| NewSeriesAndClassesContracts {
+                    owner_cik,
+                    new_series,
+                    new_classes_contract,
+                    extra: Vec::new(),
+                    extra_containers: Vec::new(),
+                },
+            ),
+            extra: Vec::new(),
+            extra_containers: Vec::new(),
+        }
+    }
+}
+
+prop_compose! {
+    fn submission()(
+        (
+            accession_number,
+            filing_type,
+            public_document_count,
+            items,
+            filing_date,
+            date_of_filing_date_change,
+        ) in (
+            accession_number(),
+            value(),
+            of(0usize..10),
+            vec(value(), 0..3),
+            date(),
+            of(date()),
+        ).boxed(),
+        (effectiveness_date, period, filers, documents, series_and_classes_contracts_data)
+            in (
+                of(date()),
+                of(date()),
+                vec(company(), 0..3),
+                vec(document(), 0..4),
+                of(series_and_classes_contracts_data()),
+            ).boxed(),
+        (reporting_owners, issuer, group_members, subject_company, filed_by, reference_462b)
+            in (
+                vec(company(), 0..2),
+                of(company()),
+                vec(value(), 0..2),
+                vec(company(), 0..2),
+                of(company()),
+                of(value()),
+            ).boxed(),
+        (
+            is_filer_a_new_registrant,
+            is_filer_a_well_known_seasoned_issuer,
+            filed_pursuant_to_general_instruction_a2,
+            is_fund_24f2_eligible,
+            action_date,
+            received_date,
+            ma_i_individual,
+            abs_rule,
+            period_start,
+            no_quarterly_activity,
+            no_annual_activity,
+        ) in (
+            of(any::<bool>()),
+            of(any::<bool>()),
+            of(any::<bool>()),
+            of(any::<bool>()),
+            of(date()),
+            of(date()),
+            of(value()),
+            of(value()),
+            of(date()),
+            of(any::<bool>()),
+            of(any::<bool>()),
+        ).boxed(),
+        (
+            abs_asset_class,
+            depositor_cik,
+            sponsor_cik,
+            category,
+            registered_entity,
+            depositor,
+            securitizer,
+            references_429,
+            securitizer_cik,
+            issuing_entity_cik,
+            issuing_entity_name,
+        ) in (
+            of(value()),
+            of(cik()),
+            of(cik()),
+            of(value()),
+            of(any::<bool>()),
+            of(company()),
+            of(company()),
+            of(value()),
+            of(cik()),
+            of(cik()),
+            of(value()),
+        ).boxed(),
+        (
+            paper,
+            confirming_copy,
+            securitizer_file_number,
+            depositor_file_number,
+            timestamp,
+            private_to_public,
+            filed_for,
+            public_reference_acc,
+            public_rel_date,
+            deletion,
+            correction,
+        ) in (
+            any::<bool>(),
+            any::<bool>(),
+            of(file_number()),
+            of(file_number()),
+            of(date_time()),
+            any::<bool>(),
+            vec(company(), 0..2),
+            of(value()),
+            of(date()),
+            any::<bool>(),
+            any::<bool>(),
+        ).boxed(),
+        sros in of(value()),
+        previous_accession_number in of(accession_number()),
+    ) -> Submission {
+        Submission {
+            accession_number,
+            filing_type,
+            public_document_count,
+            items,
+            filing_date,
+            date_of_filing_date_change,
+            effectiveness_date,
+            period,
+            filers,
+            documents,
+            series_and_classes_contracts_data,
+            reporting_owners,
+            issuer,
+            group_members,
+            subject_company,
+            filed_by,
+            reference_462b,
+            is_filer_a_new_registrant,
+            is_filer_a_well_known_seasoned_issuer,
+            filed_pursuant_to_general_instruction_a2,
+            is_fund_24f2_eligible,
+            action_date,
+            received_date,
+            ma_i_individual,
+            abs_rule,
+            period_start,
+            no_quarterly_activity,
+            no_annual_activity,
+            abs_asset_class,
+            depositor_cik,
+            sponsor_cik,
+            category,
+            registered_entity,
+            depositor,
+            securitizer,
+            references_429,
+            securitizer_cik,
+            issuing_entity_cik,
+            issuing_entity_name,
+            paper,
+            confirming_copy,
+            securitizer_file_number,
+            depositor_file_number,
+            timestamp,
+            private_to_public,
+            filed_for,
+            public_reference_acc,
+            public_rel_date,
+            deletion,
+            correction,
+            sros,
+            previous_accession_number,
+            extra: Vec::new(),
+            extra_containers: Vec::new(),
+        }
+    }
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(64))]
+
+    #[test]
+    fn submissions_round_trip(submission in submission()) {
+        prop_assert_eq!(round_trip(&submission)?, submission);
+    }
+
+    #[test]
+    fn bodies_round_trip(typed_data in typed_data()) {
+        let text = typed_data.to_text_block(Some(TextEncoding::Utf8));
+        let (parsed, _) = TypedData::from_bytes(&text, DecodingPolicy::Strict)?;
+        prop_assert_eq!(parsed, typed_data);
+    }
+}
+
+fn corpus_files(dir: &Path, files: &mut Vec<PathBuf>) {
+    for entry in std::fs::read_dir(dir).expect("could not read corpus directory") {
+        let path = entry.expect("could not read corpus directory").path();
+        if path.is_dir() {
+            corpus_files(&path, files);
+        } else {
+            files.push(path);
+        }
+    }
+}
+
+// Counts corpus submissions as they are parsed, so that only one is held in memory at a time.
+#[derive(Default)]
+struct Tally {
+    submissions: usize,
+    unparsed: usize,
+    failures: Vec<String>,
+}
+
+impl Tally {
+    fn check(&mut self, name: &str, submission: Result<Submission>) {
+        self.submissions += 1;
+        let submission = match submission {
+            Ok(submission) => submission,
+            Err(_) => {
+                self.unparsed += 1;
+                return;
+            }
+        };
+        match round_trip(&submission) {
+            Ok(ref written) if *written == submission => {}
+            Ok(_) => self
+                .failures
+                .push(format!("{}: round trip changed the submission", name)),
+            Err(e) => self.failures.push(format!("{}: {}", name, e)),
+        }
+    }
+}
+
+// Runs over every `.nc` file and feed archive under SEC_DATA_PARSER_CORPUS. Filings which do not
+// parse in the first place are counted but not treated as failures.
+#[test]
+fn corpus_round_trips() {
+    let dir = match std::env::var_os("SEC_DATA_PARSER_CORPUS") {
+        Some(dir) => PathBuf::from(dir),
+        None => return,
+    };
+    let mut files = Vec::new();
+    corpus_files(&dir, &mut files);
+
+    let mut tally = Tally::default();
+    for path in files {
+        let name = path.display().to_string();
+        if name.ends_with(".nc") {
+            tally.check(&name, parse_submission(&path));
+        } else if name.ends_with(".tar.gz") {
+            let mut archive = FeedArchive::open(&path).expect("could not open archive");
+            for (entry, submission) in archive.submissions().expect("could not read archive") {
+                tally.check(&format!("{}:{}", name, entry), submission);
+            }
+        }
+    }
+
+    let Tally {
+        submissions,
+        unparsed,
+        failures,
+    } = tally;
+    println!(
+        "{} submissions, {} did not parse, {} failed to round trip",
+        submissions,
+        unparsed,
+        failures.len()
+    );
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
@@ -0,0 +1,53 @@
+use sec_data_parser::*;
+
+#[test]
+fn codes_are_looked_up() {
+    let software = Sic(7372).industry().unwrap();
+    assert_eq!(software.title, "SERVICES-PREPACKAGED SOFTWARE");
+    assert_eq!(software.office, Office::Technology);
+    assert_eq!(software.office.to_string(), "Office of Technology");
+
+    let pharma = Sic::parse("2834").unwrap().industry().unwrap();
+    assert_eq!(pharma.sic, Sic(2834));
+    assert_eq!(pharma.office, Office::LifeSciences);
+
+    assert_eq!(
+        Sic(6798).industry().unwrap().office,
+        Office::RealEstateAndConstruction
+    );
+    assert_eq!(
+        Sic(100).industry().unwrap().title,
+        "AGRICULTURAL PRODUCTION-CROPS"
+    );
+    assert_eq!(
+        Sic(9995).industry().unwrap().title,
+        "NON-OPERATING ESTABLISHMENTS"
+    );
+
+    assert_eq!(Sic(1234).industry(), None);
+}
+
+#[test]
+fn company_industry() {
+    let submission = parse_submission_from_str(
+        "<SUBMISSION>
+<ACCESSION-NUMBER>0000950123-21-001234
+<TYPE>10-K
+<FILING-DATE>20210115
+<FILER>
+<COMPANY-DATA>
+<CONFORMED-NAME>ACME BANCORP
+<CIK>0000012345
+<ASSIGNED-SIC>6022
+</COMPANY-DATA>
+</FILER>
+</SUBMISSION>
+",
+    )
+    .unwrap();
+    let company = submission.filers[0].company_data.as_ref().unwrap();
+
+    let industry = company.sic().unwrap();
+    assert_eq!(industry.title, "STATE COMMERCIAL BANKS");
+    assert_eq!(industry.office, Office::Finance);
+}
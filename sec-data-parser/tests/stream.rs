@@ -0,0 +1,37 @@
+use sec_data_parser::*;
+
+const TRAILING_HEADER: &str = "<SUBMISSION>
+<ACCESSION-NUMBER>0000950123-21-001234
+<TYPE>8-K
+<PUBLIC-DOCUMENT-COUNT>1
+<FILING-DATE>20210115
+<DOCUMENT>
+<TYPE>8-K
+<SEQUENCE>1
+<TEXT>
+Hello
+</TEXT>
+</DOCUMENT>
+<ITEMS>2.02
+</SUBMISSION>
+";
+
+#[test]
+fn header_values_after_documents() {
+    let from_bytes = parse_submission_from_str(TRAILING_HEADER).unwrap();
+    let from_reader = parse_submission_from_reader(TRAILING_HEADER.as_bytes()).unwrap();
+
+    assert_eq!(from_reader.items, vec!["2.02"]);
+    assert_eq!(from_reader.documents.len(), 1);
+    assert_eq!(from_reader, from_bytes);
+}
+
+#[test]
+fn trailing_values_are_checked_like_the_header() {
+    let duplicate = TRAILING_HEADER.replace("<ITEMS>2.02", "<FILING-DATE>20210116");
+
+    let from_bytes = parse_submission_from_str(&duplicate).unwrap_err();
+    let from_reader = parse_submission_from_reader(duplicate.as_bytes()).unwrap_err();
+
+    assert_eq!(from_reader.to_string(), from_bytes.to_string());
+}
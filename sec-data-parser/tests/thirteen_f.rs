@@ -0,0 +1,275 @@
+use sec_data_parser::thirteen_f::*;
+use sec_data_parser::*;
+
+const HEADER: &str = "<SUBMISSION>
+<ACCESSION-NUMBER>0001104659-21-004321
+<TYPE>13F-HR
+<PERIOD>20201231
+<FILING-DATE>20210210
+<FILER>
+<COMPANY-DATA>
+<CONFORMED-NAME>EXAMPLE CAPITAL MANAGEMENT LLC
+<CIK>0001000001
+</COMPANY-DATA>
+</FILER>
+";
+
+#[test]
+fn xml_information_table() {
+    let submission = parse_submission_from_str(&format!(
+        "{}<DOCUMENT>
+<TYPE>13F-HR
+<SEQUENCE>1
+<FILENAME>primary_doc.xml
+<TEXT>
+<XML>
+<edgarSubmission></edgarSubmission>
+</XML>
+</TEXT>
+</DOCUMENT>
+<DOCUMENT>
+<TYPE>INFORMATION TABLE
+<SEQUENCE>2
+<FILENAME>infotable.xml
+<TEXT>
+<XML>
+<?xml version=\"1.0\" encoding=\"UTF-8\"?>
+<ns1:informationTable xmlns:ns1=\"http://www.sec.gov/edgar/document/thirteenf/informationtable\">
+  <ns1:infoTable>
+    <ns1:nameOfIssuer>APPLE INC</ns1:nameOfIssuer>
+    <ns1:titleOfClass>COM</ns1:titleOfClass>
+    <ns1:cusip>037833100</ns1:cusip>
+    <ns1:value>13269</ns1:value>
+    <ns1:shrsOrPrnAmt>
+      <ns1:sshPrnamt>100000</ns1:sshPrnamt>
+      <ns1:sshPrnamtType>SH</ns1:sshPrnamtType>
+    </ns1:shrsOrPrnAmt>
+    <ns1:investmentDiscretion>SOLE</ns1:investmentDiscretion>
+    <ns1:votingAuthority>
+      <ns1:Sole>90000</ns1:Sole>
+      <ns1:Shared>0</ns1:Shared>
+      <ns1:None>10000</ns1:None>
+    </ns1:votingAuthority>
+  </ns1:infoTable>
+  <ns1:infoTable>
+    <ns1:nameOfIssuer>SPDR S&amp;P 500 ETF TR</ns1:nameOfIssuer>
+    <ns1:titleOfClass>TR UNIT</ns1:titleOfClass>
+    <ns1:cusip>78462F103</ns1:cusip>
+    <ns1:value>3739</ns1:value>
+    <ns1:shrsOrPrnAmt>
+      <ns1:sshPrnamt>10000</ns1:sshPrnamt>
+      <ns1:sshPrnamtType>SH</ns1:sshPrnamtType>
+    </ns1:shrsOrPrnAmt>
+    <ns1:putCall>Put</ns1:putCall>
+    <ns1:investmentDiscretion>DFND</ns1:investmentDiscretion>
+    <ns1:otherManager>1</ns1:otherManager>
+    <ns1:votingAuthority>
+      <ns1:Sole>0</ns1:Sole>
+      <ns1:Shared>10000</ns1:Shared>
+      <ns1:None>0</ns1:None>
+    </ns1:votingAuthority>
+  </ns1:infoTable>
+</ns1:informationTable>
+</XML>
+</TEXT>
+</DOCUMENT>
+</SUBMISSION>
+",
+        HEADER
+    ))
+    .unwrap();
+
+    let holdings = submission.information_table().unwrap().unwrap();
+    assert_eq!(holdings.len(), 2);
+    assert_eq!(
+        holdings[0],
+        Holding {
+            issuer_name: "APPLE INC".to_string(),
+            class: "COM".to_string(),
+            cusip: "037833100".to_string(),
+            value: 13269,
+            shares: 100000,
+            shares_type: Some(SharesType::Shares),
+            put_call: None,
+            discretion: Some(InvestmentDiscretion::Sole),
+            other_managers: None,
+            voting: Some(VotingAuthority {
+                sole: 90000,
+                shared: 0,
+                none: 10000,
+            }),
+        }
+    );
+    assert_eq!(holdings[1].issuer_name, "SPDR S&P 500 ETF TR");
+    assert_eq!(holdings[1].put_call, Some(PutCall::Put));
+    assert_eq!(
+        holdings[1].discretion,
+        Some(InvestmentDiscretion::SharedDefined)
+    );
+    assert_eq!(holdings[1].other_managers.as_deref(), Some("1"));
+}
+
+#[test]
+fn legacy_text_table() {
+    let submission = parse_submission_from_str(&format!(
+        "{}<DOCUMENT>
+<TYPE>13F-HR
+<SEQUENCE>1
+<TEXT>
+                              FORM 13F INFORMATION TABLE
+
+                                                    VALUE    SHARES/ SH/ PUT/ INVSTMT  OTHER      VOTING AUTHORITY
+NAME OF ISSUER            TITLE OF CLASS  CUSIP     (x$1000) PRN AMT PRN CALL DSCRETN MANAGERS   SOLE   SHARED   NONE
+------------------------  -------------- --------- -------- ------- --- ---- ------- -------- ------- ------- -------
+<S>                       <C>            <C>       <C>      <C>     <C> <C>  <C>     <C>      <C>     <C>     <C>
+ABBOTT LABS               COM            002824100      1,234  25,000 SH       SOLE                25,000       0       0
+BERKSHIRE HATHAWAY INC DEL  CL B         084670207        802  10,000 SH       DEFINED 1 2             0  10,000       0
+GENERAL ELECTRIC CO       COM            369604103        150  10,000 SH  CALL OTHER                    0       0  10,000
+                                                    ---------
+                                                        2,186
+</TEXT>
+</DOCUMENT>
+</SUBMISSION>
+",
+        HEADER
+    ))
+    .unwrap();
+
+    let holdings = submission.information_table().unwrap().unwrap();
+    assert_eq!(holdings.len(), 3);
+
+    assert_eq!(holdings[0].issuer_name, "ABBOTT LABS");
+    assert_eq!(holdings[0].class, "COM");
+    assert_eq!(holdings[0].cusip, "002824100");
+    assert_eq!(holdings[0].value, 1234);
+    assert_eq!(holdings[0].shares, 25000);
+    assert_eq!(holdings[0].discretion, Some(InvestmentDiscretion::Sole));
+    assert_eq!(holdings[0].other_managers, None);
+    assert_eq!(holdings[0].voting.unwrap().sole, 25000);
+
+    assert_eq!(holdings[1].issuer_name, "BERKSHIRE HATHAWAY INC DEL");
+    assert_eq!(holdings[1].class, "CL B");
+    assert_eq!(holdings[1].other_managers.as_deref(), Some("1 2"));
+    assert_eq!(holdings[1].voting.unwrap().shared, 10000);
+
+    assert_eq!(holdings[2].put_call, Some(PutCall::Call));
+    assert_eq!(
+        holdings[2].discretion,
+        Some(InvestmentDiscretion::SharedOther)
+    );
+}
+
+#[test]
+fn other_forms_have_no_information_table() {
+    let submission = parse_submission_from_str(&format!(
+        "{}</SUBMISSION>\n",
+        HEADER.replace("13F-HR", "13F-NT")
+    ))
+    .unwrap();
+
+    assert!(submission.information_table().is_none());
+}
+
+#[test]
+fn bad_values_are_errors() {
+    let error = parse_xml(
+        "<informationTable><infoTable>
+<nameOfIssuer>APPLE INC</nameOfIssuer>
+<cusip>037833100</cusip>
+<value>n/a</value>
+<shrsOrPrnAmt><sshPrnamt>1</sshPrnamt></shrsOrPrnAmt>
+</infoTable></informationTable>",
+    )
+    .unwrap_err();
+
+    assert_eq!(
+        error.to_string(),
+        "malformed XML document: bad value \"n/a\" for <value>"
+    );
+}
+
+#[test]
+fn split_cusips_are_joined() {
+    let holdings = parse_text(
+        "APPLE INC                 COM            037833 10 0    13,269  100,000 SH       SOLE      90,000       0  10,000",
+    )
+    .unwrap()
+    .holdings;
+
+    assert_eq!(holdings.len(), 1);
+    assert_eq!(holdings[0].issuer_name, "APPLE INC");
+    assert_eq!(holdings[0].class, "COM");
+    assert_eq!(holdings[0].cusip, "037833100");
+    assert_eq!(holdings[0].value, 13269);
+    assert_eq!(holdings[0].shares, 100000);
+    assert_eq!(holdings[0].voting.unwrap().none, 10000);
+}
+
+#[test]
+fn unreadable_rows_are_kept_apart() {
+    let error =
+        parse_text("FORM 13F INFORMATION TABLE\n\nNo holdings this quarter.\n").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "malformed document body: information table has no holdings"
+    );
+
+    let table = parse_text(
+        "ABBOTT LABS               COM            002824100      1,234  25,000 SH       SOLE\n\
+         APPLE INC                 COM            037833100        n/a     n/a SH       SOLE\n",
+    )
+    .unwrap();
+    assert_eq!(table.holdings.len(), 1);
+    assert_eq!(table.holdings[0].issuer_name, "ABBOTT LABS");
+    assert_eq!(
+        table.unread_rows,
+        vec!["APPLE INC                 COM            037833100        n/a     n/a SH       SOLE"]
+    );
+}
+
+#[test]
+fn cover_page_is_not_read_as_holdings() {
+    let submission = parse_submission_from_str(&format!(
+        "{}<DOCUMENT>
+<TYPE>13F-HR
+<SEQUENCE>1
+<TEXT>
+                              FORM 13F COVER PAGE
+
+Name:     Example Capital Management LLC
+Address:  1 Main Street
+          Springfield, IL  627011232 100 20
+
+                              FORM 13F SUMMARY PAGE
+
+Form 13F Information Table Entry Total:         1
+Form 13F Information Table Value Total:     1,234
+
+                              FORM 13F INFORMATION TABLE
+
+NAME OF ISSUER            TITLE OF CLASS  CUSIP     (x$1000) PRN AMT PRN CALL DSCRETN MANAGERS   SOLE   SHARED   NONE
+ABBOTT LABS               COM            002824100      1,234  25,000 SH       SOLE                25,000       0       0
+</TEXT>
+</DOCUMENT>
+</SUBMISSION>
+",
+        HEADER
+    ))
+    .unwrap();
+
+    let holdings = submission.information_table().unwrap().unwrap();
+    assert_eq!(holdings.len(), 1);
+    assert_eq!(holdings[0].issuer_name, "ABBOTT LABS");
+}
+
+#[test]
+fn all_digit_cusips_need_a_check_digit() {
+    let line = |cusip: &str| {
+        format!(
+            "ABBOTT LABS               COM            {}      1,234  25,000 SH       SOLE",
+            cusip
+        )
+    };
+    assert_eq!(parse_text(&line("002824100")).unwrap().holdings.len(), 1);
+    assert!(parse_text(&line("627011234")).is_err());
+}
@@ -0,0 +1,74 @@
+use sec_data_parser::*;
+
+const SUBMISSION: &str = "<SUBMISSION>
+<ACCESSION-NUMBER>0000950123-21-001234
+<TYPE>10-K
+<PUBLIC-DOCUMENT-COUNT>4
+<FILING-DATE>20210115
+<DOCUMENT>
+<TYPE>10-K
+<SEQUENCE>1
+<FILENAME>d10k.htm
+<TEXT>
+Annual report
+</TEXT>
+</DOCUMENT>
+<DOCUMENT>
+<TYPE>EX-21
+<SEQUENCE>2
+<FILENAME>dex21.htm
+<TEXT>
+Subsidiaries
+</TEXT>
+</DOCUMENT>
+<DOCUMENT>
+<TYPE>EX-23
+<SEQUENCE>3
+<FILENAME>dex23.htm
+<TEXT>
+Consent
+</TEXT>
+</DOCUMENT>
+</SUBMISSION>
+";
+
+#[test]
+fn count_is_exposed() {
+    let submission = parse_submission_from_str(SUBMISSION).unwrap();
+
+    assert_eq!(submission.public_document_count, Some(4));
+}
+
+#[test]
+fn findings_are_reported() {
+    let mut submission = parse_submission_from_str(SUBMISSION).unwrap();
+    submission.documents[1].sequence = 4;
+    submission.documents[2].sequence = 4;
+    submission.documents[2].filename = Some("d10k.htm".to_string());
+    submission.filing_type = "10-K/A".to_string();
+
+    assert_eq!(
+        submission.validate(),
+        vec![
+            Finding::DocumentCount {
+                declared: 4,
+                found: 3
+            },
+            Finding::MissingSequences { first: 2, last: 3 },
+            Finding::DuplicateSequence(4),
+            Finding::DuplicateFilename("d10k.htm".to_string()),
+            Finding::PrimaryDocumentType {
+                filing_type: "10-K/A".to_string(),
+                document_type: "10-K".to_string()
+            },
+        ]
+    );
+}
+
+#[test]
+fn consistent_submission_has_no_findings() {
+    let submission = SUBMISSION.replace("<PUBLIC-DOCUMENT-COUNT>4", "<PUBLIC-DOCUMENT-COUNT>3");
+    let submission = parse_submission_from_str(&submission).unwrap();
+
+    assert_eq!(submission.validate(), Vec::new());
+}
@@ -0,0 +1,105 @@
+use sec_data_parser::*;
+
+const SUBMISSION: &str = "<SUBMISSION>
+<ACCESSION-NUMBER>0000950123-21-001234
+<TYPE>SC 13D
+<PUBLIC-DOCUMENT-COUNT>3
+<FILING-DATE>20210115
+<FILED-BY>
+<COMPANY-DATA>
+<CONFORMED-NAME>FIRST FILER
+<CIK>0000012345
+</COMPANY-DATA>
+</FILED-BY>
+<FILED-BY>
+<COMPANY-DATA>
+<CONFORMED-NAME>SECOND FILER
+<CIK>0000067890
+</COMPANY-DATA>
+</FILED-BY>
+<DOCUMENT>
+<TYPE>SC 13D
+<SEQUENCE>1
+<TEXT>
+Hello
+</TEXT>
+</DOCUMENT>
+<DOCUMENT>
+<TYPE>EX-99
+<SEQUENCE>2
+<FLAWED>
+<TEXT>
+World
+</TEXT>
+</DOCUMENT>
+</SUBMISSION>
+";
+
+fn kinds(report: &ParseReport) -> Vec<&WarningKind> {
+    report.warnings.iter().map(|w| &w.kind).collect()
+}
+
+#[test]
+fn anomalies_are_reported() {
+    let report = ParseOptions::default().report_str(SUBMISSION).unwrap();
+
+    assert_eq!(
+        kinds(&report),
+        vec![
+            &WarningKind::DuplicateContainer(ContainerTag::FiledBy),
+            &WarningKind::FlawedDocument,
+            &WarningKind::DocumentCountMismatch {
+                declared: 3,
+                found: 2
+            },
+        ]
+    );
+    assert_eq!(report.warnings[0].span.unwrap().line, 12);
+    assert_eq!(
+        report.warnings[1].path,
+        vec![ContainerTag::Submission, ContainerTag::Document]
+    );
+    assert_eq!(report.warnings[2].span.unwrap().line, 4);
+
+    let filed_by = report.submission.filed_by.unwrap();
+    assert_eq!(
+        filed_by.company_data.unwrap().conformed_name,
+        "SECOND FILER"
+    );
+}
+
+#[test]
+fn streaming_reports_the_same_anomalies() {
+    let from_bytes = ParseOptions::default().report_str(SUBMISSION).unwrap();
+    let from_reader = ParseOptions::default()
+        .report_reader(SUBMISSION.as_bytes())
+        .unwrap();
+
+    assert_eq!(from_reader.warnings, from_bytes.warnings);
+
+    let header_only = format!(
+        "{}</SUBMISSION>\n",
+        &SUBMISSION[..SUBMISSION.find("<DOCUMENT>").unwrap()]
+    );
+    let from_bytes = ParseOptions::default().report_str(&header_only).unwrap();
+    let from_reader = ParseOptions::default()
+        .report_reader(header_only.as_bytes())
+        .unwrap();
+
+    assert_eq!(
+        kinds(&from_reader)[1],
+        &WarningKind::DocumentCountMismatch {
+            declared: 3,
+            found: 0
+        }
+    );
+    assert_eq!(from_reader.warnings, from_bytes.warnings);
+}
+
+#[test]
+fn matching_count_is_not_reported() {
+    let submission = SUBMISSION.replace("<PUBLIC-DOCUMENT-COUNT>3", "<PUBLIC-DOCUMENT-COUNT>2");
+    let report = ParseOptions::default().report_str(&submission).unwrap();
+
+    assert_eq!(report.warnings.len(), 2);
+}
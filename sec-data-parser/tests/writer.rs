@@ -0,0 +1,70 @@
+use sec_data_parser::*;
+use std::io;
+use std::path::{Path, PathBuf};
+
+fn fixture(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures")
+        .join(name)
+}
+
+#[test]
+fn trees_are_written_as_tags() {
+    let tree = DocumentTree::container(
+        ContainerTag::Filer,
+        vec![DocumentTree::container(
+            ContainerTag::CompanyData,
+            vec![
+                DocumentTree::value(ValueTag::ConformedName, "ACME CORP"),
+                DocumentTree::value(ValueTag::Cik, "0000012345"),
+            ],
+        )],
+    );
+    let mut out = Vec::new();
+    write_tree(&tree, &mut out).unwrap();
+
+    assert_eq!(
+        String::from_utf8(out).unwrap(),
+        "<FILER>\n<COMPANY-DATA>\n<CONFORMED-NAME>ACME CORP\n<CIK>0000012345\n</COMPANY-DATA>\n</FILER>\n"
+    );
+}
+
+#[test]
+fn fixtures_survive_a_round_trip() {
+    for name in ["8-k.nc", "uuencoded.nc"] {
+        let source = std::fs::read(fixture(name)).unwrap();
+        let submission = parse_submission_from_bytes(&source).unwrap();
+
+        let written = submission_to_bytes(&submission).unwrap();
+        assert_eq!(
+            parse_submission_from_bytes(&written).unwrap(),
+            submission,
+            "{}",
+            name
+        );
+    }
+}
+
+#[test]
+fn text_fixtures_are_reproduced() {
+    let source = std::fs::read(fixture("8-k.nc")).unwrap();
+    let submission = parse_submission_from_bytes(&source).unwrap();
+
+    let written = submission_to_bytes(&submission).unwrap();
+    assert_eq!(
+        String::from_utf8(written).unwrap(),
+        String::from_utf8(source).unwrap()
+    );
+}
+
+#[test]
+fn lazy_bodies_must_be_loaded() {
+    let options = ParseOptions {
+        bodies: BodyMode::Lazy,
+        ..Default::default()
+    };
+    let submission = options.parse_path(&fixture("8-k.nc")).unwrap();
+
+    let error = submission_to_bytes(&submission).unwrap_err();
+    assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+}